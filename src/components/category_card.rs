@@ -1,31 +1,318 @@
 //! Category Card Component
 //!
-//! Displays an expandable/collapsible category with progress tracking.
+//! Displays an expandable/collapsible category with progress tracking. Each
+//! course row can open to list every requirement the course could count toward.
 //! Minimal, clean design with smooth expand/collapse.
 
-use crate::models::Category;
+use crate::data::faculties::faculty_for_prefix;
+use crate::data::{gen_ed::get_gen_ed_curriculum, major::get_major_curriculum};
+use crate::logic::auditor::course_eligibility;
+use crate::logic::sort::{sort_courses, SortKey};
+use crate::models::{
+    format_credits, is_in_progress_grade, progress_percent, Category, Course, CourseMatch,
+    DisplayLang, StrandProgress,
+};
 use leptos::*;
+use std::collections::HashSet;
 
-/// Collapsible accordion row for a course category
-#[component]
-pub fn CategoryCard(category: Category) -> impl IntoView {
-    let (is_expanded, set_is_expanded) = create_signal(false);
-    let percentage = (category.collected_credits / category.required_credits * 100.0).min(100.0);
-    let complete = percentage >= 100.0;
-    let category_clone = category.clone();
+/// Progress below this percentage shows as "just started".
+pub const PROGRESS_PARTIAL_FROM: f32 = 50.0;
+/// Progress from this percentage shows as "nearly there".
+pub const PROGRESS_NEAR_FROM: f32 = 75.0;
+/// Progress from this percentage shows as complete.
+pub const PROGRESS_COMPLETE_FROM: f32 = 100.0;
+
+/// Percentage thresholds that pick a progress-bar color. The default uses the
+/// `PROGRESS_*` constants.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressTheme {
+    pub partial_from: f32,
+    pub near_from: f32,
+    pub complete_from: f32,
+}
+
+impl Default for ProgressTheme {
+    fn default() -> Self {
+        Self {
+            partial_from: PROGRESS_PARTIAL_FROM,
+            near_from: PROGRESS_NEAR_FROM,
+            complete_from: PROGRESS_COMPLETE_FROM,
+        }
+    }
+}
+
+impl ProgressTheme {
+    /// Tailwind background class for a bar at `percent`.
+    pub fn color_class(&self, percent: f32) -> &'static str {
+        if percent >= self.complete_from {
+            "bg-emerald-500"
+        } else if percent >= self.near_from {
+            "bg-brand-500"
+        } else if percent >= self.partial_from {
+            "bg-brand-400"
+        } else {
+            "bg-amber-400"
+        }
+    }
+}
+
+/// Progress-bar color class for `percent` under the default theme.
+pub fn progress_color_class(percent: f32) -> &'static str {
+    ProgressTheme::default().color_class(percent)
+}
+
+/// Names of the expanded category cards. Kept outside the cards so every card
+/// can be expanded or collapsed at once; all cards start collapsed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExpandedCategories(HashSet<String>);
+
+impl ExpandedCategories {
+    pub fn is_expanded(&self, name: &str) -> bool {
+        self.0.contains(name)
+    }
+
+    pub fn set_expanded(&mut self, name: &str, expanded: bool) {
+        if expanded {
+            self.0.insert(name.to_string());
+        } else {
+            self.0.remove(name);
+        }
+    }
+
+    pub fn toggle(&mut self, name: &str) {
+        let expanded = self.is_expanded(name);
+        self.set_expanded(name, !expanded);
+    }
+
+    pub fn expand_all<'a>(&mut self, names: impl IntoIterator<Item = &'a str>) {
+        self.0.extend(names.into_iter().map(str::to_string));
+    }
+
+    pub fn collapse_all(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// Expansion state shared by all category cards. Provided as context by the
+/// app; a card without it keeps its own state.
+#[derive(Debug, Clone, Copy)]
+pub struct CategoryExpansion(pub RwSignal<ExpandedCategories>);
+
+/// Open/closed state of one card: the caller's signal when it passes
+/// `expanded`, otherwise the card's entry in the shared `ExpandedCategories`.
+#[derive(Debug, Clone, Copy)]
+pub struct CardExpansion {
+    own: Option<RwSignal<bool>>,
+    shared: RwSignal<ExpandedCategories>,
+    name: StoredValue<String>,
+}
+
+impl CardExpansion {
+    pub fn new(
+        name: &str,
+        own: Option<RwSignal<bool>>,
+        shared: RwSignal<ExpandedCategories>,
+    ) -> Self {
+        Self {
+            own,
+            shared,
+            name: store_value(name.to_string()),
+        }
+    }
+
+    pub fn is_expanded(&self) -> bool {
+        match self.own {
+            Some(signal) => signal.get(),
+            None => self
+                .shared
+                .with(|e| self.name.with_value(|n| e.is_expanded(n))),
+        }
+    }
+
+    pub fn set_expanded(&self, expanded: bool) {
+        match self.own {
+            Some(signal) => signal.set(expanded),
+            None => self
+                .shared
+                .update(|e| self.name.with_value(|n| e.set_expanded(n, expanded))),
+        }
+    }
+
+    pub fn toggle(&self) {
+        match self.own {
+            Some(signal) => signal.update(|open| *open = !*open),
+            None => self
+                .shared
+                .update(|e| self.name.with_value(|n| e.toggle(n))),
+        }
+    }
+}
+
+/// Category whose card should expand and scroll into view (e.g. after a click
+/// on a missing requirement). Provided as context by the app.
+#[derive(Debug, Clone, Copy)]
+pub struct CategoryFocus(pub RwSignal<Option<String>>);
+
+/// Maps a `MissingCourse.category` to the top-level category card it belongs
+/// to (e.g. "Core Courses" → "Major Courses"); `None` when no card matches.
+pub fn target_category(missing_category: &str) -> Option<&'static str> {
+    match missing_category {
+        "General Education" => Some("General Education"),
+        "Basic Science" | "Core Courses" | "Capstone" | "Major Electives" | "Major Courses" => {
+            Some("Major Courses")
+        }
+        "Free Electives" => Some("Free Electives"),
+        _ => None,
+    }
+}
+
+/// DOM id of a category card, e.g. "Major Courses" → "category-major-courses".
+pub fn category_dom_id(category: &str) -> String {
+    format!("category-{}", category.to_lowercase().replace(' ', "-"))
+}
 
-    let progress_color = if complete {
-        "bg-emerald-500"
+/// Hover text for a course row: the requirement it was matched to and the
+/// credits it contributed, noting when that is less than the transcript credit
+/// (e.g. a 6-credit module filling a 3-credit slot).
+pub fn course_match_tooltip(course: &Course, matched: &CourseMatch, is_thai: bool) -> String {
+    let credited = format_credits(matched.credited);
+    let mut text = if is_thai {
+        format!("นับใน {}: {} หน่วยกิต", matched.requirement, credited)
     } else {
-        "bg-brand-500"
+        format!("Counts toward {}: {} cr", matched.requirement, credited)
     };
+    if matched.credited < course.credit {
+        let transcript = format_credits(course.credit);
+        if is_thai {
+            text.push_str(&format!(" (จาก {} หน่วยกิตในใบเกรด)", transcript));
+        } else {
+            text.push_str(&format!(" (of {} cr on transcript)", transcript));
+        }
+    }
+    text
+}
 
+/// Chip text for GenEd strand `number`: a check once the strand is complete,
+/// otherwise the credits still missing (e.g. "3 · 2 cr").
+pub fn strand_chip_label(number: usize, strand: &StrandProgress) -> String {
+    if strand.completed >= strand.required {
+        format!("{} ✓", number)
+    } else {
+        format!(
+            "{} · {} cr",
+            number,
+            format_credits(strand.required - strand.completed)
+        )
+    }
+}
+
+/// Row of strand chips shown under the GenEd header, readable while collapsed
+#[component]
+fn StrandChips(strands: Vec<StrandProgress>) -> impl IntoView {
     view! {
-        <div class="group">
+        <div class="px-5 pb-3 -mt-1 flex flex-wrap gap-1.5">
+            {strands.iter().enumerate().map(|(i, strand)| {
+                let chip_class = if strand.completed >= strand.required {
+                    "bg-emerald-50 text-emerald-700 border-emerald-200/60"
+                } else if strand.completed > 0.0 {
+                    "bg-amber-50 text-amber-700 border-amber-200/60"
+                } else {
+                    "bg-zinc-50 text-zinc-500 border-zinc-200"
+                };
+                view! {
+                    <span
+                        class={format!("text-2xs font-medium font-mono px-1.5 py-0.5 rounded border tabular-nums {}", chip_class)}
+                        title=strand.name.clone()
+                    >
+                        {strand_chip_label(i + 1, strand)}
+                    </span>
+                }
+            }).collect::<Vec<_>>()}
+        </div>
+    }
+}
+
+/// Progress bars for GenEd strands, with sub-groups indented beneath
+#[component]
+fn StrandBreakdown(strands: Vec<StrandProgress>) -> impl IntoView {
+    let bar = |progress: &StrandProgress, nested: bool| {
+        let pct = progress_percent(progress.completed, progress.required);
+        let (row_class, bar_height) = if nested {
+            ("pl-4 text-2xs text-zinc-500", "h-1")
+        } else {
+            ("text-[13px] text-zinc-700", "h-1.5")
+        };
+        view! {
+            <div class=row_class>
+                <div class="flex items-center justify-between mb-1">
+                    <span class="truncate">{progress.name.clone()}</span>
+                    <span class="font-mono tabular-nums text-zinc-400 shrink-0 ml-2">
+                        {format!("{}/{}", format_credits(progress.completed), format_credits(progress.required))}
+                    </span>
+                </div>
+                <div class={format!("w-full bg-zinc-100 rounded-full overflow-hidden {}", bar_height)}>
+                    <div
+                        class={format!("h-full rounded-full {}", progress_color_class(pct))}
+                        style={format!("width: {}%", pct)}
+                    ></div>
+                </div>
+            </div>
+        }
+    };
+
+    view! {
+        <div class="px-5 py-3 space-y-2.5 border-b border-zinc-100">
+            {strands.iter().map(|strand| view! {
+                <div class="space-y-1.5">
+                    {bar(strand, false)}
+                    {strand.sub_groups.iter().map(|sub_group| bar(sub_group, true)).collect::<Vec<_>>()}
+                </div>
+            }).collect::<Vec<_>>()}
+        </div>
+    }
+}
+
+/// Collapsible accordion row for a course category. `strands` (GenEd only)
+/// adds strand chips under the header and a per-strand progress breakdown to
+/// the expanded view. `expanded` puts the open/closed state under the
+/// caller's control; without it the card uses the shared `CategoryExpansion`
+/// state (or its own when none).
+#[component]
+pub fn CategoryCard(
+    category: Category,
+    #[prop(optional)] strands: Vec<StrandProgress>,
+    #[prop(optional)] expanded: Option<RwSignal<bool>>,
+) -> impl IntoView {
+    let expansion = use_context::<CategoryExpansion>()
+        .map(|CategoryExpansion(state)| state)
+        .unwrap_or_else(|| create_rw_signal(ExpandedCategories::default()));
+    let name = store_value(category.name.clone());
+    let card = CardExpansion::new(&category.name, expanded, expansion);
+    let is_expanded = create_memo(move |_| card.is_expanded());
+    if let Some(CategoryFocus(focus)) = use_context::<CategoryFocus>() {
+        create_effect(move |_| {
+            if name.with_value(|n| focus.get().as_deref() == Some(n.as_str())) {
+                card.set_expanded(true);
+                focus.set(None);
+            }
+        });
+    }
+    let (sort_key, set_sort_key) = create_signal(SortKey::default());
+    let display_lang = use_context::<ReadSignal<DisplayLang>>();
+    let display_lang = move || display_lang.map(|s| s.get()).unwrap_or(DisplayLang::Thai);
+    let percentage = progress_percent(category.collected_credits, category.required_credits);
+    let complete = percentage >= PROGRESS_COMPLETE_FROM;
+    let category_clone = category.clone();
+    let progress_color = progress_color_class(percentage);
+    // Free electives come from any faculty, so each row names its likely one
+    let show_faculty = category.name == "Free Electives";
+
+    view! {
+        <div class="group scroll-mt-20" id=category_dom_id(&category.name)>
             // Header row
             <button
                 class="w-full px-5 py-3.5 flex items-center justify-between hover:bg-zinc-50/80 transition-colors text-left"
-                on:click=move |_| set_is_expanded.update(|v| *v = !*v)
+                on:click=move |_| card.toggle()
             >
                 <div class="flex items-center gap-3 min-w-0">
                     // Expand icon
@@ -71,16 +358,18 @@ pub fn CategoryCard(category: Category) -> impl IntoView {
                         ></div>
                     </div>
                     <span class="text-xs font-mono font-medium text-zinc-500 tabular-nums w-16 text-right">
-                        {format!("{:.0}/{:.0} cr", category.collected_credits, category.required_credits)}
+                        {format!("{}/{} cr", format_credits(category.collected_credits), format_credits(category.required_credits))}
                     </span>
                 </div>
             </button>
+            {(!strands.is_empty()).then(|| view! { <StrandChips strands=strands.clone()/> })}
 
             // Expanded course list
             {move || {
                 if is_expanded.get() {
                     view! {
                         <div class="border-t border-zinc-100 bg-zinc-50/40 animate-fade-in">
+                            {(!strands.is_empty()).then(|| view! { <StrandBreakdown strands=strands.clone()/> })}
                             {if category_clone.courses.is_empty() {
                                 view! {
                                     <div class="px-5 py-6 text-center">
@@ -91,30 +380,130 @@ pub fn CategoryCard(category: Category) -> impl IntoView {
                                     </div>
                                 }.into_view()
                             } else {
+                                let mut courses = category_clone.courses.clone();
+                                sort_courses(&mut courses, sort_key.get());
                                 view! {
+                                    <div class="flex justify-end px-5 pt-2">
+                                        <select
+                                            class="text-2xs font-medium text-zinc-600 bg-white border border-zinc-200 rounded-md px-2 py-1"
+                                            on:change=move |ev| set_sort_key.set(match event_target_value(&ev).as_str() {
+                                                "code" => SortKey::Code,
+                                                "grade-best" => SortKey::GradeBest,
+                                                "grade-worst" => SortKey::GradeWorst,
+                                                "credits" => SortKey::Credits,
+                                                _ => SortKey::Transcript,
+                                            })
+                                        >
+                                            {move || {
+                                                let is_thai = use_context::<ReadSignal<bool>>().map(|s| s.get()).unwrap_or(false);
+                                                let current = sort_key.get_untracked();
+                                                [
+                                                    ("transcript", SortKey::Transcript, "ลำดับในใบแสดงผล", "Transcript order"),
+                                                    ("code", SortKey::Code, "รหัสวิชา", "Course code"),
+                                                    ("grade-best", SortKey::GradeBest, "เกรดสูงสุดก่อน", "Best grade first"),
+                                                    ("grade-worst", SortKey::GradeWorst, "เกรดต่ำสุดก่อน", "Worst grade first"),
+                                                    ("credits", SortKey::Credits, "หน่วยกิต", "Credits"),
+                                                ].into_iter().map(|(value, key, th, en)| view! {
+                                                    <option value=value selected={key == current}>{if is_thai { th } else { en }}</option>
+                                                }).collect::<Vec<_>>()
+                                            }}
+                                        </select>
+                                    </div>
                                     <div class="divide-y divide-zinc-100/80">
-                                        {category_clone.courses.iter().map(|course| {
-                                            let course = course.clone();
-                                            let grade_color = match course.grade.chars().next().unwrap_or('F') {
+                                        {courses.into_iter().map(|course| {
+                                            let in_progress = is_in_progress_grade(&course.grade);
+                                            let is_thai = use_context::<ReadSignal<bool>>().map(|s| s.get()).unwrap_or(false);
+                                            let grade_color = if in_progress {
+                                                "bg-violet-50 text-violet-700 border-violet-300 border-dashed"
+                                            } else { match course.grade.chars().next().unwrap_or('F') {
                                                 'A' => "bg-emerald-50 text-emerald-700 border-emerald-200/60",
                                                 'B' => "bg-blue-50 text-blue-700 border-blue-200/60",
                                                 'C' => "bg-amber-50 text-amber-700 border-amber-200/60",
                                                 'D' => "bg-orange-50 text-orange-700 border-orange-200/60",
                                                 _ => "bg-zinc-50 text-zinc-600 border-zinc-200",
-                                            };
+                                            } };
+                                            let match_tooltip = course.matched.as_ref().map(|matched| course_match_tooltip(&course, matched, is_thai));
+                                            let (details_open, set_details_open) = create_signal(false);
+                                            let code = course.code.clone();
                                             view! {
-                                                <div class="flex items-center justify-between px-5 py-2.5 hover:bg-white/60 transition-colors">
+                                                <div>
+                                                <div class="flex items-center justify-between px-5 py-2.5 hover:bg-white/60 transition-colors" title=match_tooltip>
                                                     <div class="flex items-center gap-3 min-w-0 flex-1">
-                                                        <span class="font-mono text-2xs font-semibold text-zinc-400 w-14 shrink-0">{&course.code}</span>
-                                                        <span class="text-[13px] text-zinc-700 truncate">{&course.name}</span>
+                                                        <span
+                                                            class="font-mono text-2xs font-semibold text-zinc-400 w-14 shrink-0"
+                                                            title={course.section.clone().map(|section| if is_thai { format!("ตอน {}", section) } else { format!("Section {}", section) })}
+                                                        >
+                                                            {&course.code}
+                                                        </span>
+                                                        <span class="text-[13px] text-zinc-700 truncate">{
+                                                            let course = course.clone();
+                                                            move || course.display_name(display_lang()).to_string()
+                                                        }</span>
+                                                        {show_faculty.then(|| faculty_for_prefix(&course.code)).flatten().map(|faculty| view! {
+                                                            <span
+                                                                class="shrink-0 text-2xs font-medium px-1.5 py-0.5 rounded bg-zinc-50 text-zinc-500 border border-zinc-200"
+                                                                title={if is_thai { "คณะที่น่าจะเปิดสอน (จากรหัสวิชา)" } else { "Likely faculty (from the course code)" }}
+                                                            >
+                                                                {faculty}
+                                                            </span>
+                                                        })}
+                                                        {course.substitutes_for.clone().map(|target| view! {
+                                                            <span
+                                                                class="shrink-0 text-2xs font-medium px-1.5 py-0.5 rounded bg-sky-50 text-sky-700 border border-sky-200/60"
+                                                                title={if is_thai { "การเทียบวิชาที่อนุมัติ" } else { "Approved substitution" }}
+                                                            >
+                                                                {format!("→ {}", target)}
+                                                            </span>
+                                                        })}
                                                     </div>
                                                     <div class="flex items-center gap-2.5 shrink-0 ml-3">
-                                                        <span class={format!("text-2xs font-bold w-7 h-5 flex items-center justify-center rounded border {}", grade_color)}>
+                                                        <span
+                                                            class={format!("text-2xs font-bold w-7 h-5 flex items-center justify-center rounded border {}", grade_color)}
+                                                            title={in_progress.then_some(if is_thai { "กำลังเรียน (สมมติว่าผ่าน)" } else { "In progress (assumed to pass)" })}
+                                                        >
                                                             {&course.grade}
                                                         </span>
-                                                        <span class="text-2xs text-zinc-400 font-mono w-6 text-right">{format!("{}", course.credit as u32)}</span>
+                                                        <span class="text-2xs text-zinc-400 font-mono w-6 text-right">{format_credits(course.credit)}</span>
+                                                        <button
+                                                            class="text-zinc-300 hover:text-zinc-600 transition-colors"
+                                                            title={if is_thai { "ข้อกำหนดที่วิชานี้นับได้" } else { "Requirements this course can count toward" }}
+                                                            on:click=move |_| set_details_open.update(|open| *open = !*open)
+                                                        >
+                                                            <svg
+                                                                class={move || format!(
+                                                                    "w-3.5 h-3.5 transition-transform duration-200 {}",
+                                                                    if details_open.get() { "rotate-90" } else { "" }
+                                                                )}
+                                                                fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24"
+                                                            >
+                                                                <path stroke-linecap="round" stroke-linejoin="round" d="M8.25 4.5l7.5 7.5-7.5 7.5" />
+                                                            </svg>
+                                                        </button>
                                                     </div>
                                                 </div>
+                                                // Every requirement listing the code, even ones it was not matched to
+                                                {move || details_open.get().then(|| {
+                                                    let requirements = course_eligibility(&code, &get_gen_ed_curriculum(), &get_major_curriculum());
+                                                    view! {
+                                                        <div class="px-5 pb-2.5 pl-[5.25rem] animate-fade-in">
+                                                            <p class="text-2xs font-semibold text-zinc-500 mb-1">
+                                                                {if is_thai { "นับได้ใน" } else { "Could count toward" }}
+                                                            </p>
+                                                            {if requirements.is_empty() {
+                                                                view! {
+                                                                    <p class="text-2xs text-zinc-400">
+                                                                        {if is_thai { "หมวดวิชาเลือกเสรีเท่านั้น" } else { "Free electives only" }}
+                                                                    </p>
+                                                                }.into_view()
+                                                            } else {
+                                                                requirements.into_iter().map(|requirement| view! {
+                                                                    <p class="text-2xs text-zinc-600">{requirement}</p>
+                                                                }).collect::<Vec<_>>().into_view()
+                                                            }}
+                                                        </div>
+                                                    }
+                                                })}
+                                                </div>
                                             }
                                         }).collect::<Vec<_>>()}
                                     </div>
@@ -129,3 +518,130 @@ pub fn CategoryCard(category: Category) -> impl IntoView {
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_categories_map_to_their_card() {
+        assert_eq!(
+            target_category("General Education"),
+            Some("General Education")
+        );
+        assert_eq!(target_category("Core Courses"), Some("Major Courses"));
+        assert_eq!(target_category("Major Electives"), Some("Major Courses"));
+        assert_eq!(target_category("Free Electives"), Some("Free Electives"));
+        assert_eq!(target_category("Co-requisite Warning"), None);
+        assert_eq!(category_dom_id("Major Courses"), "category-major-courses");
+    }
+
+    #[test]
+    fn expand_all_and_collapse_all_update_every_card() {
+        let mut expanded = ExpandedCategories::default();
+        assert!(!expanded.is_expanded("General Education"));
+
+        expanded.toggle("General Education");
+        assert!(expanded.is_expanded("General Education"));
+
+        expanded.expand_all(["General Education", "Major Courses", "Free Electives"]);
+        assert!(expanded.is_expanded("Major Courses"));
+        assert!(expanded.is_expanded("Free Electives"));
+
+        expanded.collapse_all();
+        assert_eq!(expanded, ExpandedCategories::default());
+    }
+
+    #[test]
+    fn progress_colors_change_at_each_threshold() {
+        let cases = [
+            (49.9, "bg-amber-400"),
+            (50.0, "bg-brand-400"),
+            (74.9, "bg-brand-400"),
+            (75.0, "bg-brand-500"),
+            (100.0, "bg-emerald-500"),
+        ];
+        for (percent, class) in cases {
+            assert_eq!(progress_color_class(percent), class, "{}", percent);
+        }
+
+        let tuned = ProgressTheme {
+            partial_from: 30.0,
+            ..ProgressTheme::default()
+        };
+        assert_eq!(tuned.color_class(40.0), "bg-brand-400");
+    }
+
+    fn course(credit: f32) -> Course {
+        Course {
+            code: "344-201".to_string(),
+            name: "Module Computing".to_string(),
+            credit,
+            grade: "A".to_string(),
+            curriculum_name: None,
+            section: None,
+            substitutes_for: None,
+            matched: None,
+        }
+    }
+
+    #[test]
+    fn match_tooltip_names_the_requirement_and_credits() {
+        let matched = CourseMatch {
+            requirement: "Core Courses".to_string(),
+            credited: 3.0,
+        };
+
+        assert_eq!(
+            course_match_tooltip(&course(3.0), &matched, false),
+            "Counts toward Core Courses: 3 cr"
+        );
+        assert_eq!(
+            course_match_tooltip(&course(6.0), &matched, false),
+            "Counts toward Core Courses: 3 cr (of 6 cr on transcript)"
+        );
+        assert_eq!(
+            course_match_tooltip(&course(6.0), &matched, true),
+            "นับใน Core Courses: 3 หน่วยกิต (จาก 6 หน่วยกิตในใบเกรด)"
+        );
+    }
+
+    fn strand(completed: f32, required: f32) -> StrandProgress {
+        StrandProgress {
+            name: "Strand".to_string(),
+            completed,
+            required,
+            sub_groups: vec![],
+        }
+    }
+
+    #[test]
+    fn strand_chip_shows_check_or_missing_credits() {
+        assert_eq!(strand_chip_label(1, &strand(3.0, 3.0)), "1 ✓");
+        assert_eq!(strand_chip_label(4, &strand(1.0, 3.0)), "4 · 2 cr");
+        assert_eq!(strand_chip_label(2, &strand(0.5, 3.0)), "2 · 2.5 cr");
+    }
+
+    #[test]
+    fn caller_signal_receives_the_card_toggles() {
+        let runtime = create_runtime();
+        let own = create_rw_signal(false);
+        let shared = create_rw_signal(ExpandedCategories::default());
+        let card = CardExpansion::new("Major Courses", Some(own), shared);
+
+        card.toggle();
+        assert!(own.get());
+        assert!(card.is_expanded());
+        card.set_expanded(false);
+        assert!(!own.get());
+        // The shared state is left alone while the caller owns the card
+        assert_eq!(shared.get(), ExpandedCategories::default());
+
+        let shared_card = CardExpansion::new("Major Courses", None, shared);
+        shared_card.toggle();
+        assert!(shared.with(|e| e.is_expanded("Major Courses")));
+        assert!(!own.get());
+
+        runtime.dispose();
+    }
+}
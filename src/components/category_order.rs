@@ -0,0 +1,121 @@
+//! Category Order
+//!
+//! Student-chosen display order of the category cards (e.g. Major before
+//! GenEd), changed with move buttons and kept in localStorage.
+
+use crate::logic::storage::{load_json, local_storage};
+use crate::models::Category;
+
+/// localStorage key holding the category names, in display order, as JSON.
+const CATEGORY_ORDER_STORAGE_KEY: &str = "course-audit.category-order";
+
+/// Direction a category card is moved in the display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveDirection {
+    Up,   // Toward the start of the list
+    Down, // Toward the end of the list
+}
+
+/// Loads the saved category order; empty (curriculum order) when none is
+/// stored or the stored value is unreadable (and then discarded).
+pub fn load_category_order() -> Vec<String> {
+    local_storage()
+        .and_then(|storage| load_json(&storage, CATEGORY_ORDER_STORAGE_KEY))
+        .unwrap_or_default()
+}
+
+/// Saves the category order, ignoring storage failures (private mode, quota).
+pub fn save_category_order(order: &[String]) {
+    if let (Some(storage), Ok(json)) = (local_storage(), serde_json::to_string(order)) {
+        let _ = storage.set_item(CATEGORY_ORDER_STORAGE_KEY, &json);
+    }
+}
+
+/// Swaps the item at `index` with its neighbour in `direction`. Returns
+/// `false` (leaving `items` unchanged) when `index` is out of range or the
+/// item is already first (up) or last (down).
+pub fn move_item<T>(items: &mut [T], index: usize, direction: MoveDirection) -> bool {
+    let target = match direction {
+        MoveDirection::Up => index.checked_sub(1),
+        MoveDirection::Down => Some(index + 1),
+    };
+    match target {
+        Some(target) if index < items.len() && target < items.len() => {
+            items.swap(index, target);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// `categories` sorted by `order` (category names). Categories the order
+/// does not name, such as one added since it was saved, keep their
+/// curriculum order after the named ones.
+pub fn ordered_categories(mut categories: Vec<Category>, order: &[String]) -> Vec<Category> {
+    // Stable sort keeps curriculum order among unnamed categories
+    categories.sort_by_key(|category| {
+        order
+            .iter()
+            .position(|name| name == &category.name)
+            .unwrap_or(order.len())
+    });
+    categories
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(name: &str) -> Category {
+        Category {
+            name: name.to_string(),
+            required_credits: 0.0,
+            collected_credits: 0.0,
+            courses: vec![],
+        }
+    }
+
+    #[test]
+    fn items_move_one_place_within_bounds() {
+        let mut items = vec![1, 2, 3];
+
+        assert!(move_item(&mut items, 1, MoveDirection::Up));
+        assert_eq!(items, [2, 1, 3]);
+        assert!(move_item(&mut items, 1, MoveDirection::Down));
+        assert_eq!(items, [2, 3, 1]);
+
+        assert!(!move_item(&mut items, 0, MoveDirection::Up));
+        assert!(!move_item(&mut items, 2, MoveDirection::Down));
+        assert!(!move_item(&mut items, 7, MoveDirection::Up));
+        assert_eq!(items, [2, 3, 1]);
+    }
+
+    #[test]
+    fn saved_order_comes_first_and_unknown_categories_keep_their_place() {
+        let categories = vec![
+            category("General Education"),
+            category("Major Courses"),
+            category("Minor"),
+            category("Free Electives"),
+        ];
+
+        let ordered = ordered_categories(
+            categories,
+            &[
+                "Free Electives".to_string(),
+                "General Education".to_string(),
+            ],
+        );
+
+        let names: Vec<_> = ordered.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Free Electives",
+                "General Education",
+                "Major Courses",
+                "Minor"
+            ]
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! Cluster Board Component
+//!
+//! Grid of every major-elective cluster with how many of its courses are
+//! passed, so a student can pick which clusters to finish. Picked clusters
+//! can be pinned: they are listed first, and the missing-requirements list
+//! then names their remaining courses. Pins are kept in localStorage.
+
+use crate::components::category_card::progress_color_class;
+use crate::logic::storage::{load_json, local_storage};
+use crate::models::{progress_percent, ClusterProgress};
+use leptos::*;
+use std::collections::HashSet;
+
+/// localStorage key holding the pinned cluster ids as a JSON array.
+const PINNED_CLUSTERS_STORAGE_KEY: &str = "course-audit.pinned-clusters";
+
+/// Loads the pinned cluster ids; empty when none are stored or the stored
+/// value is unreadable (and then discarded).
+pub fn load_pinned_clusters() -> HashSet<String> {
+    local_storage()
+        .and_then(|storage| load_json(&storage, PINNED_CLUSTERS_STORAGE_KEY))
+        .unwrap_or_default()
+}
+
+/// Saves the pinned cluster ids, ignoring storage failures (private mode, quota).
+pub fn save_pinned_clusters(pinned: &HashSet<String>) {
+    if let (Some(storage), Ok(json)) = (local_storage(), serde_json::to_string(pinned)) {
+        let _ = storage.set_item(PINNED_CLUSTERS_STORAGE_KEY, &json);
+    }
+}
+
+/// Scoreboard of elective clusters across all domains, pinned clusters first
+#[component]
+pub fn ClusterBoard(
+    clusters: Vec<ClusterProgress>,
+    #[prop(into)] pinned: Signal<HashSet<String>>,
+    #[prop(into)] on_toggle_pin: Callback<String>,
+) -> impl IntoView {
+    let is_thai = use_context::<ReadSignal<bool>>();
+    let is_thai = move || is_thai.map(|s| s.get()).unwrap_or(false);
+
+    let ordered = move || {
+        let pinned = pinned.get();
+        let mut clusters = clusters.clone();
+        // Stable sort keeps curriculum order within each group
+        clusters.sort_by_key(|cluster| !pinned.contains(&cluster.cluster_id));
+        clusters
+            .into_iter()
+            .map(|cluster| (pinned.contains(&cluster.cluster_id), cluster))
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft p-5">
+            <h3 class="text-sm font-semibold text-zinc-800">{move || if is_thai() { "ความคืบหน้ากลุ่มวิชาเลือก" } else { "Elective Clusters" }}</h3>
+            <p class="text-2xs text-zinc-400 mt-0.5 mb-4">{move || if is_thai() { "วิชาที่ผ่านแล้วในแต่ละกลุ่ม ปักหมุดกลุ่มที่ตั้งใจเรียนให้ครบ" } else { "Passed courses in each cluster. Pin the clusters you plan to complete" }}</p>
+            <div class="grid grid-cols-1 sm:grid-cols-2 xl:grid-cols-3 gap-3">
+                {move || ordered().into_iter().map(|(is_pinned, cluster)| {
+                    let complete = cluster.completed_courses >= cluster.required_courses;
+                    let pct = progress_percent(cluster.completed_courses as f32, cluster.required_courses as f32);
+                    let tile_class = match (is_pinned, complete) {
+                        (true, _) => "border-brand-300 bg-brand-50/40 ring-1 ring-brand-200",
+                        (false, true) => "border-emerald-200/70 bg-emerald-50/40",
+                        (false, false) => "border-zinc-200/80",
+                    };
+                    let cluster_id = cluster.cluster_id.clone();
+                    view! {
+                        <div class={format!("rounded-xl border px-3.5 py-3 {}", tile_class)}>
+                            <div class="flex items-baseline justify-between gap-2">
+                                <p class="text-[13px] font-medium text-zinc-800 truncate" title=cluster.name.clone()>
+                                    {format!("{} {}", cluster.cluster_id, cluster.name)}
+                                </p>
+                                <span class="text-xs font-mono text-zinc-500 tabular-nums shrink-0">
+                                    {format!("{}/{}", cluster.completed_courses, cluster.required_courses)}
+                                </span>
+                            </div>
+                            <p class="text-2xs text-zinc-400 truncate mt-0.5">{cluster.domain.clone()}</p>
+                            <div class={format!("mt-2 rounded-full bg-zinc-100 overflow-hidden {}", if is_pinned { "h-1.5" } else { "h-1" })}>
+                                <div class={format!("h-full rounded-full {}", progress_color_class(pct))} style={format!("width: {}%", pct)}></div>
+                            </div>
+                            <div class="flex items-center justify-between gap-2 mt-1.5">
+                                <p class="text-2xs text-zinc-400">
+                                    {move || if is_thai() {
+                                        format!("จากทั้งหมด {} วิชาในกลุ่ม", cluster.total_courses)
+                                    } else {
+                                        format!("of {} courses offered", cluster.total_courses)
+                                    }}
+                                </p>
+                                <button
+                                    class={if is_pinned {
+                                        "text-2xs font-medium text-brand-700 hover:text-brand-900 transition-colors"
+                                    } else {
+                                        "text-2xs font-medium text-zinc-400 hover:text-zinc-700 transition-colors"
+                                    }}
+                                    on:click=move |_| on_toggle_pin.call(cluster_id.clone())
+                                >
+                                    {move || match (is_thai(), is_pinned) {
+                                        (true, true) => "เลิกปักหมุด",
+                                        (true, false) => "ปักหมุด",
+                                        (false, true) => "Unpin",
+                                        (false, false) => "Pin",
+                                    }}
+                                </button>
+                            </div>
+                        </div>
+                    }
+                }).collect::<Vec<_>>()}
+            </div>
+        </div>
+    }
+}
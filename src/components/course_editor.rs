@@ -0,0 +1,128 @@
+//! Course Editor Component
+//!
+//! Small form for adding a course the PDF parser missed (or one the student
+//! wants to try out). The parent decides how to re-audit the added course.
+//! The code field autocompletes from the curriculum; unknown codes are still
+//! accepted (they can only count as free electives).
+
+use crate::data::{gen_ed::get_gen_ed_curriculum, major::get_major_curriculum};
+use crate::logic::auditor::{curriculum_courses, suggest_courses};
+use crate::logic::parser::{normalize_grade, sanitize_credit};
+use crate::models::{canonical_code, CourseCode, ParsedCourse};
+use leptos::*;
+
+/// Most suggestions listed under the code field.
+const SUGGESTION_LIMIT: usize = 8;
+
+/// Inline form that emits a `ParsedCourse` through `on_add`
+#[component]
+pub fn CourseEditor(#[prop(into)] on_add: Callback<ParsedCourse>) -> impl IntoView {
+    let is_thai = use_context::<ReadSignal<bool>>();
+    let is_thai = move || is_thai.map(|s| s.get()).unwrap_or(false);
+
+    let (code, set_code) = create_signal(String::new());
+    let (name, set_name) = create_signal(String::new());
+    let (credits, set_credits) = create_signal("3".to_string());
+    let (grade, set_grade) = create_signal(String::new());
+    let catalog = store_value(curriculum_courses(
+        &get_gen_ed_curriculum(),
+        &get_major_curriculum(),
+    ));
+
+    // Typing (or picking) a known code fills in its curriculum name and credits
+    let on_code_input = move |ev: ev::Event| {
+        let value = event_target_value(&ev);
+        let code = canonical_code(&value);
+        if let Some(course) = catalog.with_value(|c| c.iter().find(|c| c.code == code).cloned()) {
+            set_name.set(course.name);
+            set_credits.set(course.credits.to_string());
+        }
+        set_code.set(value);
+    };
+    let suggestions = move || {
+        catalog.with_value(|c| {
+            suggest_courses(&code.get(), c, SUGGESTION_LIMIT)
+                .into_iter()
+                .map(|course| view! { <option value=course.code.clone()>{course.name.clone()}</option> })
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let can_add = move || {
+        CourseCode::parse(&code.get()).is_some()
+            && !grade.get().trim().is_empty()
+            && credits
+                .get()
+                .trim()
+                .parse::<f32>()
+                .is_ok_and(|credit| !sanitize_credit(credit).1)
+    };
+
+    let on_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        let Some(course_code) = CourseCode::parse(&code.get_untracked()).filter(|_| can_add())
+        else {
+            return;
+        };
+        on_add.call(ParsedCourse {
+            code: course_code.to_string(),
+            name: name.get_untracked().trim().to_string(),
+            grade: normalize_grade(&grade.get_untracked()),
+            parsed_credit: credits.get_untracked().trim().parse().unwrap_or(0.0),
+            section: None,
+            low_confidence: false,
+        });
+        set_code.set(String::new());
+        set_name.set(String::new());
+        set_grade.set(String::new());
+    };
+
+    let input_class = "text-[13px] px-2.5 py-1.5 rounded-lg border border-zinc-200 bg-white focus:outline-none focus:ring-2 focus:ring-brand-500/40 min-w-0";
+
+    view! {
+        <form class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft p-5 flex flex-col gap-3" on:submit=on_submit>
+            <div>
+                <h3 class="text-sm font-semibold text-zinc-800">{move || if is_thai() { "เพิ่มวิชาเอง" } else { "Add a Course" }}</h3>
+                <p class="text-2xs text-zinc-400 mt-0.5">{move || if is_thai() { "สำหรับวิชาที่ไม่พบในไฟล์ PDF" } else { "For courses missing from the PDF" }}</p>
+            </div>
+            <div class="grid grid-cols-2 sm:grid-cols-[7rem_1fr_4rem_4rem_auto] gap-2">
+                <input
+                    class=input_class
+                    placeholder="344-101"
+                    list="course-code-suggestions"
+                    autocomplete="off"
+                    prop:value=code
+                    on:input=on_code_input
+                />
+                <datalist id="course-code-suggestions">{suggestions}</datalist>
+                <input
+                    class=input_class
+                    placeholder={move || if is_thai() { "ชื่อวิชา" } else { "Course name" }}
+                    prop:value=name
+                    on:input=move |ev| set_name.set(event_target_value(&ev))
+                />
+                <input
+                    class=input_class
+                    type="number" min="0" step="1"
+                    title={move || if is_thai() { "หน่วยกิต" } else { "Credits" }}
+                    prop:value=credits
+                    on:input=move |ev| set_credits.set(event_target_value(&ev))
+                />
+                <input
+                    class=input_class
+                    placeholder="A"
+                    title={move || if is_thai() { "เกรด" } else { "Grade" }}
+                    prop:value=grade
+                    on:input=move |ev| set_grade.set(event_target_value(&ev))
+                />
+                <button
+                    type="submit"
+                    class="text-xs font-medium text-white bg-zinc-900 hover:bg-zinc-800 rounded-lg px-3 py-1.5 transition-colors disabled:opacity-40 disabled:cursor-not-allowed"
+                    disabled=move || !can_add()
+                >
+                    {move || if is_thai() { "เพิ่ม" } else { "Add" }}
+                </button>
+            </div>
+        </form>
+    }
+}
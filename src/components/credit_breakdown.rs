@@ -0,0 +1,149 @@
+//! Credit Breakdown Component
+//!
+//! Donut chart of earned credits split by requirement type (GenEd, Major,
+//! Free Electives), drawn with plain SVG arcs, plus a legend with credit counts.
+
+use crate::models::{format_credits, Category};
+use leptos::*;
+
+/// Slice color for any category other than GenEd, Major and Free Electives
+/// (e.g. a minor).
+const OTHER_SLICE_COLOR: &str = "#8b5cf6";
+
+const RADIUS: f32 = 15.9155;
+const CENTER: f32 = 18.0;
+
+/// Converts category totals into `(start, end)` angles in degrees, clockwise
+/// from 12 o'clock. Slices are proportional to their share of the sum; when
+/// nothing has been earned every slice is empty (`start == end == 0`).
+pub fn slice_angles(totals: &[f32]) -> Vec<(f32, f32)> {
+    let sum: f32 = totals.iter().map(|t| t.max(0.0)).sum();
+    if sum <= 0.0 {
+        return vec![(0.0, 0.0); totals.len()];
+    }
+
+    let mut start = 0.0;
+    totals
+        .iter()
+        .map(|total| {
+            let end = start + total.max(0.0) / sum * 360.0;
+            let slice = (start, end);
+            start = end;
+            slice
+        })
+        .collect()
+}
+
+/// Slice and legend color of a category, fixed by name so reordering the
+/// cards never swaps colors.
+pub fn slice_color(category: &str) -> &'static str {
+    match category {
+        "General Education" => "#3b82f6",
+        "Major Courses" => "#10b981",
+        "Free Electives" => "#f59e0b",
+        _ => OTHER_SLICE_COLOR,
+    }
+}
+
+/// Point on the donut ring at `degrees` clockwise from 12 o'clock.
+fn ring_point(degrees: f32) -> (f32, f32) {
+    let radians = (degrees - 90.0).to_radians();
+    (
+        CENTER + RADIUS * radians.cos(),
+        CENTER + RADIUS * radians.sin(),
+    )
+}
+
+/// Donut chart of earned credits per top-level category
+#[component]
+pub fn CreditBreakdown(categories: Vec<Category>) -> impl IntoView {
+    let is_thai = use_context::<ReadSignal<bool>>();
+    let is_thai = move || is_thai.map(|s| s.get()).unwrap_or(false);
+
+    let totals: Vec<f32> = categories.iter().map(|c| c.collected_credits).collect();
+    let angles = slice_angles(&totals);
+
+    let slices = angles
+        .iter()
+        .zip(&categories)
+        .filter(|((start, end), _)| end > start)
+        .map(|(&(start, end), category)| {
+            let color = slice_color(&category.name);
+            if end - start >= 359.99 {
+                // A single full slice: an arc cannot start and end on the same point
+                view! {
+                    <circle cx=CENTER cy=CENTER r=RADIUS fill="none" stroke=color stroke-width="4"/>
+                }
+                .into_view()
+            } else {
+                let (x1, y1) = ring_point(start);
+                let (x2, y2) = ring_point(end);
+                let large_arc = if end - start > 180.0 { 1 } else { 0 };
+                view! {
+                    <path
+                        d=format!("M {x1} {y1} A {RADIUS} {RADIUS} 0 {large_arc} 1 {x2} {y2}")
+                        fill="none" stroke=color stroke-width="4"
+                    />
+                }
+                .into_view()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft p-5 flex items-center gap-6">
+            <svg class="w-28 h-28 shrink-0" viewBox="0 0 36 36">
+                <circle cx=CENTER cy=CENTER r=RADIUS fill="none" stroke="#e4e4e7" stroke-width="4"/>
+                {slices}
+            </svg>
+            <div class="flex-1 min-w-0">
+                <h3 class="text-sm font-semibold text-zinc-800 mb-3">{move || if is_thai() { "สัดส่วนหน่วยกิตที่ได้รับ" } else { "Credits by Requirement Type" }}</h3>
+                <ul class="space-y-2">
+                    {categories.into_iter().map(|category| {
+                        let color = slice_color(&category.name);
+                        let name = category.name.clone();
+                        view! {
+                            <li class="flex items-center gap-2.5 text-[13px]">
+                                <span class="w-2.5 h-2.5 rounded-full shrink-0" style=format!("background-color: {}", color)></span>
+                                <span class="text-zinc-700 flex-1 truncate">{move || crate::category_label(&name, is_thai())}</span>
+                                <span class="font-mono text-zinc-500 tabular-nums">
+                                    {move || format!("{} {}", format_credits(category.collected_credits), if is_thai() { "หน่วยกิต" } else { "cr" })}
+                                </span>
+                            </li>
+                        }
+                    }).collect::<Vec<_>>()}
+                </ul>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slices_are_proportional_to_category_totals() {
+        assert_eq!(
+            slice_angles(&[30.0, 60.0, 30.0]),
+            [(0.0, 90.0), (90.0, 270.0), (270.0, 360.0)]
+        );
+    }
+
+    #[test]
+    fn nothing_earned_gives_empty_slices() {
+        assert_eq!(slice_angles(&[0.0, 0.0, 0.0]), [(0.0, 0.0); 3]);
+    }
+
+    #[test]
+    fn colors_follow_the_category_not_its_position() {
+        assert_eq!(slice_color("General Education"), "#3b82f6");
+        assert_eq!(slice_color("Major Courses"), "#10b981");
+        assert_eq!(slice_color("Free Electives"), "#f59e0b");
+        assert_eq!(slice_color("Minor in Mathematics"), OTHER_SLICE_COLOR);
+        assert_ne!(
+            slice_color("Minor in Mathematics"),
+            slice_color("General Education")
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! GenEd Electives by Faculty
+//!
+//! Collapsible list of the GenEd elective options grouped by the faculty that
+//! offers them, each faculty shown with its code prefixes (e.g. Law, 874) so
+//! students can tell what an unfamiliar prefix means.
+
+use crate::data::faculties::faculty_for_prefix;
+use crate::models::{format_credits, GenEdCourse, GenEdElectives};
+use leptos::*;
+
+/// Group label for courses whose prefix has no known faculty.
+const OTHER_FACULTY: &str = "Other";
+
+/// Courses in one faculty group, with the distinct code prefixes seen.
+#[derive(Debug, Clone)]
+pub struct FacultyGroup {
+    pub faculty: String,
+    pub prefixes: Vec<String>, // In first-seen order
+    pub courses: Vec<GenEdCourse>,
+}
+
+/// Groups `courses` by `faculty_for_prefix`, keeping the order in which
+/// faculties first appear; courses with an unknown prefix go to a final
+/// "Other" group.
+pub fn group_by_faculty(courses: &[GenEdCourse]) -> Vec<FacultyGroup> {
+    let mut groups: Vec<FacultyGroup> = Vec::new();
+    let mut other: Option<FacultyGroup> = None;
+    for course in courses {
+        let prefix = course
+            .code
+            .split('-')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let group = match faculty_for_prefix(&course.code) {
+            Some(faculty) => match groups.iter().position(|g| g.faculty == faculty) {
+                Some(index) => &mut groups[index],
+                None => {
+                    groups.push(FacultyGroup {
+                        faculty: faculty.to_string(),
+                        prefixes: Vec::new(),
+                        courses: Vec::new(),
+                    });
+                    groups.last_mut().expect("group was just pushed")
+                }
+            },
+            None => other.get_or_insert_with(|| FacultyGroup {
+                faculty: OTHER_FACULTY.to_string(),
+                prefixes: Vec::new(),
+                courses: Vec::new(),
+            }),
+        };
+        if !group.prefixes.contains(&prefix) {
+            group.prefixes.push(prefix);
+        }
+        group.courses.push(course.clone());
+    }
+    groups.extend(other);
+    groups
+}
+
+/// Collapsible catalog of GenEd elective options grouped by faculty
+#[component]
+pub fn GenEdElectivesByFaculty(electives: GenEdElectives) -> impl IntoView {
+    let (is_expanded, set_is_expanded) = create_signal(false);
+    let is_thai = use_context::<ReadSignal<bool>>();
+    let is_thai = move || is_thai.map(|s| s.get()).unwrap_or(false);
+    let courses: Vec<GenEdCourse> = electives
+        .sub_categories
+        .iter()
+        .flat_map(|sub_category| sub_category.courses.iter().cloned())
+        .collect();
+    let groups = group_by_faculty(&courses);
+
+    view! {
+        <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft overflow-hidden">
+            <button
+                class="w-full px-5 py-4 flex items-center gap-2.5 text-left bg-zinc-50/50 text-zinc-800"
+                on:click=move |_| set_is_expanded.update(|v| *v = !*v)
+            >
+                <svg
+                    class={move || format!(
+                        "w-4 h-4 opacity-60 transition-transform duration-200 {}",
+                        if is_expanded.get() { "rotate-90" } else { "" }
+                    )}
+                    fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24"
+                >
+                    <path stroke-linecap="round" stroke-linejoin="round" d="M8.25 4.5l7.5 7.5-7.5 7.5" />
+                </svg>
+                <h3 class="text-sm font-semibold">{move || if is_thai() { "วิชาเลือกศึกษาทั่วไปตามคณะ" } else { "GenEd Electives by Faculty" }}</h3>
+            </button>
+            {move || is_expanded.get().then(|| view! {
+                <div class="border-t border-zinc-100 animate-fade-in">
+                    {groups.iter().map(|group| view! {
+                        <div class="px-5 py-3 border-b border-zinc-100 last:border-b-0">
+                            <p class="text-xs font-semibold text-zinc-700 mb-1.5">
+                                {group.faculty.clone()}
+                                <span class="font-mono text-2xs font-medium text-zinc-400 ml-2">{group.prefixes.join(", ")}</span>
+                            </p>
+                            <div class="space-y-1">
+                                {group.courses.iter().map(|course| view! {
+                                    <p class="text-[13px] text-zinc-600 truncate">
+                                        <span class="font-mono text-2xs font-semibold text-zinc-400 mr-2">{course.code.clone()}</span>
+                                        {course.name.clone()}
+                                        <span class="text-2xs text-zinc-400 ml-1.5">{format!("({} cr)", format_credits(course.credits))}</span>
+                                    </p>
+                                }).collect::<Vec<_>>()}
+                            </div>
+                        </div>
+                    }).collect::<Vec<_>>()}
+                </div>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn course(code: &str) -> GenEdCourse {
+        GenEdCourse {
+            code: code.to_string(),
+            name: "Course".to_string(),
+            credits: 2.0,
+        }
+    }
+
+    #[test]
+    fn courses_group_by_faculty_with_unknown_prefixes_last() {
+        let groups = group_by_faculty(&[
+            course("999-101"),
+            course("890-101"),
+            course("874-193"),
+            course("895-125"),
+        ]);
+
+        let summary: Vec<_> = groups
+            .iter()
+            .map(|g| (g.faculty.as_str(), g.prefixes.clone(), g.courses.len()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    "Liberal Arts",
+                    vec!["890".to_string(), "895".to_string()],
+                    2
+                ),
+                ("Law", vec!["874".to_string()], 1),
+                ("Other", vec!["999".to_string()], 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Graduation Report Component
+//!
+//! Single-page advising report built from an audit result: student summary,
+//! per-category completion and the missing list grouped by category.
+//! Laid out for printing on one A4 page.
+
+use crate::logic::advisory::GraduationStatus;
+use crate::logic::checklist::group_missing;
+use crate::models::{format_credits, AuditResult};
+use leptos::*;
+
+/// Printable advising report for a full audit result
+#[component]
+pub fn GraduationReport(
+    result: AuditResult,
+    gpa: Option<f32>,
+    status: GraduationStatus,
+) -> impl IntoView {
+    let is_thai = use_context::<ReadSignal<bool>>();
+    let is_thai = move || is_thai.map(|s| s.get()).unwrap_or(false);
+
+    let eligible = status == GraduationStatus::Eligible;
+    let blockers = match status {
+        GraduationStatus::Eligible => Vec::new(),
+        GraduationStatus::NotEligible { blockers } => blockers,
+    };
+    let missing_groups = group_missing(&result.missing_subjects);
+
+    view! {
+        <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft p-6 sm:p-8 flex flex-col gap-6 print:border-0 print:shadow-none print:p-0">
+            // ── Student Summary ─────────────────────────────────────
+            <section>
+                <h2 class="text-base font-semibold text-zinc-900 tracking-tight">{move || if is_thai() { "รายงานตรวจสอบการสำเร็จการศึกษา" } else { "Graduation Checklist" }}</h2>
+                <p class="text-2xs text-zinc-400 mt-0.5">{move || if is_thai() { "วท.บ. (วิทยาการคอมพิวเตอร์)" } else { "B.Sc. (Computer Science)" }}</p>
+                <dl class="grid grid-cols-3 gap-4 mt-4 text-[13px]">
+                    <div>
+                        <dt class="text-zinc-500">{move || if is_thai() { "หน่วยกิตรวม" } else { "Total credits" }}</dt>
+                        <dd class="font-semibold text-zinc-900 tabular-nums">{format_credits(result.total_credits)}</dd>
+                    </div>
+                    <div>
+                        <dt class="text-zinc-500">{move || if is_thai() { "เกรดเฉลี่ยสะสม" } else { "Cumulative GPA" }}</dt>
+                        <dd class="font-semibold text-zinc-900 tabular-nums">{gpa.map(|g| format!("{:.2}", g)).unwrap_or_else(|| "-".to_string())}</dd>
+                    </div>
+                    <div>
+                        <dt class="text-zinc-500">{move || if is_thai() { "สถานะ" } else { "Status" }}</dt>
+                        <dd class={if eligible { "font-semibold text-emerald-700" } else { "font-semibold text-red-700" }}>
+                            {move || match (is_thai(), eligible) {
+                                (true, true) => "พร้อมสำเร็จการศึกษา",
+                                (true, false) => "ยังไม่ครบเกณฑ์",
+                                (false, true) => "Eligible to graduate",
+                                (false, false) => "Not yet eligible",
+                            }}
+                        </dd>
+                    </div>
+                </dl>
+                {(!blockers.is_empty()).then(|| view! {
+                    <ul class="mt-3 list-disc list-inside text-2xs text-zinc-500 space-y-0.5">
+                        {blockers.into_iter().map(|b| view! { <li>{b}</li> }).collect::<Vec<_>>()}
+                    </ul>
+                })}
+            </section>
+
+            // ── Categories ──────────────────────────────────────────
+            <section>
+                <h3 class="text-xs font-semibold text-zinc-500 uppercase tracking-widest mb-2">{move || if is_thai() { "หมวดวิชา" } else { "Categories" }}</h3>
+                <table class="w-full text-[13px]">
+                    <tbody class="divide-y divide-zinc-100">
+                        {result.categories.iter().map(|cat| {
+                            let name = cat.name.clone();
+                            let done = cat.collected_credits >= cat.required_credits;
+                            view! {
+                                <tr>
+                                    <td class="py-1.5 text-zinc-700">{move || crate::category_label(&name, is_thai())}</td>
+                                    <td class="py-1.5 text-right font-mono tabular-nums text-zinc-600">
+                                        {format!("{} / {}", format_credits(cat.collected_credits), format_credits(cat.required_credits))}
+                                    </td>
+                                    <td class={format!("py-1.5 pl-3 w-6 text-right {}", if done { "text-emerald-600" } else { "text-zinc-300" })}>
+                                        {if done { "✓" } else { "○" }}
+                                    </td>
+                                </tr>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </tbody>
+                </table>
+            </section>
+
+            // ── Missing Requirements ────────────────────────────────
+            <section>
+                <h3 class="text-xs font-semibold text-zinc-500 uppercase tracking-widest mb-2">{move || if is_thai() { "ข้อกำหนดที่ขาด" } else { "Missing Requirements" }}</h3>
+                {if missing_groups.is_empty() {
+                    view! {
+                        <p class="text-[13px] text-zinc-500">{move || if is_thai() { "ไม่มี" } else { "None" }}</p>
+                    }.into_view()
+                } else {
+                    missing_groups.into_iter().map(|(category, items)| view! {
+                        <div class="mb-3">
+                            <p class="text-[13px] font-semibold text-zinc-700">{move || crate::category_label(&category, is_thai())}</p>
+                            <ul class="mt-1 list-disc list-inside text-[13px] text-zinc-600 space-y-0.5">
+                                {items.into_iter().map(|item| view! { <li>{item}</li> }).collect::<Vec<_>>()}
+                            </ul>
+                        </div>
+                    }).collect::<Vec<_>>().into_view()
+                }}
+            </section>
+        </div>
+    }
+}
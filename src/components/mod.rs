@@ -1 +1,14 @@
 pub mod category_card;
+pub mod category_order;
+pub mod cluster_board;
+pub mod course_editor;
+pub mod credit_breakdown;
+pub mod faculty_groups;
+pub mod graduation_report;
+pub mod notice_panel;
+pub mod requirements_table;
+pub mod shared_summary;
+pub mod substitution_editor;
+pub mod term_planner;
+pub mod toast;
+pub mod viewport;
@@ -0,0 +1,93 @@
+//! Notice Panel Component
+//!
+//! Collapsible panel listing courses that deserve the student's attention
+//! without affecting credits (e.g., courses excluded from free electives).
+
+use leptos::*;
+
+/// Visual tone of a notice panel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NoticeTone {
+    Info,
+    Warning,
+}
+
+/// A single row inside a notice panel.
+#[derive(Debug, Clone)]
+pub struct NoticeItem {
+    pub code: String,
+    pub name: String,
+    pub detail_en: String,
+    pub detail_th: String,
+}
+
+/// Collapsible list of course notices with a bilingual title
+#[component]
+pub fn NoticePanel(
+    title_en: &'static str,
+    title_th: &'static str,
+    tone: NoticeTone,
+    items: Vec<NoticeItem>,
+) -> impl IntoView {
+    let (is_expanded, set_is_expanded) = create_signal(false);
+    let is_thai = use_context::<ReadSignal<bool>>();
+    let is_thai = move || is_thai.map(|s| s.get()).unwrap_or(false);
+    let count = items.len();
+
+    let (border_class, header_class, badge_class, dot_class) = match tone {
+        NoticeTone::Info => (
+            "border-zinc-200/80",
+            "bg-zinc-50/50 text-zinc-800",
+            "text-zinc-600 bg-zinc-100",
+            "bg-zinc-300",
+        ),
+        NoticeTone::Warning => (
+            "border-amber-200/60",
+            "bg-amber-50/50 text-amber-800",
+            "text-amber-700 bg-amber-100",
+            "bg-amber-400",
+        ),
+    };
+
+    view! {
+        <div class={format!("bg-white rounded-2xl border shadow-soft overflow-hidden {}", border_class)}>
+            <button
+                class={format!("w-full px-5 py-4 flex items-center gap-2.5 text-left {}", header_class)}
+                on:click=move |_| set_is_expanded.update(|v| *v = !*v)
+            >
+                <svg
+                    class={move || format!(
+                        "w-4 h-4 opacity-60 transition-transform duration-200 {}",
+                        if is_expanded.get() { "rotate-90" } else { "" }
+                    )}
+                    fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24"
+                >
+                    <path stroke-linecap="round" stroke-linejoin="round" d="M8.25 4.5l7.5 7.5-7.5 7.5" />
+                </svg>
+                <h3 class="text-sm font-semibold">{move || if is_thai() { title_th } else { title_en }}</h3>
+                <span class={format!("ml-auto text-2xs font-semibold px-2 py-0.5 rounded-full {}", badge_class)}>
+                    {move || if is_thai() { format!("{} รายการ", count) } else { format!("{} items", count) }}
+                </span>
+            </button>
+            {move || is_expanded.get().then(|| view! {
+                <div class="divide-y divide-zinc-100/80 border-t border-zinc-100 animate-fade-in">
+                    {items.iter().map(|item| {
+                        let item = item.clone();
+                        view! {
+                            <div class="flex items-start gap-2.5 px-5 py-2.5">
+                                <div class={format!("w-1.5 h-1.5 rounded-full mt-1.5 shrink-0 {}", dot_class)}></div>
+                                <div class="min-w-0">
+                                    <p class="text-[13px] text-zinc-700 truncate">
+                                        <span class="font-mono text-2xs font-semibold text-zinc-400 mr-2">{item.code.clone()}</span>
+                                        {item.name.clone()}
+                                    </p>
+                                    <p class="text-2xs text-zinc-400 mt-0.5">{move || if is_thai() { item.detail_th.clone() } else { item.detail_en.clone() }}</p>
+                                </div>
+                            </div>
+                        }
+                    }).collect::<Vec<_>>()}
+                </div>
+            })}
+        </div>
+    }
+}
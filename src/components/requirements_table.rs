@@ -0,0 +1,223 @@
+//! Requirements Table Component
+//!
+//! Dense table of every requirement group (categories, GenEd strands and
+//! sub-groups, elective clusters) with required, completed and remaining
+//! amounts. A compact alternative to the category cards for advisors.
+
+use crate::models::{format_credits, Category, ClusterProgress, StrandProgress};
+use leptos::*;
+
+/// What a row's amounts count.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RequirementUnit {
+    Credits,
+    Courses, // Elective clusters are completed by course count
+}
+
+/// One requirement group in the table. `depth` indents nested groups
+/// (0 category, 1 strand or cluster, 2 strand sub-group).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequirementRow {
+    pub name: String,
+    pub depth: u8,
+    pub required: f32,
+    pub completed: f32,
+    pub unit: RequirementUnit,
+}
+
+impl RequirementRow {
+    /// Amount still needed, never negative.
+    pub fn remaining(&self) -> f32 {
+        (self.required - self.completed).max(0.0)
+    }
+}
+
+/// Table rows in display order: each category, followed by the GenEd strands
+/// (with sub-groups) under General Education and the elective clusters under
+/// Major Courses.
+pub fn requirement_rows(
+    categories: &[Category],
+    strands: &[StrandProgress],
+    clusters: &[ClusterProgress],
+) -> Vec<RequirementRow> {
+    let credits = |name: &str, depth, required, completed| RequirementRow {
+        name: name.to_string(),
+        depth,
+        required,
+        completed,
+        unit: RequirementUnit::Credits,
+    };
+
+    let mut rows = Vec::new();
+    for category in categories {
+        rows.push(credits(
+            &category.name,
+            0,
+            category.required_credits,
+            category.collected_credits,
+        ));
+        match category.name.as_str() {
+            "General Education" => {
+                for strand in strands {
+                    rows.push(credits(&strand.name, 1, strand.required, strand.completed));
+                    for sub_group in &strand.sub_groups {
+                        rows.push(credits(
+                            &sub_group.name,
+                            2,
+                            sub_group.required,
+                            sub_group.completed,
+                        ));
+                    }
+                }
+            }
+            "Major Courses" => {
+                rows.extend(clusters.iter().map(|cluster| RequirementRow {
+                    name: format!("Cluster {} {}", cluster.cluster_id, cluster.name),
+                    depth: 1,
+                    required: cluster.required_courses as f32,
+                    completed: cluster.completed_courses as f32,
+                    unit: RequirementUnit::Courses,
+                }));
+            }
+            _ => {}
+        }
+    }
+    rows
+}
+
+/// Required / completed / remaining table over all requirement groups
+#[component]
+pub fn RequirementsTable(
+    categories: Vec<Category>,
+    strands: Vec<StrandProgress>,
+    clusters: Vec<ClusterProgress>,
+) -> impl IntoView {
+    let is_thai = use_context::<ReadSignal<bool>>();
+    let is_thai = move || is_thai.map(|s| s.get()).unwrap_or(false);
+
+    let rows = requirement_rows(&categories, &strands, &clusters);
+    let amount = move |value: f32, unit: RequirementUnit| {
+        move || match (unit, is_thai()) {
+            (RequirementUnit::Credits, _) => format_credits(value),
+            (RequirementUnit::Courses, true) => format!("{} วิชา", value),
+            (RequirementUnit::Courses, false) => format!("{} courses", value),
+        }
+    };
+
+    view! {
+        <div class="overflow-x-auto">
+            <table class="w-full text-[13px]">
+                <thead>
+                    <tr class="text-2xs font-semibold text-zinc-400 uppercase tracking-wider text-right">
+                        <th class="px-5 py-2 text-left">{move || if is_thai() { "ข้อกำหนด" } else { "Requirement" }}</th>
+                        <th class="px-3 py-2">{move || if is_thai() { "ต้องการ" } else { "Required" }}</th>
+                        <th class="px-3 py-2">{move || if is_thai() { "ได้แล้ว" } else { "Completed" }}</th>
+                        <th class="px-3 py-2">{move || if is_thai() { "คงเหลือ" } else { "Remaining" }}</th>
+                        <th class="px-5 py-2 w-8"></th>
+                    </tr>
+                </thead>
+                <tbody class="divide-y divide-zinc-100">
+                    {rows.into_iter().map(|row| {
+                        let complete = row.remaining() <= 0.0;
+                        let name_class = match row.depth {
+                            0 => "px-5 py-2 font-semibold text-zinc-800",
+                            1 => "px-5 py-2 pl-9 text-zinc-700",
+                            _ => "px-5 py-2 pl-14 text-2xs text-zinc-500",
+                        };
+                        view! {
+                            <tr class="font-mono tabular-nums text-right text-zinc-600">
+                                <td class={format!("font-sans text-left truncate max-w-xs {}", name_class)} title=row.name.clone()>
+                                    {row.name.clone()}
+                                </td>
+                                <td class="px-3 py-2">{amount(row.required, row.unit)}</td>
+                                <td class="px-3 py-2">{amount(row.completed, row.unit)}</td>
+                                <td class={if complete { "px-3 py-2 text-zinc-300" } else { "px-3 py-2 text-amber-600" }}>
+                                    {amount(row.remaining(), row.unit)}
+                                </td>
+                                <td class="px-5 py-2 text-center">
+                                    {if complete {
+                                        view! { <span class="text-emerald-500" title="Complete">"✓"</span> }
+                                    } else {
+                                        view! { <span class="text-zinc-300" title="Incomplete">"○"</span> }
+                                    }}
+                                </td>
+                            </tr>
+                        }
+                    }).collect::<Vec<_>>()}
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{gen_ed::get_gen_ed_curriculum, major::get_major_curriculum};
+    use crate::logic::auditor::audit_transcript;
+    use crate::logic::parser::{parse_transcript, ParseOptions};
+    use crate::models::AuditOptions;
+
+    #[test]
+    fn rows_cover_every_group_in_display_order() {
+        let courses = parse_transcript(
+            "322-101 CALCULUS I 01 3 B+\n344-431 BIG DATA 01 3 A\n890-102 EVERYDAY ENGLISH 01 2 A",
+            &ParseOptions::default(),
+        )
+        .unwrap();
+        let result = audit_transcript(
+            &courses,
+            &get_gen_ed_curriculum(),
+            &get_major_curriculum(),
+            &AuditOptions::default(),
+        );
+
+        let rows = requirement_rows(
+            &result.categories,
+            &result.gen_ed_strands,
+            &result.cluster_progress,
+        );
+
+        let sub_groups: usize = result
+            .gen_ed_strands
+            .iter()
+            .map(|s| s.sub_groups.len())
+            .sum();
+        assert_eq!(
+            rows.len(),
+            result.categories.len()
+                + result.gen_ed_strands.len()
+                + sub_groups
+                + result.cluster_progress.len()
+        );
+        assert_eq!(rows[0].name, "General Education");
+        assert_eq!(rows[1].depth, 1);
+        for category in &result.categories {
+            assert!(rows
+                .iter()
+                .any(|row| row.name == category.name && row.depth == 0));
+        }
+        assert!(rows.iter().all(|row| row.remaining() >= 0.0));
+    }
+
+    #[test]
+    fn remaining_is_never_negative() {
+        let row = RequirementRow {
+            name: "Free Electives".to_string(),
+            depth: 0,
+            required: 6.0,
+            completed: 9.0,
+            unit: RequirementUnit::Credits,
+        };
+
+        assert_eq!(row.remaining(), 0.0);
+        assert_eq!(
+            RequirementRow {
+                completed: 2.5,
+                ..row
+            }
+            .remaining(),
+            3.5
+        );
+    }
+}
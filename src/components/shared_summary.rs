@@ -0,0 +1,75 @@
+//! Shared Summary Component
+//!
+//! Read-only progress view rendered from a `#s=<payload>` share link.
+//! Shows only totals and per-category percentages — never course data.
+
+use crate::logic::share::SharedSummary;
+use crate::models::{format_credits, format_percent};
+use leptos::*;
+
+/// Read-only card for a decoded share link; `None` renders an invalid-link notice
+#[component]
+pub fn SharedSummaryView(summary: Option<SharedSummary>) -> impl IntoView {
+    let is_thai = use_context::<ReadSignal<bool>>();
+    let is_thai = move || is_thai.map(|s| s.get()).unwrap_or(false);
+
+    let Some(summary) = summary else {
+        return view! {
+            <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft h-full min-h-[500px] flex flex-col items-center justify-center gap-2 px-8 text-center">
+                <p class="text-sm font-semibold text-zinc-800">{move || if is_thai() { "ลิงก์สรุปผลไม่ถูกต้อง" } else { "Invalid summary link" }}</p>
+                <p class="text-[13px] text-zinc-500 max-w-sm leading-relaxed">{move || if is_thai() { "ลิงก์นี้อาจถูกตัดหรือแก้ไข กรุณาขอลิงก์ใหม่จากเจ้าของ" } else { "This link may have been truncated or edited. Ask the owner for a fresh link." }}</p>
+            </div>
+        }
+        .into_view();
+    };
+
+    let graduation_ready = summary.graduation_ready;
+
+    view! {
+        <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft p-6 sm:p-8 flex flex-col gap-6 animate-fade-in">
+            <div class="flex items-start justify-between gap-4">
+                <div>
+                    <p class="text-xs font-semibold text-brand-600 uppercase tracking-widest mb-1">{move || if is_thai() { "สรุปผลที่แชร์" } else { "Shared Summary" }}</p>
+                    <div class="flex items-baseline gap-2">
+                        <span class="text-5xl font-extrabold tracking-tighter text-zinc-900 tabular-nums">{format_credits(summary.total_credits)}</span>
+                        <span class="text-base font-medium text-zinc-400">{move || if is_thai() { "หน่วยกิตที่ได้รับ" } else { "credits earned" }}</span>
+                    </div>
+                </div>
+                <span class={format!(
+                    "text-2xs font-semibold px-2 py-0.5 rounded-full {}",
+                    if graduation_ready { "bg-emerald-100 text-emerald-700" } else { "bg-brand-50 text-brand-600" }
+                )}>
+                    {move || match (is_thai(), graduation_ready) {
+                        (true, true) => "พร้อมสำเร็จการศึกษา",
+                        (true, false) => "กำลังดำเนินการ",
+                        (false, true) => "Ready to Graduate",
+                        (false, false) => "In Progress",
+                    }}
+                </span>
+            </div>
+            <div class="space-y-3">
+                {summary.categories.iter().map(|category| {
+                    let name = category.name.clone();
+                    let display_name = move || crate::category_label(&name, is_thai());
+                    let percent = category.percent;
+                    view! {
+                        <div>
+                            <div class="flex items-center justify-between text-[13px] mb-1">
+                                <span class="font-medium text-zinc-700">{display_name}</span>
+                                <span class="font-mono text-zinc-500 tabular-nums">{format_percent(percent)}</span>
+                            </div>
+                            <div class="w-full bg-zinc-100 rounded-full h-1.5 overflow-hidden">
+                                <div
+                                    class={format!("h-full rounded-full progress-animated {}", if percent >= 100.0 { "bg-emerald-500" } else { "bg-brand-500" })}
+                                    style={format!("width: {}%", percent)}
+                                ></div>
+                            </div>
+                        </div>
+                    }
+                }).collect::<Vec<_>>()}
+            </div>
+            <p class="text-2xs text-zinc-400">{move || if is_thai() { "ลิงก์นี้มีเฉพาะสรุปความคืบหน้า ไม่มีรายชื่อวิชาหรือเกรด" } else { "This link contains only progress totals — no course names or grades." }}</p>
+        </div>
+    }
+    .into_view()
+}
@@ -0,0 +1,133 @@
+//! Substitution Editor Component
+//!
+//! Records advisor-approved petitions where a taken course fills a different
+//! requirement (e.g. an elective standing in for a core course). The list is
+//! kept in localStorage so it survives reloads; the parent re-audits.
+
+use crate::logic::storage::{load_json, local_storage};
+use crate::models::{canonical_code, CourseCode};
+use leptos::*;
+use std::collections::HashMap;
+
+/// localStorage key holding the substitutions as a JSON object.
+const SUBSTITUTIONS_STORAGE_KEY: &str = "course-audit.substitutions";
+
+/// Loads saved substitutions (taken code -> satisfied code); empty when none
+/// are stored or the stored value is unreadable (and then discarded).
+pub fn load_substitutions() -> HashMap<String, String> {
+    local_storage()
+        .and_then(|storage| load_json(&storage, SUBSTITUTIONS_STORAGE_KEY))
+        .unwrap_or_default()
+}
+
+/// Saves substitutions, ignoring storage failures (private mode, quota).
+pub fn save_substitutions(substitutions: &HashMap<String, String>) {
+    if let (Some(storage), Ok(json)) = (local_storage(), serde_json::to_string(substitutions)) {
+        let _ = storage.set_item(SUBSTITUTIONS_STORAGE_KEY, &json);
+    }
+}
+
+/// List of approved substitutions with an inline form to add more
+#[component]
+pub fn SubstitutionEditor(
+    #[prop(into)] substitutions: Signal<HashMap<String, String>>,
+    #[prop(into)] on_change: Callback<HashMap<String, String>>,
+) -> impl IntoView {
+    let is_thai = use_context::<ReadSignal<bool>>();
+    let is_thai = move || is_thai.map(|s| s.get()).unwrap_or(false);
+
+    let (taken, set_taken) = create_signal(String::new());
+    let (satisfies, set_satisfies) = create_signal(String::new());
+
+    let can_add = move || {
+        let (Some(taken), Some(satisfies)) = (
+            CourseCode::parse(&taken.get()),
+            CourseCode::parse(&satisfies.get()),
+        ) else {
+            return false;
+        };
+        taken != satisfies
+    };
+
+    let on_submit = move |ev: ev::SubmitEvent| {
+        ev.prevent_default();
+        if !can_add() {
+            return;
+        }
+        let mut updated = substitutions.get_untracked();
+        updated.insert(
+            canonical_code(&taken.get_untracked()),
+            canonical_code(&satisfies.get_untracked()),
+        );
+        on_change.call(updated);
+        set_taken.set(String::new());
+        set_satisfies.set(String::new());
+    };
+
+    let sorted = move || {
+        let mut entries: Vec<(String, String)> = substitutions.get().into_iter().collect();
+        entries.sort();
+        entries
+    };
+
+    let input_class = "text-[13px] font-mono px-2.5 py-1.5 rounded-lg border border-zinc-200 bg-white focus:outline-none focus:ring-2 focus:ring-brand-500/40 min-w-0 flex-1";
+
+    view! {
+        <form class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft p-5 flex flex-col gap-3" on:submit=on_submit>
+            <div>
+                <h3 class="text-sm font-semibold text-zinc-800">{move || if is_thai() { "การเทียบวิชาที่อนุมัติ" } else { "Approved Substitutions" }}</h3>
+                <p class="text-2xs text-zinc-400 mt-0.5">{move || if is_thai() { "วิชาที่ยื่นคำร้องให้นับแทนวิชาในหลักสูตร" } else { "Courses petitioned to satisfy a different requirement" }}</p>
+            </div>
+            {move || {
+                let entries = sorted();
+                (!entries.is_empty()).then(|| view! {
+                    <ul class="flex flex-col gap-1.5">
+                        {entries.into_iter().map(|(from, to)| {
+                            let key = from.clone();
+                            view! {
+                                <li class="flex items-center gap-2 text-[13px] text-zinc-700">
+                                    <span class="font-mono">{from}</span>
+                                    <span class="text-zinc-400">"→"</span>
+                                    <span class="font-mono">{to}</span>
+                                    <button
+                                        type="button"
+                                        class="ml-auto text-2xs text-zinc-400 hover:text-red-600 transition-colors"
+                                        on:click=move |_| {
+                                            let mut updated = substitutions.get_untracked();
+                                            updated.remove(&key);
+                                            on_change.call(updated);
+                                        }
+                                    >
+                                        {move || if is_thai() { "ลบ" } else { "Remove" }}
+                                    </button>
+                                </li>
+                            }
+                        }).collect::<Vec<_>>()}
+                    </ul>
+                })
+            }}
+            <div class="flex items-center gap-2">
+                <input
+                    class=input_class
+                    placeholder=move || if is_thai() { "วิชาที่เรียน" } else { "Taken" }
+                    prop:value=taken
+                    on:input=move |ev| set_taken.set(event_target_value(&ev))
+                />
+                <span class="text-zinc-400 text-sm">"→"</span>
+                <input
+                    class=input_class
+                    placeholder=move || if is_thai() { "นับแทน" } else { "Counts as" }
+                    prop:value=satisfies
+                    on:input=move |ev| set_satisfies.set(event_target_value(&ev))
+                />
+            </div>
+            <button
+                type="submit"
+                class="self-end text-xs font-medium text-white bg-zinc-900 hover:bg-zinc-800 rounded-lg px-3 py-1.5 transition-colors disabled:opacity-40 disabled:cursor-not-allowed"
+                disabled=move || !can_add()
+            >
+                {move || if is_thai() { "เพิ่ม" } else { "Add" }}
+            </button>
+        </form>
+    }
+}
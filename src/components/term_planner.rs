@@ -0,0 +1,109 @@
+//! Term Planner Component
+//!
+//! Turns the remaining credits into a per-semester load for a chosen number
+//! of semesters left before graduation, and the GPA needed over next
+//! semester's load to reach a target cumulative GPA.
+
+use crate::logic::advisory::{credits_per_term, required_future_gpa, MAX_GRADE_POINT};
+use crate::models::format_credits;
+use leptos::*;
+
+/// Semesters left assumed until the student changes it.
+const DEFAULT_TERMS_LEFT: u32 = 2;
+
+/// Target cumulative GPA assumed until the student changes it.
+const DEFAULT_TARGET_GPA: f32 = 2.5;
+
+/// Remaining-credit estimator with a semesters-left input. With
+/// `grade_totals` (grade points, graded credits) it also shows the GPA needed
+/// next semester to reach a target; without a course list it is omitted.
+#[component]
+pub fn TermPlanner(remaining: f32, grade_totals: Option<(f32, f32)>) -> impl IntoView {
+    let is_thai = use_context::<ReadSignal<bool>>();
+    let is_thai = move || is_thai.map(|s| s.get()).unwrap_or(false);
+
+    let (terms_left, set_terms_left) = create_signal(DEFAULT_TERMS_LEFT);
+    let (target_gpa, set_target_gpa) = create_signal(DEFAULT_TARGET_GPA);
+
+    // Next semester's load: the per-term estimate, or everything when no
+    // terms are left
+    let planned_credits = move || credits_per_term(remaining, terms_left.get()).ceil();
+
+    let target_summary = move |(points, credits): (f32, f32)| {
+        let planned = planned_credits();
+        let target = target_gpa.get();
+        let needed = required_future_gpa(points, credits, planned, target);
+        match (is_thai(), needed > MAX_GRADE_POINT, needed <= 0.0) {
+            (true, true, _) => format!(
+                "เกรดเฉลี่ยสะสม {:.2} เป็นไปไม่ได้ในภาคเรียนหน้า แม้ได้ A ทุกวิชา",
+                target
+            ),
+            (false, true, _) => format!(
+                "A {:.2} cumulative GPA is out of reach next semester, even with straight A's",
+                target
+            ),
+            (true, false, true) => format!("เกรดเฉลี่ยสะสมจะไม่ต่ำกว่า {:.2} แน่นอน", target),
+            (false, false, true) => format!("Your cumulative GPA stays at {:.2} or above", target),
+            (true, false, false) => format!(
+                "ต้องได้เกรดเฉลี่ย {:.2} ใน {} หน่วยกิตภาคเรียนหน้า เพื่อให้เกรดเฉลี่ยสะสมถึง {:.2}",
+                needed,
+                format_credits(planned),
+                target
+            ),
+            (false, false, false) => format!(
+                "You need a {:.2} GPA over the next {} credits to reach {:.2} overall",
+                needed,
+                format_credits(planned),
+                target
+            ),
+        }
+    };
+
+    let summary = move || {
+        let terms = terms_left.get();
+        // Whole credits: a student cannot register for half a credit
+        let per_term = format_credits(credits_per_term(remaining, terms).ceil());
+        match (is_thai(), terms) {
+            (true, 0) => format!("ต้องเรียนอีก {} หน่วยกิตทั้งหมดในตอนนี้", format_credits(remaining)),
+            (false, 0) => format!(
+                "All {} remaining credits are due now",
+                format_credits(remaining)
+            ),
+            (true, _) => format!(
+                "ต้องเรียนประมาณ {} หน่วยกิต/ภาคเรียน อีก {} ภาคเรียน",
+                per_term, terms
+            ),
+            (false, _) => format!(
+                "You need ~{} credits/semester for {} more semesters",
+                per_term, terms
+            ),
+        }
+    };
+
+    view! {
+        <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft px-5 py-4 flex flex-col sm:flex-row sm:items-center gap-3">
+            <label class="flex items-center gap-2 text-xs text-zinc-500 shrink-0">
+                {move || if is_thai() { "ภาคเรียนที่เหลือ" } else { "Semesters left" }}
+                <input
+                    class="w-16 text-[13px] font-mono px-2.5 py-1.5 rounded-lg border border-zinc-200 bg-white focus:outline-none focus:ring-2 focus:ring-brand-500/40"
+                    type="number" min="0" step="1"
+                    prop:value=move || terms_left.get().to_string()
+                    on:input=move |ev| set_terms_left.set(event_target_value(&ev).parse().unwrap_or(0))
+                />
+            </label>
+            <p class="text-[13px] text-zinc-700">{summary}</p>
+            {grade_totals.map(|totals| view! {
+                <label class="flex items-center gap-2 text-xs text-zinc-500 shrink-0 sm:ml-auto">
+                    {move || if is_thai() { "เกรดเฉลี่ยเป้าหมาย" } else { "Target GPA" }}
+                    <input
+                        class="w-20 text-[13px] font-mono px-2.5 py-1.5 rounded-lg border border-zinc-200 bg-white focus:outline-none focus:ring-2 focus:ring-brand-500/40"
+                        type="number" min="0" max="4" step="0.05"
+                        prop:value=move || format!("{:.2}", target_gpa.get())
+                        on:change=move |ev| set_target_gpa.set(event_target_value(&ev).parse().unwrap_or(DEFAULT_TARGET_GPA))
+                    />
+                </label>
+                <p class="text-[13px] text-zinc-700">{move || target_summary(totals)}</p>
+            })}
+        </div>
+    }
+}
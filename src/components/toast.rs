@@ -0,0 +1,138 @@
+//! Toast Component
+//!
+//! Short, auto-dismissing messages for transient feedback (e.g. results
+//! saved, analysis failed). The queue lives in a signal provided as context
+//! so any handler can push a toast.
+
+use leptos::*;
+
+/// Most toasts shown at once; pushing more drops the oldest.
+pub const TOAST_CAPACITY: usize = 3;
+
+/// How long a toast stays on screen, in milliseconds.
+const TOAST_DURATION_MS: u32 = 4000;
+
+/// Visual style of a toast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Success,
+    Error,
+}
+
+/// A single queued message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub id: u64,
+    pub kind: ToastKind,
+    pub message: String,
+}
+
+/// Visible toasts, oldest first, capped at `TOAST_CAPACITY`.
+#[derive(Debug, Clone, Default)]
+pub struct ToastQueue {
+    pub toasts: Vec<Toast>,
+    next_id: u64,
+}
+
+impl ToastQueue {
+    /// Appends a toast and returns its id, dropping the oldest when full.
+    pub fn push(&mut self, kind: ToastKind, message: impl Into<String>) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.toasts.push(Toast {
+            id,
+            kind,
+            message: message.into(),
+        });
+        if self.toasts.len() > TOAST_CAPACITY {
+            self.toasts.remove(0);
+        }
+        id
+    }
+
+    /// Removes the toast with `id`; unknown ids are ignored.
+    pub fn dismiss(&mut self, id: u64) {
+        self.toasts.retain(|toast| toast.id != id);
+    }
+}
+
+/// Toast queue shared through context. Provided by the app.
+#[derive(Debug, Clone, Copy)]
+pub struct Toasts(pub RwSignal<ToastQueue>);
+
+impl Toasts {
+    /// Shows a toast and schedules its dismissal.
+    pub fn show(&self, kind: ToastKind, message: impl Into<String>) {
+        let queue = self.0;
+        let mut id = 0;
+        queue.update(|q| id = q.push(kind, message));
+        spawn_local(async move {
+            gloo_timers::future::TimeoutFuture::new(TOAST_DURATION_MS).await;
+            queue.update(|q| q.dismiss(id));
+        });
+    }
+}
+
+/// Fixed stack of toasts in the bottom-right corner
+#[component]
+pub fn ToastHost(toasts: Toasts) -> impl IntoView {
+    let queue = toasts.0;
+
+    view! {
+        <div class="print:hidden fixed bottom-4 right-4 z-[60] flex flex-col gap-2 w-72 max-w-[calc(100vw-2rem)]" aria-live="polite">
+            <For
+                each=move || queue.get().toasts
+                key=|toast| toast.id
+                children=move |toast| {
+                    let id = toast.id;
+                    let tone = match toast.kind {
+                        ToastKind::Success => "bg-emerald-50 border-emerald-200/60 text-emerald-800",
+                        ToastKind::Error => "bg-red-50 border-red-200/60 text-red-800",
+                    };
+                    view! {
+                        <div class={format!("flex items-start gap-2.5 px-4 py-3 rounded-xl border shadow-medium text-[13px] animate-scale-in {}", tone)}>
+                            <p class="flex-1 leading-relaxed">{toast.message}</p>
+                            <button
+                                class="opacity-60 hover:opacity-100 text-sm leading-none"
+                                on:click=move |_| queue.update(|q| q.dismiss(id))
+                            >
+                                "×"
+                            </button>
+                        </div>
+                    }
+                }
+            />
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(queue: &ToastQueue) -> Vec<&str> {
+        queue.toasts.iter().map(|t| t.message.as_str()).collect()
+    }
+
+    #[test]
+    fn push_adds_and_dismiss_removes() {
+        let mut queue = ToastQueue::default();
+        let saved = queue.push(ToastKind::Success, "Saved");
+        queue.push(ToastKind::Error, "Upload failed");
+
+        queue.dismiss(saved);
+
+        assert_eq!(messages(&queue), ["Upload failed"]);
+    }
+
+    #[test]
+    fn oldest_toasts_drop_past_capacity() {
+        let mut queue = ToastQueue::default();
+        for i in 0..5 {
+            queue.push(ToastKind::Success, format!("Toast {}", i));
+        }
+
+        assert_eq!(queue.toasts.len(), TOAST_CAPACITY);
+        assert_eq!(messages(&queue), ["Toast 2", "Toast 3", "Toast 4"]);
+    }
+}
@@ -0,0 +1,62 @@
+//! Viewport Tracking
+//!
+//! Reports whether the window is below the compact breakpoint so the app can
+//! make structural layout changes (condensed progress rows, shorter
+//! placeholders) that plain responsive classes cannot express.
+
+use leptos::*;
+
+/// Widest viewport, in CSS pixels, that uses the compact layout (below Tailwind `sm`).
+pub const COMPACT_MAX_WIDTH_PX: u32 = 639;
+
+/// Media query matching the compact layout.
+fn compact_media_query() -> String {
+    format!("(max-width: {}px)", COMPACT_MAX_WIDTH_PX)
+}
+
+/// Whether a viewport `width` in CSS pixels uses the compact layout.
+pub fn is_compact_width(width: f64) -> bool {
+    width <= f64::from(COMPACT_MAX_WIDTH_PX)
+}
+
+/// Current compact state via `matchMedia`, falling back to the window width
+/// when the browser returns no media query list.
+fn is_compact_now() -> bool {
+    let Some(window) = web_sys::window() else {
+        return false;
+    };
+    match window.match_media(&compact_media_query()) {
+        Ok(Some(query)) => query.matches(),
+        _ => window
+            .inner_width()
+            .ok()
+            .and_then(|width| width.as_f64())
+            .is_some_and(is_compact_width),
+    }
+}
+
+/// Signal that is `true` while the viewport is compact, updated on resize.
+pub fn use_compact_layout() -> ReadSignal<bool> {
+    let (compact, set_compact) = create_signal(is_compact_now());
+    let _ = window_event_listener(ev::resize, move |_| {
+        let now = is_compact_now();
+        if now != compact.get_untracked() {
+            set_compact.set(now);
+        }
+    });
+    compact
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widths_below_the_sm_breakpoint_are_compact() {
+        assert!(is_compact_width(375.0));
+        assert!(is_compact_width(639.0));
+        assert!(!is_compact_width(640.0));
+        assert!(!is_compact_width(1440.0));
+        assert_eq!(compact_media_query(), "(max-width: 639px)");
+    }
+}
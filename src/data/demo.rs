@@ -0,0 +1,23 @@
+//! Bundled sample transcript for demo mode
+//!
+//! Plain transcript text (as produced by PDF extraction) for a third-year
+//! student, so the tool can be tried without uploading a PDF.
+
+/// Name shown for the demo transcript in place of an uploaded file name
+pub const DEMO_FILE_NAME: &str = "demo-transcript";
+
+/// Extracted text of the sample transcript, fed straight into the parser
+pub const DEMO_TRANSCRIPT: &str = include_str!("demo_transcript.txt");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::parser::{parse_transcript, ParseOptions};
+
+    #[test]
+    fn demo_transcript_parses_to_courses() {
+        let courses = parse_transcript(DEMO_TRANSCRIPT, &ParseOptions::default())
+            .expect("demo transcript should parse");
+        assert!(!courses.is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+//! Faculty Prefixes
+//!
+//! Maps the numeric course-code prefixes found in the curriculum data to the
+//! faculty that offers them (e.g. `874-` is Law, `891-` is Liberal Arts'
+//! foreign languages). Prefixes not listed here have no known faculty.
+
+/// Course-code prefix → offering faculty.
+const FACULTY_PREFIXES: &[(&str, &str)] = &[
+    ("001", "University-wide"),
+    ("003", "University-wide"),
+    ("193", "Traditional Thai Medicine"),
+    ("200", "Engineering"),
+    ("315", "Science"),
+    ("322", "Science"),
+    ("324", "Science"),
+    ("325", "Science"),
+    ("330", "Science"),
+    ("331", "Science"),
+    ("332", "Science"),
+    ("333", "Science"),
+    ("336", "Science"),
+    ("338", "Science"),
+    ("344", "Science"),
+    ("460", "Management Sciences"),
+    ("473", "Management Sciences"),
+    ("500", "Natural Resources"),
+    ("858", "Agro-Industry"),
+    ("874", "Law"),
+    ("890", "Liberal Arts"),
+    ("891", "Liberal Arts"),
+    ("895", "Liberal Arts"),
+];
+
+/// Faculty offering the course `code` (e.g. "874-101" or just "874"), judged
+/// by its numeric prefix; `None` for prefixes not in the data.
+pub fn faculty_for_prefix(code: &str) -> Option<&'static str> {
+    let prefix = code.trim().split('-').next()?;
+    FACULTY_PREFIXES
+        .iter()
+        .find(|(known, _)| *known == prefix)
+        .map(|(_, faculty)| *faculty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefixes_map_to_their_faculty() {
+        assert_eq!(faculty_for_prefix("890-101"), Some("Liberal Arts"));
+        assert_eq!(faculty_for_prefix("891"), Some("Liberal Arts"));
+        assert_eq!(faculty_for_prefix("895-125"), Some("Liberal Arts"));
+        assert_eq!(faculty_for_prefix("874-193"), Some("Law"));
+        assert_eq!(faculty_for_prefix("344-101"), Some("Science"));
+        assert_eq!(faculty_for_prefix("999-999"), None);
+    }
+}
@@ -395,7 +395,7 @@ pub fn get_gen_ed_curriculum() -> GenEdCurriculum {
                     name: "English Language".to_string(),
                     required_credits: 0.0,
                     min_courses: 0,
-                    max_courses: 99,
+                    max_courses: 3,
                     courses: vec![
                         GenEdCourse {
                             code: "890-811".to_string(),
@@ -511,7 +511,7 @@ pub fn get_gen_ed_curriculum() -> GenEdCurriculum {
                     name: "Foreign Languages".to_string(),
                     required_credits: 0.0,
                     min_courses: 0,
-                    max_courses: 99,
+                    max_courses: 3,
                     courses: vec![
                         GenEdCourse {
                             code: "891-811".to_string(),
@@ -602,7 +602,7 @@ pub fn get_gen_ed_curriculum() -> GenEdCurriculum {
                     name: "Humanities and Social Sciences".to_string(),
                     required_credits: 0.0,
                     min_courses: 0,
-                    max_courses: 99,
+                    max_courses: 3,
                     courses: vec![
                         GenEdCourse {
                             code: "895-811".to_string(),
@@ -773,7 +773,7 @@ pub fn get_gen_ed_curriculum() -> GenEdCurriculum {
                     name: "Science and Health".to_string(),
                     required_credits: 0.0,
                     min_courses: 0,
-                    max_courses: 99,
+                    max_courses: 3,
                     courses: vec![
                         GenEdCourse {
                             code: "315-103".to_string(),
@@ -824,7 +824,7 @@ pub fn get_gen_ed_curriculum() -> GenEdCurriculum {
                     name: "Law".to_string(),
                     required_credits: 0.0,
                     min_courses: 0,
-                    max_courses: 99,
+                    max_courses: 3,
                     courses: vec![
                         GenEdCourse {
                             code: "874-191".to_string(),
@@ -860,7 +860,7 @@ pub fn get_gen_ed_curriculum() -> GenEdCurriculum {
                     name: "Interdisciplinary and Others".to_string(),
                     required_credits: 0.0,
                     min_courses: 0,
-                    max_courses: 99,
+                    max_courses: 3,
                     courses: vec![
                         GenEdCourse {
                             code: "193-031".to_string(),
@@ -23,6 +23,10 @@ use crate::models::{
 pub fn get_major_curriculum() -> MajorCurriculum {
     MajorCurriculum {
         name: "Major Specific Courses - Computer Science".to_string(),
+        // Declared totals follow the published programme structure, while the
+        // course lists hold only the courses defined so far: the components
+        // below add up to 83 of the 96 credits and the listed core courses to
+        // 48 of the core's 56. The audit reports against the declared figures.
         total_required_credits: 96.0,
 
         // 2.1 Basic Science (12 Credits)
@@ -34,31 +38,37 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                     code: "324-101".to_string(),
                     name: "General Chemistry I".to_string(),
                     credits: 3.0,
+                    corequisites: vec!["325-101".to_string()],
                 },
                 MajorCourse {
                     code: "325-101".to_string(),
                     name: "General Chemistry Laboratory I".to_string(),
                     credits: 1.0,
+                    corequisites: vec!["324-101".to_string()],
                 },
                 MajorCourse {
                     code: "330-101".to_string(),
                     name: "Principles of Biology I".to_string(),
                     credits: 3.0,
+                    corequisites: vec!["331-101".to_string()],
                 },
                 MajorCourse {
                     code: "331-101".to_string(),
                     name: "Principles of Biology Laboratory I".to_string(),
                     credits: 1.0,
+                    corequisites: vec!["330-101".to_string()],
                 },
                 MajorCourse {
                     code: "332-101".to_string(),
                     name: "Fundamental Physics".to_string(),
                     credits: 3.0,
+                    corequisites: vec!["333-101".to_string()],
                 },
                 MajorCourse {
                     code: "333-101".to_string(),
                     name: "Fundamental Physics Laboratory".to_string(),
                     credits: 1.0,
+                    corequisites: vec!["332-101".to_string()],
                 },
             ],
         },
@@ -72,91 +82,109 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                     code: "322-101".to_string(),
                     name: "Calculus I".to_string(),
                     credits: 3.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "322-102".to_string(),
                     name: "Calculus II".to_string(),
                     credits: 3.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-201".to_string(),
                     name: "MODULE: Computing for Computer Science".to_string(),
                     credits: 6.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-111".to_string(),
                     name: "MODULE: Programming Concepts and Algorithms".to_string(),
                     credits: 6.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-181".to_string(),
                     name: "Communication Skill in Technology".to_string(),
                     credits: 1.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-233".to_string(),
                     name: "MODULE: Information Systems Analysis and Design and Principles of Database Systems".to_string(),
                     credits: 6.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-211".to_string(),
                     name: "Introduction to Object-Oriented Programming".to_string(),
                     credits: 2.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-243".to_string(),
                     name: "Software Interactive Design".to_string(),
                     credits: 1.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-221".to_string(),
                     name: "Computer Architectures and Organization".to_string(),
                     credits: 2.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-222".to_string(),
                     name: "Operating Systems".to_string(),
                     credits: 2.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-223".to_string(),
                     name: "Fundamentals of Computer Security".to_string(),
                     credits: 2.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-281".to_string(),
                     name: "Public Speaking in Computer Science".to_string(),
                     credits: 1.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-341".to_string(),
                     name: "Software Engineering".to_string(),
                     credits: 3.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-351".to_string(),
                     name: "Data Communications and Networking".to_string(),
                     credits: 3.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-361".to_string(),
                     name: "Principles of Artificial Intelligence".to_string(),
                     credits: 3.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-381".to_string(),
                     name: "Thinking and Creativity for Innovation Design".to_string(),
                     credits: 2.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-382".to_string(),
                     name: "Ethics for Digital Technology".to_string(),
                     credits: 1.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-491".to_string(),
                     name: "Seminar in Computer Science".to_string(),
                     credits: 1.0,
+                    corequisites: Vec::new(),
                 },
             ],
         },
@@ -170,11 +198,13 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                     code: "344-492".to_string(),
                     name: "Projects in Computer Science".to_string(),
                     credits: 3.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-495".to_string(),
                     name: "Cooperative Education".to_string(),
                     credits: 6.0,
+                    corequisites: Vec::new(),
                 },
             ],
         },
@@ -201,16 +231,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-331".to_string(),
                                     name: "Data Science".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-332".to_string(),
                                     name: "Data Mining".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-431".to_string(),
                                     name: "Big Data".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -224,16 +257,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-232".to_string(),
                                     name: "Knowledge Management and Decision Support Systems".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-333".to_string(),
                                     name: "Data Analytics and Visualization".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-334".to_string(),
                                     name: "Business Intelligent Systems".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -247,16 +283,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-311".to_string(),
                                     name: "Advanced Object-Oriented Programming".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-432".to_string(),
                                     name: "Next Generation Database Technologies".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-401".to_string(),
                                     name: "Cryptography and Security".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -279,16 +318,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-352".to_string(),
                                     name: "Computer Network Systems".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-353".to_string(),
                                     name: "Computer Systems and Network Security".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-451".to_string(),
                                     name: "Internet Technology and Applications".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -302,16 +344,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-212".to_string(),
                                     name: "Web Application Programming".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-312".to_string(),
                                     name: "Mobile Application Development".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-321".to_string(),
                                     name: "Wireless Technology".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -325,16 +370,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-322".to_string(),
                                     name: "Embedded Systems".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-323".to_string(),
                                     name: "Internet of Things".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-324".to_string(),
                                     name: "Cloud Computing Systems".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -357,16 +405,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-342".to_string(),
                                     name: "Software Testing Techniques".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-441".to_string(),
                                     name: "Software Project and Quality Management".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-442".to_string(),
                                     name: "Software Measurement and Evaluation".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -380,16 +431,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-242".to_string(),
                                     name: "Principles of Business Software Development".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-335".to_string(),
                                     name: "Database Application Development".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-443".to_string(),
                                     name: "Object-Oriented Analysis and Design".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -403,16 +457,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-343".to_string(),
                                     name: "Introduction to User Experience Design".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-344".to_string(),
                                     name: "Usability Evaluation".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-444".to_string(),
                                     name: "Information Architecture for User Experience Design".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -426,16 +483,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-335".to_string(),
                                     name: "Database Application Development".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-433".to_string(),
                                     name: "Database Administration and Maintenance".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-434".to_string(),
                                     name: "Database Performance Tuning".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -458,26 +518,31 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-261".to_string(),
                                     name: "Artificial Intelligence for Everyone".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-362".to_string(),
                                     name: "Machine Learning".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-461".to_string(),
                                     name: "Neural Networks".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-462".to_string(),
                                     name: "Pattern Recognition".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-463".to_string(),
                                     name: "Internet of Robotic Things".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -491,16 +556,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-363".to_string(),
                                     name: "Natural Language Processing".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-464".to_string(),
                                     name: "Text Mining and Sentiment Analysis".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-465".to_string(),
                                     name: "Linguistic Intelligence and Machine Translation".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -514,16 +582,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-271".to_string(),
                                     name: "3D Modeling and Animation".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-371".to_string(),
                                     name: "Introduction to Computer Game Programming".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-372".to_string(),
                                     name: "Advanced Game Development".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -537,16 +608,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                                     code: "344-373".to_string(),
                                     name: "Fundamentals of Digital Image Processing".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-374".to_string(),
                                     name: "Advanced Digital Image Processing".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                                 MajorCourse {
                                     code: "344-471".to_string(),
                                     name: "Computer Vision and Applications".to_string(),
                                     credits: 3.0,
+                                    corequisites: Vec::new(),
                                 },
                             ],
                         },
@@ -558,16 +632,19 @@ pub fn get_major_curriculum() -> MajorCurriculum {
                     code: "344-496".to_string(),
                     name: "Special Topics in Computer Science".to_string(),
                     credits: 3.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-493".to_string(),
                     name: "Selected Topic in Computer Science I".to_string(),
                     credits: 3.0,
+                    corequisites: Vec::new(),
                 },
                 MajorCourse {
                     code: "344-494".to_string(),
                     name: "Selected Topic in Computer Science II".to_string(),
                     credits: 3.0,
+                    corequisites: Vec::new(),
                 },
             ],
         },
@@ -3,6 +3,13 @@
 //! Contains all required courses and requirements for:
 //! - General Education (GenEd) requirements
 //! - Major-specific courses
+//! - MODULE courses printed as separate component rows
+//! - Course-code prefixes and the faculties that offer them
+//!
+//! Also bundles a sample transcript for demo mode.
 
+pub mod demo;
+pub mod faculties;
 pub mod gen_ed;
 pub mod major;
+pub mod modules;
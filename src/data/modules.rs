@@ -0,0 +1,38 @@
+//! MODULE Component Data
+//!
+//! MODULE courses carry 6 credits under one code, but some transcripts print
+//! them as two 3-credit component rows with a letter suffix. This table lists
+//! the components of each module so the parser can merge them back into a
+//! single module row.
+
+use crate::models::ModuleComponents;
+
+fn module(code: &str, name: &str, components: [&str; 2]) -> ModuleComponents {
+    ModuleComponents {
+        code: code.to_string(),
+        name: name.to_string(),
+        credits: 6.0,
+        components: components.iter().map(|c| c.to_string()).collect(),
+    }
+}
+
+/// Returns the component listing of every 6-credit MODULE course.
+pub fn get_module_components() -> Vec<ModuleComponents> {
+    vec![
+        module(
+            "344-111",
+            "MODULE: Programming Concepts and Algorithms",
+            ["344-111A", "344-111B"],
+        ),
+        module(
+            "344-201",
+            "MODULE: Computing for Computer Science",
+            ["344-201A", "344-201B"],
+        ),
+        module(
+            "344-233",
+            "MODULE: Information Systems Analysis and Design and Principles of Database Systems",
+            ["344-233A", "344-233B"],
+        ),
+    ]
+}
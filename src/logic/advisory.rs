@@ -0,0 +1,510 @@
+//! Advisory Checks
+//!
+//! Informational checks that never change credit totals but flag things a
+//! student or advisor should look at (e.g., passed courses with weak grades,
+//! failed courses still to retake, withdrawals, credits collected beyond a category's
+//! requirement, a total below the degree minimum, GenEd courses offered by
+//! the major's own faculty, graduation readiness).
+
+use crate::models::{
+    canonical_code, format_credits, grade_point, is_failing_grade, is_passing_grade,
+    is_withdrawn_grade, progress_percent, AuditResult, Category, Course, MissingCourse,
+    ParsedCourse,
+};
+use std::collections::HashSet;
+
+/// Default grade-point threshold below which a passed course is flagged (C = 2.0).
+pub const WEAK_GRADE_THRESHOLD: f32 = 2.0;
+
+/// Returns passing courses whose grade point is below `threshold` (e.g. D, D+
+/// at the default 2.0). Grades without grade points (S/U/P/G/...) are skipped.
+pub fn weak_grade_courses(courses: &[ParsedCourse], threshold: f32) -> Vec<ParsedCourse> {
+    courses
+        .iter()
+        .filter(|parsed| is_passing_grade(&parsed.grade))
+        .filter(|parsed| grade_point(&parsed.grade).is_some_and(|points| points < threshold))
+        .cloned()
+        .collect()
+}
+
+/// Returns withdrawn (W) enrollments in transcript order, every attempt
+/// included, so gaps in a study plan can be explained to an advisor.
+pub fn withdrawn_courses(courses: &[ParsedCourse]) -> Vec<ParsedCourse> {
+    courses
+        .iter()
+        .filter(|parsed| is_withdrawn_grade(&parsed.grade))
+        .cloned()
+        .collect()
+}
+
+/// Returns failed courses (F/U) that were not passed on a later attempt, one
+/// entry per code (the latest failed attempt) in transcript order.
+pub fn failed_courses(courses: &[ParsedCourse]) -> Vec<ParsedCourse> {
+    let passed: HashSet<String> = courses
+        .iter()
+        .filter(|parsed| is_passing_grade(&parsed.grade))
+        .map(|parsed| canonical_code(&parsed.code))
+        .collect();
+
+    let mut failed: Vec<ParsedCourse> = Vec::new();
+    for parsed in courses
+        .iter()
+        .filter(|parsed| is_failing_grade(&parsed.grade))
+    {
+        let code = canonical_code(&parsed.code);
+        if passed.contains(&code) {
+            continue;
+        }
+        match failed.iter_mut().find(|f| canonical_code(&f.code) == code) {
+            Some(earlier) => *earlier = parsed.clone(),
+            None => failed.push(parsed.clone()),
+        }
+    }
+    failed
+}
+
+/// Category used for the English exit requirement in the missing list.
+pub const ENGLISH_EXIT_CATEGORY: &str = "English Exit Requirement";
+
+/// Returns a missing entry when no passing `marker_code` appears on the
+/// transcript. The exit test is a graduation gate beyond credit counting.
+///
+/// The curriculum data has no code for the exit test, so the marker (the S/U
+/// entry without credits that records a pass) must be set by the user through
+/// `AuditOptions::english_exit_marker`; the check is skipped until it is.
+pub fn english_exit_missing(courses: &[ParsedCourse], marker_code: &str) -> Option<MissingCourse> {
+    let marker = canonical_code(marker_code);
+    let passed = courses
+        .iter()
+        .any(|parsed| canonical_code(&parsed.code) == marker && is_passing_grade(&parsed.grade));
+    (!passed).then(|| MissingCourse {
+        category: ENGLISH_EXIT_CATEGORY.to_string(),
+        description: format!("{} - English proficiency exit test not passed", marker_code),
+        reason: None,
+    })
+}
+
+/// Overall credit minimum for the degree. Category requirements can add up
+/// to less once co-op or overlaps shrink them, so the total is checked on its
+/// own. Override it through `AuditOptions::degree_min_credits`.
+pub const DEGREE_MIN_CREDITS: f32 = 132.0;
+
+/// Category used for the degree credit minimum in the missing list.
+pub const TOTAL_CREDITS_CATEGORY: &str = "Total Credits";
+
+/// Returns a missing entry when `total_credits` is below `min_credits`, even
+/// if every category looks complete.
+pub fn total_credits_missing(total_credits: f32, min_credits: f32) -> Option<MissingCourse> {
+    (total_credits < min_credits).then(|| MissingCourse {
+        category: TOTAL_CREDITS_CATEGORY.to_string(),
+        description: format!(
+            "Total credits: missing {}",
+            format_credits(min_credits - total_credits)
+        ),
+        reason: Some(format!(
+            "{} of the {} credits the degree requires",
+            format_credits(total_credits),
+            format_credits(min_credits)
+        )),
+    })
+}
+
+/// Course-code prefixes of the faculty running the major (computing courses).
+pub const MAJOR_FACULTY_PREFIXES: &[&str] = &["344", "315"];
+
+/// Returns courses counted toward General Education whose code prefix is one
+/// of `prefixes` (e.g. 315-100 The Art of Computing), so an advisor can check
+/// a computing course is not being mis-applied outside the major.
+pub fn gen_ed_faculty_conflicts(categories: &[Category], prefixes: &[&str]) -> Vec<Course> {
+    categories
+        .iter()
+        .filter(|category| category.name == "General Education")
+        .flat_map(|category| &category.courses)
+        .filter(|course| {
+            let code = canonical_code(&course.code);
+            code.split('-')
+                .next()
+                .is_some_and(|prefix| prefixes.contains(&prefix))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Credits collected in a category beyond what it requires.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreditSurplus {
+    pub category: String,
+    pub surplus: f32,
+}
+
+/// Lists categories where collected credits exceed the requirement, with the
+/// surplus amount (e.g. 100 collected vs 96 required → +4).
+pub fn credit_surpluses(categories: &[Category]) -> Vec<CreditSurplus> {
+    categories
+        .iter()
+        .filter(|c| c.collected_credits > c.required_credits)
+        .map(|c| CreditSurplus {
+            category: c.name.clone(),
+            surplus: c.collected_credits - c.required_credits,
+        })
+        .collect()
+}
+
+/// Credits to take each term to clear `remaining` credits in `terms_left`
+/// terms. With no terms left everything remaining is due now.
+pub fn credits_per_term(remaining: f32, terms_left: u32) -> f32 {
+    let remaining = remaining.max(0.0);
+    if terms_left == 0 {
+        remaining
+    } else {
+        remaining / terms_left as f32
+    }
+}
+
+/// Highest grade point a course can earn (A).
+pub const MAX_GRADE_POINT: f32 = 4.0;
+
+/// Returned by `required_future_gpa` when the target cannot be reached even
+/// with straight A's; any value above `MAX_GRADE_POINT` means unreachable.
+pub const GPA_UNREACHABLE: f32 = f32::INFINITY;
+
+/// GPA needed over `planned_credits` of new graded courses for the cumulative
+/// GPA to reach `target`, given the grade points and graded credits so far.
+/// Clamped below at 0.0 (the target is met whatever the grades); returns
+/// `GPA_UNREACHABLE` when it would take more than `MAX_GRADE_POINT`.
+pub fn required_future_gpa(
+    current_points: f32,
+    current_credits: f32,
+    planned_credits: f32,
+    target: f32,
+) -> f32 {
+    if planned_credits <= 0.0 {
+        let met = current_credits > 0.0 && current_points / current_credits >= target;
+        return if met { 0.0 } else { GPA_UNREACHABLE };
+    }
+    let needed = (target * (current_credits + planned_credits) - current_points) / planned_credits;
+    // Tolerance keeps an exactly reachable target (needs a 4.00) reachable
+    if needed > MAX_GRADE_POINT + 1e-4 {
+        GPA_UNREACHABLE
+    } else {
+        needed.clamp(0.0, MAX_GRADE_POINT)
+    }
+}
+
+/// Default minimum cumulative GPA required to graduate.
+pub const MIN_GRADUATION_GPA: f32 = 2.0;
+
+/// Whether the audited transcript meets every graduation requirement.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraduationStatus {
+    Eligible,
+    /// Not yet eligible; `blockers` lists what still stands in the way.
+    NotEligible {
+        blockers: Vec<String>,
+    },
+}
+
+/// Derives the graduation status from an audit result. Every incomplete
+/// category contributes a "{category}: missing X credits" blocker, any
+/// remaining missing requirements add a single count blocker, and a cumulative
+/// GPA under `min_gpa` adds a "GPA below" blocker even when credits are met.
+/// A transcript without graded credits (no GPA) is not blocked on GPA.
+pub fn graduation_status(result: &AuditResult, min_gpa: f32) -> GraduationStatus {
+    let mut blockers: Vec<String> = result
+        .categories
+        .iter()
+        .filter(|c| c.collected_credits < c.required_credits)
+        .map(|c| {
+            format!(
+                "{}: missing {:.1} credits",
+                c.name,
+                c.required_credits - c.collected_credits
+            )
+        })
+        .collect();
+
+    if !result.missing_subjects.is_empty() {
+        blockers.push(format!(
+            "{} missing requirements",
+            result.missing_subjects.len()
+        ));
+    }
+
+    if let Some(gpa) = result.gpa.filter(|&gpa| gpa < min_gpa) {
+        blockers.push(format!("GPA below {:.2} (currently {:.2})", min_gpa, gpa));
+    }
+
+    if blockers.is_empty() {
+        GraduationStatus::Eligible
+    } else {
+        GraduationStatus::NotEligible { blockers }
+    }
+}
+
+/// Thresholds behind the program-level status pill, kept in one place so
+/// they can be tuned together.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgramStatusThresholds {
+    pub min_gpa: f32,            // A cumulative GPA below this is at risk
+    pub late_percent: f32,       // Overall progress from which open requirements weigh more
+    pub late_max_missing: usize, // Missing requirements tolerated that late
+}
+
+impl Default for ProgramStatusThresholds {
+    fn default() -> Self {
+        Self {
+            min_gpa: MIN_GRADUATION_GPA,
+            late_percent: 75.0,
+            late_max_missing: 5,
+        }
+    }
+}
+
+/// At-a-glance verdict for the whole program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgramStatus {
+    ReadyToGraduate,
+    OnTrack,
+    AtRisk,
+}
+
+impl ProgramStatus {
+    pub fn label(self, is_thai: bool) -> &'static str {
+        match (self, is_thai) {
+            (ProgramStatus::ReadyToGraduate, false) => "Ready to Graduate",
+            (ProgramStatus::ReadyToGraduate, true) => "พร้อมสำเร็จการศึกษา",
+            (ProgramStatus::OnTrack, false) => "On Track",
+            (ProgramStatus::OnTrack, true) => "เป็นไปตามแผน",
+            (ProgramStatus::AtRisk, false) => "At Risk",
+            (ProgramStatus::AtRisk, true) => "มีความเสี่ยง",
+        }
+    }
+}
+
+/// Overall completion across categories; surplus in one category does not
+/// make up for a shortfall in another.
+pub fn overall_percent(result: &AuditResult) -> f32 {
+    let (collected, required) = result
+        .categories
+        .iter()
+        .fold((0.0, 0.0), |(c, r), category| {
+            (
+                c + category.collected_credits.min(category.required_credits),
+                r + category.required_credits,
+            )
+        });
+    progress_percent(collected, required)
+}
+
+/// Ready to graduate once every graduation requirement is met. Otherwise at
+/// risk when the GPA is below `thresholds.min_gpa`, or when overall progress
+/// has reached `late_percent` with more than `late_max_missing` requirements
+/// still missing; on track in every other case.
+pub fn program_status(result: &AuditResult, thresholds: &ProgramStatusThresholds) -> ProgramStatus {
+    if graduation_status(result, thresholds.min_gpa) == GraduationStatus::Eligible {
+        return ProgramStatus::ReadyToGraduate;
+    }
+    let gpa_blocked = result.gpa.is_some_and(|gpa| gpa < thresholds.min_gpa);
+    let late_with_gaps = overall_percent(result) >= thresholds.late_percent
+        && result.missing_subjects.len() > thresholds.late_max_missing;
+    if gpa_blocked || late_with_gaps {
+        ProgramStatus::AtRisk
+    } else {
+        ProgramStatus::OnTrack
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(code: &str, grade: &str, credit: f32) -> ParsedCourse {
+        ParsedCourse {
+            code: code.to_string(),
+            name: "Course".to_string(),
+            grade: grade.to_string(),
+            parsed_credit: credit,
+            section: None,
+            low_confidence: false,
+        }
+    }
+
+    fn codes(courses: &[ParsedCourse]) -> Vec<&str> {
+        courses.iter().map(|c| c.code.as_str()).collect()
+    }
+
+    #[test]
+    fn d_grade_is_weak_and_c_grade_is_not() {
+        let courses = [parsed("344-101", "D", 3.0), parsed("344-102", "C", 3.0)];
+
+        assert_eq!(
+            codes(&weak_grade_courses(&courses, WEAK_GRADE_THRESHOLD)),
+            ["344-101"]
+        );
+    }
+
+    fn category(name: &str, collected: f32, required: f32) -> Category {
+        Category {
+            name: name.to_string(),
+            required_credits: required,
+            collected_credits: collected,
+            courses: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn credits_beyond_a_requirement_are_a_surplus() {
+        let categories = [
+            category("General Education", 30.0, 30.0),
+            category("Major Courses", 100.0, 96.0),
+        ];
+
+        assert_eq!(
+            credit_surpluses(&categories),
+            [CreditSurplus {
+                category: "Major Courses".to_string(),
+                surplus: 4.0,
+            }]
+        );
+    }
+
+    /// Result with the given categories (total = their collected credits),
+    /// nothing missing and the given GPA.
+    fn result(categories: Vec<Category>, gpa: Option<f32>) -> AuditResult {
+        let mut result: AuditResult = serde_json::from_str(
+            r#"{"schema_version": 1, "total_credits": 0, "categories": [], "missing_subjects": []}"#,
+        )
+        .unwrap();
+        result.total_credits = categories.iter().map(|c| c.collected_credits).sum();
+        result.categories = categories;
+        result.gpa = gpa;
+        result
+    }
+
+    fn complete_categories() -> Vec<Category> {
+        vec![
+            category("General Education", 30.0, 30.0),
+            category("Major Courses", 96.0, 96.0),
+            category("Free Electives", 6.0, 6.0),
+        ]
+    }
+
+    #[test]
+    fn gpa_below_the_minimum_blocks_graduation() {
+        assert_eq!(
+            graduation_status(
+                &result(complete_categories(), Some(1.8)),
+                MIN_GRADUATION_GPA
+            ),
+            GraduationStatus::NotEligible {
+                blockers: vec!["GPA below 2.00 (currently 1.80)".to_string()],
+            }
+        );
+        assert_eq!(
+            graduation_status(
+                &result(complete_categories(), Some(2.1)),
+                MIN_GRADUATION_GPA
+            ),
+            GraduationStatus::Eligible
+        );
+    }
+
+    #[test]
+    fn english_exit_marker_present_or_absent() {
+        let marker = "890-001";
+        let passed = [parsed("344-101", "A", 3.0), parsed("890-001", "S", 0.0)];
+        let failed = [parsed("344-101", "A", 3.0), parsed("890-001", "U", 0.0)];
+
+        assert!(english_exit_missing(&passed, marker).is_none());
+        let missing = english_exit_missing(&failed, marker).expect("exit test not passed");
+        assert_eq!(missing.category, ENGLISH_EXIT_CATEGORY);
+        assert!(missing.description.starts_with(marker));
+        assert!(english_exit_missing(&[], marker).is_some());
+    }
+
+    #[test]
+    fn failed_courses_skip_retakes_that_passed() {
+        let courses = [
+            parsed("322-101", "F", 3.0),
+            parsed("322-102", "F", 3.0),
+            parsed("322-102", "C", 3.0),
+            parsed("999-101", "F", 3.0),
+            parsed("999-102", "W", 1.0),
+            parsed("999-101", "F", 3.0),
+        ];
+
+        assert_eq!(codes(&failed_courses(&courses)), ["322-101", "999-101"]);
+    }
+
+    #[test]
+    fn credits_per_term_spreads_what_remains() {
+        assert_eq!(credits_per_term(36.0, 2), 18.0);
+        assert_eq!(credits_per_term(37.0, 2), 18.5);
+        assert_eq!(credits_per_term(37.0, 0), 37.0);
+        assert_eq!(credits_per_term(-3.0, 2), 0.0);
+    }
+
+    #[test]
+    fn required_future_gpa_covers_reachable_exact_and_unreachable_targets() {
+        // 60 credits at 2.0 (120 points) with 20 more credits planned
+        assert!((required_future_gpa(120.0, 60.0, 20.0, 2.25) - 3.0).abs() < 1e-4);
+        assert!((required_future_gpa(120.0, 60.0, 20.0, 2.5) - MAX_GRADE_POINT).abs() < 1e-4);
+        assert!(required_future_gpa(120.0, 60.0, 20.0, 3.0) > MAX_GRADE_POINT);
+
+        assert_eq!(required_future_gpa(240.0, 60.0, 20.0, 2.0), 0.0);
+        assert_eq!(required_future_gpa(240.0, 60.0, 0.0, 2.0), 0.0);
+        assert!(required_future_gpa(120.0, 60.0, 0.0, 2.5) > MAX_GRADE_POINT);
+    }
+
+    #[test]
+    fn gen_ed_course_from_the_major_faculty_is_a_conflict() {
+        let course = |code: &str| Course {
+            code: code.to_string(),
+            name: "Course".to_string(),
+            credit: 3.0,
+            grade: "A".to_string(),
+            curriculum_name: None,
+            substitutes_for: None,
+            section: None,
+            matched: None,
+        };
+        let mut gen_ed = category("General Education", 6.0, 30.0);
+        gen_ed.courses = vec![course("315-100"), course("890-101")];
+
+        let conflicts = gen_ed_faculty_conflicts(&[gen_ed], MAJOR_FACULTY_PREFIXES);
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].code, "315-100");
+    }
+
+    #[test]
+    fn total_below_the_degree_minimum_is_a_blocker() {
+        let missing = total_credits_missing(129.0, 132.0).unwrap();
+
+        assert_eq!(missing.category, TOTAL_CREDITS_CATEGORY);
+        assert_eq!(missing.description, "Total credits: missing 3");
+        assert!(total_credits_missing(132.0, 132.0).is_none());
+    }
+
+    #[test]
+    fn program_status_reflects_progress_missing_items_and_gpa() {
+        let status = |collected: f32, missing: usize, gpa: Option<f32>| {
+            let mut result = result(vec![category("Major Courses", collected, 100.0)], gpa);
+            result.missing_subjects = vec![
+                MissingCourse {
+                    category: "Major Courses".to_string(),
+                    description: "Missing".to_string(),
+                    reason: None,
+                };
+                missing
+            ];
+            program_status(&result, &ProgramStatusThresholds::default())
+        };
+
+        assert_eq!(status(100.0, 0, Some(3.0)), ProgramStatus::ReadyToGraduate);
+        assert_eq!(status(50.0, 20, Some(3.0)), ProgramStatus::OnTrack);
+        assert_eq!(status(100.0, 0, Some(1.8)), ProgramStatus::AtRisk);
+        assert_eq!(status(90.0, 8, Some(3.0)), ProgramStatus::AtRisk);
+        assert_eq!(ProgramStatus::OnTrack.label(false), "On Track");
+    }
+}
@@ -0,0 +1,128 @@
+//! Anonymized Results
+//!
+//! Advisor-facing copy of an audit result that hides elective choices: every
+//! course outside the required list is reduced to a generic "Elective (3 cr)"
+//! row, so aggregate progress can be shared with faculty without revealing
+//! which electives a student took.
+
+use crate::data::{gen_ed::get_gen_ed_curriculum, major::get_major_curriculum};
+use crate::logic::auditor::required_course_codes;
+use crate::models::{canonical_code, format_credits, AuditResult, Course};
+use std::collections::HashSet;
+
+/// File name suggested when downloading an anonymized result.
+pub const ANONYMIZED_RESULT_FILE_NAME: &str = "audit-result-anonymized.json";
+
+/// Which courses keep their code and name in an anonymized result.
+#[derive(Debug, Clone)]
+pub struct AnonymizePolicy {
+    pub required_codes: HashSet<String>, // Canonical codes shown as-is
+}
+
+impl Default for AnonymizePolicy {
+    /// Keeps the courses every student must pass (basic science, core and
+    /// choose-all GenEd courses) under the bundled curriculum.
+    fn default() -> Self {
+        Self {
+            required_codes: required_course_codes(
+                &get_gen_ed_curriculum(),
+                &get_major_curriculum(),
+            ),
+        }
+    }
+}
+
+/// Replaces a non-required course with a generic elective row. Credits and
+/// grade are kept; code, names, section and the matched requirement (which
+/// names the cluster) are cleared.
+fn anonymize_course(course: &mut Course, policy: &AnonymizePolicy) {
+    if policy
+        .required_codes
+        .contains(&canonical_code(&course.code))
+    {
+        return;
+    }
+    *course = Course {
+        code: String::new(),
+        name: format!("Elective ({} cr)", format_credits(course.credit)),
+        credit: course.credit,
+        grade: std::mem::take(&mut course.grade),
+        curriculum_name: None,
+        substitutes_for: None,
+        section: None,
+        matched: None,
+    };
+}
+
+/// Copy of `result` with every course outside `policy.required_codes`
+/// genericized, across categories and the excluded, unrecognized, weak-grade,
+/// withdrawn and retake lists. Cluster progress is dropped since it names the
+/// clusters a student chose; credit totals and missing requirements are
+/// unchanged.
+pub fn anonymize_result(result: &AuditResult, policy: &AnonymizePolicy) -> AuditResult {
+    let mut anonymized = result.clone();
+    let courses = anonymized
+        .categories
+        .iter_mut()
+        .flat_map(|category| category.courses.iter_mut())
+        .chain(
+            anonymized
+                .excluded_courses
+                .iter_mut()
+                .map(|e| &mut e.course),
+        )
+        .chain(anonymized.unrecognized_courses.iter_mut())
+        .chain(anonymized.weak_grade_courses.iter_mut())
+        .chain(anonymized.withdrawn_courses.iter_mut())
+        .chain(anonymized.retake_courses.iter_mut().map(|r| &mut r.course));
+    for course in courses {
+        anonymize_course(course, policy);
+    }
+    anonymized.cluster_progress.clear();
+    anonymized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::auditor::audit_transcript;
+    use crate::logic::parser::{parse_transcript, ParseOptions};
+    use crate::models::AuditOptions;
+
+    #[test]
+    fn required_courses_keep_their_names_and_electives_are_genericized() {
+        let courses = parse_transcript(
+            "322-101 CALCULUS I 01 3 B+\n344-431 BIG DATA 01 3 A\n999-101 POTTERY 01 3 A",
+            &ParseOptions::default(),
+        )
+        .unwrap();
+        let result = audit_transcript(
+            &courses,
+            &get_gen_ed_curriculum(),
+            &get_major_curriculum(),
+            &AuditOptions::default(),
+        );
+
+        let anonymized = anonymize_result(&result, &AnonymizePolicy::default());
+
+        let names: Vec<_> = anonymized
+            .categories
+            .iter()
+            .flat_map(|c| &c.courses)
+            .map(|c| (c.code.as_str(), c.name.as_str()))
+            .collect();
+        assert!(names.contains(&("322-101", "CALCULUS I")));
+        assert!(!names
+            .iter()
+            .any(|(code, _)| *code == "344-431" || *code == "999-101"));
+        assert_eq!(
+            names
+                .iter()
+                .filter(|(_, name)| *name == "Elective (3 cr)")
+                .count(),
+            2
+        );
+        assert!(anonymized.cluster_progress.is_empty());
+        assert_eq!(anonymized.total_credits, result.total_credits);
+    }
+}
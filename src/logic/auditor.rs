@@ -3,73 +3,313 @@
 //! Implements the core logic for validating student transcripts against curriculum requirements:
 //! - **GenEd Auditing**: Matches courses to 6 strands with support for sub-groups and sequences
 //! - **Major Auditing**: Matches courses to Basic Science, Core, Capstone, and Electives
+//! - **Minor Auditing**: Matches an optional minor over courses the major left unclaimed
 //! - **Free Elective Detection**: Credits unmatched courses as free electives
 //! - **Greedy Matching**: Allows repeatable courses to accumulate credits
 
+use crate::logic::advisory::{
+    english_exit_missing, failed_courses, total_credits_missing, weak_grade_courses,
+    withdrawn_courses, TOTAL_CREDITS_CATEGORY, WEAK_GRADE_THRESHOLD,
+};
 use crate::models::{
-    free_elective_dedupe_key, is_passing_grade, GenEdCurriculum, MajorCurriculum, MissingCourse,
-    ParsedCourse,
+    calculate_gpa, canonical_code, counts_as_passed, free_elective_dedupe_key, grade_point,
+    is_passing_grade, is_special_topic, AuditOptions, AuditResult, Category, ClusterProgress,
+    CorequisiteWarning, Course, CourseMatch, Coverage, CreditSource, CreditSummary,
+    CurriculumCourseRef, CurriculumLocation, ElectivePolicy, ExcludedCourse, ExclusionReason,
+    FreeElectivePolicy, FreeElectiveSummary, GenEdCourse, GenEdCurriculum, GenEdSummary,
+    MajorCluster, MajorCourse, MajorCurriculum, MajorSummary, MatchPriority, MinorCurriculum,
+    MissingCourse, ParsedCourse, PassingPolicy, RetakeCourse, StrandProgress, AUDIT_SCHEMA_VERSION,
 };
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+
+/// Lookup from canonical course code to transcript indices, built once per
+/// audit so each curriculum course is matched without scanning the whole
+/// transcript.
+struct CourseIndex<'a> {
+    courses: &'a [ParsedCourse],
+    by_code: HashMap<String, Vec<usize>>, // Indices in transcript order
+    assume_in_progress_pass: bool,        // IP courses count as passed
+    passing_policy: PassingPolicy,        // Whether P courses earn credits
+    credit_source: CreditSource,          // Credits counted for a matched course
+    // Curriculum credits by canonical code; set when duplicate rows should be
+    // chosen by credit (`AuditOptions::prefer_curriculum_credit`)
+    preferred_credits: Option<HashMap<String, f32>>,
+}
+
+impl<'a> CourseIndex<'a> {
+    /// Indexes `courses`; a course with an approved substitution is filed
+    /// under the code it satisfies instead of its own.
+    fn new(courses: &'a [ParsedCourse], options: &AuditOptions) -> Self {
+        let mut by_code: HashMap<String, Vec<usize>> = HashMap::new();
+        for (idx, parsed) in courses.iter().enumerate() {
+            let key = options
+                .substitution_for(&parsed.code)
+                .unwrap_or_else(|| canonical_code(&parsed.code));
+            by_code.entry(key).or_default().push(idx);
+        }
+        Self {
+            courses,
+            by_code,
+            assume_in_progress_pass: options.assume_in_progress_pass,
+            passing_policy: options.passing_policy,
+            credit_source: options.credit_source,
+            preferred_credits: options.prefer_curriculum_credit.then(HashMap::new),
+        }
+    }
+
+    /// Registers the curriculum credits of `courses` (code, credits) used to
+    /// choose between duplicate rows. Does nothing unless the audit prefers
+    /// curriculum credits.
+    fn with_curriculum_credits<'c>(
+        mut self,
+        courses: impl IntoIterator<Item = (&'c str, f32)>,
+    ) -> Self {
+        if let Some(preferred) = &mut self.preferred_credits {
+            for (code, credits) in courses {
+                preferred.entry(canonical_code(code)).or_insert(credits);
+            }
+        }
+        self
+    }
+
+    fn passes(&self, parsed: &ParsedCourse) -> bool {
+        counts_as_passed(&parsed.grade, self.assume_in_progress_pass)
+            && self.passing_policy.earns_credits(&parsed.grade)
+    }
+
+    /// Best passing occurrence of `code` not in `used`: the attempt with the
+    /// highest grade point wins (so a retake graded A is claimed over an
+    /// earlier B), and ties, including ungraded passes such as S, go to the
+    /// earliest attempt in transcript order. When the audit prefers
+    /// curriculum credits, a row whose credit equals the curriculum value
+    /// comes first, then the higher credit, before grades are compared.
+    fn find_unused(&self, code: &str, used: &HashSet<usize>) -> Option<(usize, &'a ParsedCourse)> {
+        let code = canonical_code(code);
+        let expected = self
+            .preferred_credits
+            .as_ref()
+            .map(|preferred| preferred.get(&code).copied());
+        let credit_rank = |parsed: &ParsedCourse| match expected {
+            Some(expected) => (expected == Some(parsed.parsed_credit), parsed.parsed_credit),
+            None => (false, 0.0),
+        };
+        let rank = |parsed: &ParsedCourse| grade_point(&parsed.grade).unwrap_or(0.0);
+        self.by_code
+            .get(&code)?
+            .iter()
+            .map(|&idx| (idx, &self.courses[idx]))
+            .filter(|(idx, parsed)| !used.contains(idx) && self.passes(parsed))
+            .min_by(|(a_idx, a), (b_idx, b)| {
+                let (a_fits, a_credit) = credit_rank(a);
+                let (b_fits, b_credit) = credit_rank(b);
+                b_fits
+                    .cmp(&a_fits)
+                    .then(b_credit.total_cmp(&a_credit))
+                    .then(rank(b).total_cmp(&rank(a)))
+                    .then(a_idx.cmp(b_idx))
+            })
+    }
+
+    /// Credits a matched course earns under the audit's `CreditSource`
+    /// (by default the lesser of curriculum and transcript values, guarding
+    /// against PDF-parsing drift).
+    fn matched_credits(&self, curriculum_credits: f32, parsed: &ParsedCourse) -> f32 {
+        self.credit_source
+            .credited(curriculum_credits, parsed.parsed_credit)
+    }
+
+    /// Whether any occurrence of `code` has a passing grade, claimed or not.
+    fn has_passing(&self, code: &str) -> bool {
+        self.by_code
+            .get(&canonical_code(code))
+            .is_some_and(|indices| indices.iter().any(|&idx| self.passes(&self.courses[idx])))
+    }
+}
+
+/// Whether a sequence group is a pair of courses that both earn credits. A
+/// 0-credit prerequisite (e.g. 890-101) in a group would let the pair pass
+/// without its credits, so such groups are never matched.
+fn is_credit_bearing_pair(group: &[String], strand_courses: &[GenEdCourse]) -> bool {
+    group.len() == 2
+        && group.iter().all(|code| {
+            strand_courses
+                .iter()
+                .find(|c| &c.code == code)
+                .is_none_or(|c| c.credits > 0.0)
+        })
+}
+
+/// Returns the elective credits required for the chosen capstone path.
+/// Co-op carries more credits than the standard capstone slot, and that surplus
+/// is deducted from the elective requirement (e.g. 6-credit co-op vs 3-credit
+/// project leaves 3 fewer elective credits to take).
+pub fn required_elective_credits(
+    curriculum: &MajorCurriculum,
+    capstone: Option<&MajorCourse>,
+) -> f32 {
+    let surplus = capstone
+        .map(|option| (option.credits - curriculum.capstone.credits_per_option).max(0.0))
+        .unwrap_or(0.0);
+
+    (curriculum.electives.total_required_credits - surplus).max(0.0)
+}
+
+/// Debug-build check that every credit is finite and non-negative. The parser
+/// sanitizes credits, so a failure here means a new code path skipped it.
+fn debug_assert_credits_sane(courses: &[ParsedCourse]) {
+    debug_assert!(
+        courses
+            .iter()
+            .all(|c| c.parsed_credit.is_finite() && c.parsed_credit >= 0.0),
+        "audit input has a non-finite or negative credit"
+    );
+}
+
+/// Order in which `audit_transcript` runs the requirement groups. GenEd is
+/// audited as a whole first, so its electives run before the major's
+/// required groups even though those have a higher `MatchPriority`.
+const AUDIT_ORDER: [MatchPriority; 7] = [
+    MatchPriority::GenEdStrand,
+    MatchPriority::GenEdElective,
+    MatchPriority::BasicScience,
+    MatchPriority::Core,
+    MatchPriority::Capstone,
+    MatchPriority::Cluster,
+    MatchPriority::OtherElective,
+];
 
-/// Returns the lesser of the curriculum-defined credit value and the parsed
-/// transcript value, guarding against PDF-parsing drift.
-fn matched_course_credits(curriculum_credits: f32, parsed: &ParsedCourse) -> f32 {
-    curriculum_credits.min(parsed.parsed_credit)
+/// Canonical codes the group `phase` must leave unclaimed: codes of groups
+/// with a higher `MatchPriority` that are audited after it. Skipping them
+/// makes the priority, not the audit order, decide who claims a shared code.
+pub fn reserved_codes(
+    gen_ed: &GenEdCurriculum,
+    major: &MajorCurriculum,
+    phase: MatchPriority,
+) -> HashSet<String> {
+    let position = |priority| AUDIT_ORDER.iter().position(|&p| p == priority);
+    curriculum_courses(gen_ed, major)
+        .into_iter()
+        .filter(|course| {
+            let priority = course.location.priority();
+            priority < phase && position(priority) > position(phase)
+        })
+        .map(|course| canonical_code(&course.code))
+        .collect()
 }
 
 /// Audits courses against the GenEd curriculum, honoring strand sub-groups and
 /// sequential strand rules. Credits come from the curriculum (golden data).
+/// Also reports each strand's progress, with sub-groups nested under it.
+///
+/// GenEd electives skip `reserved` codes (see `reserved_codes`) so a course a
+/// major required group also lists is left for that group. Pass an empty set
+/// to audit GenEd on its own.
 pub fn audit_gen_ed(
     courses: &[ParsedCourse],
     curriculum: &GenEdCurriculum,
-) -> (f32, Vec<MissingCourse>, HashSet<usize>) {
+    reserved: &HashSet<String>,
+    options: &AuditOptions,
+) -> GenEdSummary {
+    debug_assert_credits_sane(courses);
     let mut completed_credits = 0.0;
+    let mut strands = Vec::new();
     let mut missing_courses: Vec<MissingCourse> = Vec::new();
     let mut used_indices = HashSet::new();
+    let mut matches: HashMap<usize, CourseMatch> = HashMap::new();
     let mut gen_ed_elective_total_credits = 0.0;
+    let strand_courses = curriculum.strands.iter().flat_map(|strand| {
+        strand
+            .courses
+            .iter()
+            .flatten()
+            .chain(strand.sub_groups.iter().flatten().flat_map(|g| &g.courses))
+    });
+    let elective_courses = curriculum
+        .electives
+        .sub_categories
+        .iter()
+        .flat_map(|sub_cat| &sub_cat.courses);
+    let index = CourseIndex::new(courses, options).with_curriculum_credits(
+        strand_courses
+            .chain(elective_courses)
+            .map(|course| (course.code.as_str(), course.credits)),
+    );
 
     for strand in &curriculum.strands {
         let selection_rule = strand.selection_rule.as_deref().unwrap_or("choose_all");
+        let strand_start_credits = completed_credits;
+        let mut sub_group_progress = Vec::new();
+
+        // 0-credit courses (e.g. 890-101 Essential English) earn nothing but are
+        // required gates: claim them when passed, report them when absent.
+        for course in strand.courses.iter().flatten().filter(|c| c.credits <= 0.0) {
+            if let Some((idx, _)) = index.find_unused(&course.code, &used_indices) {
+                used_indices.insert(idx);
+                matches.insert(
+                    idx,
+                    CourseMatch {
+                        requirement: strand.name.clone(),
+                        credited: 0.0,
+                    },
+                );
+            } else {
+                missing_courses.push(MissingCourse {
+                    category: "General Education".to_string(),
+                    description: format!(
+                        "Prerequisite {} - {}: missing (0-credit, required)",
+                        course.code, course.name
+                    ),
+                    reason: None,
+                });
+            }
+        }
 
         match selection_rule {
             "choose_sequential_pair" => {
                 let mut sequence_satisfied = false;
 
+                // Pairs overlap (890-103 is in two), so only the first
+                // satisfied pair is claimed. Extra courses of the sequence
+                // stay unclaimed and fall through to free electives.
+                // A 0-credit prerequisite (890-101) is handled by the gate
+                // loop above: it is claimed when passed but is never a pair
+                // member, so a pair listing one is skipped as malformed.
                 if let (Some(strand_courses), Some(sequence_groups)) =
                     (&strand.courses, &strand.sequence_groups)
                 {
                     'outer: for pair in sequence_groups {
-                        if pair.len() != 2 {
+                        if !is_credit_bearing_pair(pair, strand_courses) {
                             continue;
                         }
 
                         let mut found_indices = Vec::new();
-                        let mut credits_sum = 0.0;
+                        let mut found_credits = Vec::new();
 
                         for code in pair {
                             if let Some(def_course) =
                                 strand_courses.iter().find(|c| &c.code == code)
                             {
-                                if let Some((idx, parsed)) =
-                                    courses.iter().enumerate().find(|(idx, parsed)| {
-                                        !used_indices.contains(idx)
-                                            && parsed.code == *code
-                                            && is_passing_grade(&parsed.grade)
-                                    })
+                                if let Some((idx, parsed)) = index.find_unused(code, &used_indices)
                                 {
                                     found_indices.push(idx);
-                                    credits_sum +=
-                                        matched_course_credits(def_course.credits, parsed);
+                                    found_credits
+                                        .push(index.matched_credits(def_course.credits, parsed));
                                 }
                             }
                         }
 
                         if found_indices.len() == 2 {
-                            for idx in found_indices {
+                            for (idx, credited) in found_indices.into_iter().zip(found_credits) {
                                 used_indices.insert(idx);
+                                completed_credits += credited;
+                                matches.insert(
+                                    idx,
+                                    CourseMatch {
+                                        requirement: strand.name.clone(),
+                                        credited,
+                                    },
+                                );
                             }
-                            completed_credits += credits_sum;
                             sequence_satisfied = true;
 
                             break 'outer;
@@ -79,9 +319,10 @@ pub fn audit_gen_ed(
 
                 if !sequence_satisfied {
                     if let Some(sequence_groups) = &strand.sequence_groups {
+                        let strand_courses = strand.courses.as_deref().unwrap_or_default();
                         let pair_text = sequence_groups
                             .iter()
-                            .filter(|p| p.len() == 2)
+                            .filter(|p| is_credit_bearing_pair(p, strand_courses))
                             .map(|p| format!("{} + {}", p[0], p[1]))
                             .collect::<Vec<_>>()
                             .join(" OR ");
@@ -92,29 +333,29 @@ pub fn audit_gen_ed(
                                 "{}: choose one pair ({})",
                                 strand.name, pair_text
                             ),
+                            reason: Some("No pair was found with both courses passed".to_string()),
                         });
                     }
                 }
             }
             "choose_one" => {
                 if let Some(strand_courses) = &strand.courses {
-                    if let Some((_course, idx, matched_credits)) =
-                        strand_courses.iter().find_map(|course| {
-                            courses
-                                .iter()
-                                .enumerate()
-                                .find(|(idx, parsed)| {
-                                    !used_indices.contains(idx)
-                                        && parsed.code == course.code
-                                        && is_passing_grade(&parsed.grade)
-                                })
-                                .map(|(idx, parsed)| {
-                                    (course, idx, matched_course_credits(course.credits, parsed))
-                                })
-                        })
-                    {
+                    if let Some((idx, matched_credits)) = strand_courses.iter().find_map(|course| {
+                        index
+                            .find_unused(&course.code, &used_indices)
+                            .map(|(idx, parsed)| {
+                                (idx, index.matched_credits(course.credits, parsed))
+                            })
+                    }) {
                         completed_credits += matched_credits;
                         used_indices.insert(idx);
+                        matches.insert(
+                            idx,
+                            CourseMatch {
+                                requirement: strand.name.clone(),
+                                credited: matched_credits,
+                            },
+                        );
                     } else {
                         let options = strand_courses
                             .iter()
@@ -125,6 +366,10 @@ pub fn audit_gen_ed(
                         missing_courses.push(MissingCourse {
                             category: "General Education".to_string(),
                             description: format!("{}: choose 1 ({})", strand.name, options),
+                            reason: Some(format!(
+                                "None of the {} options was found with a passing grade",
+                                strand_courses.len()
+                            )),
                         });
                     }
                 }
@@ -140,20 +385,37 @@ pub fn audit_gen_ed(
                             }
 
                             if let Some((idx, parsed)) =
-                                courses.iter().enumerate().find(|(idx, parsed)| {
-                                    !used_indices.contains(idx)
-                                        && parsed.code == course.code
-                                        && is_passing_grade(&parsed.grade)
-                                })
+                                index.find_unused(&course.code, &used_indices)
                             {
-                                let matched_credits =
-                                    matched_course_credits(course.credits, parsed);
-                                completed_credits += matched_credits;
+                                let matched_credits = index.matched_credits(course.credits, parsed);
+                                // Only the part within the sub-group requirement counts
+                                let credited = matched_credits
+                                    .min(sub_group.required_credits - sub_group_credits);
                                 sub_group_credits += matched_credits;
                                 used_indices.insert(idx);
+                                matches.insert(
+                                    idx,
+                                    CourseMatch {
+                                        requirement: format!(
+                                            "{} > {}",
+                                            strand.name, sub_group.name
+                                        ),
+                                        credited,
+                                    },
+                                );
                             }
                         }
 
+                        // A course larger than the sub-group (e.g. 3 credits in a
+                        // 2-credit sub-group) only counts up to the requirement.
+                        completed_credits += sub_group_credits.min(sub_group.required_credits);
+                        sub_group_progress.push(StrandProgress {
+                            name: sub_group.name.clone(),
+                            completed: sub_group_credits.min(sub_group.required_credits),
+                            required: sub_group.required_credits,
+                            sub_groups: Vec::new(),
+                        });
+
                         if sub_group_credits < sub_group.required_credits {
                             let options = sub_group
                                 .courses
@@ -171,6 +433,7 @@ pub fn audit_gen_ed(
                                     sub_group.required_credits - sub_group_credits,
                                     options
                                 ),
+                                reason: None,
                             });
                         }
                     }
@@ -179,16 +442,18 @@ pub fn audit_gen_ed(
             _ => {
                 if let Some(strand_courses) = &strand.courses {
                     for course in strand_courses {
-                        if let Some((idx, parsed)) =
-                            courses.iter().enumerate().find(|(idx, parsed)| {
-                                !used_indices.contains(idx)
-                                    && parsed.code == course.code
-                                    && is_passing_grade(&parsed.grade)
-                            })
+                        if let Some((idx, parsed)) = index.find_unused(&course.code, &used_indices)
                         {
-                            let matched_credits = matched_course_credits(course.credits, parsed);
+                            let matched_credits = index.matched_credits(course.credits, parsed);
                             completed_credits += matched_credits;
                             used_indices.insert(idx);
+                            matches.insert(
+                                idx,
+                                CourseMatch {
+                                    requirement: strand.name.clone(),
+                                    credited: matched_credits,
+                                },
+                            );
                         } else {
                             missing_courses.push(MissingCourse {
                                 category: "General Education".to_string(),
@@ -196,30 +461,71 @@ pub fn audit_gen_ed(
                                     "{}: {} - {}",
                                     strand.name, course.code, course.name
                                 ),
+                                reason: None,
                             });
                         }
                     }
                 }
             }
         }
+
+        strands.push(StrandProgress {
+            name: strand.name.clone(),
+            completed: completed_credits - strand_start_credits,
+            required: strand.required_credits,
+            sub_groups: sub_group_progress,
+        });
     }
 
+    // A code cross-listed in several sub-categories counts once in total, even
+    // when a retake would give the later sub-category another enrollment.
+    let mut claimed_elective_codes: HashSet<String> = HashSet::new();
     for sub_cat in &curriculum.electives.sub_categories {
         let mut sub_cat_credits = 0.0;
+        let mut sub_cat_courses = 0;
         for course in &sub_cat.courses {
-            if let Some((idx, parsed)) = courses.iter().enumerate().find(|(idx, parsed)| {
-                !used_indices.contains(idx)
-                    && parsed.code == course.code
-                    && is_passing_grade(&parsed.grade)
-            }) {
-                let matched_credits = matched_course_credits(course.credits, parsed);
+            // Courses beyond the sub-category cap are left unclaimed so they
+            // fall through to free electives instead of inflating GenEd.
+            if sub_cat_courses >= sub_cat.max_courses {
+                break;
+            }
+            let code = canonical_code(&course.code);
+            if claimed_elective_codes.contains(&code) || reserved.contains(&code) {
+                continue;
+            }
+
+            if let Some((idx, parsed)) = index.find_unused(&course.code, &used_indices) {
+                claimed_elective_codes.insert(code);
+                let matched_credits = index.matched_credits(course.credits, parsed);
                 completed_credits += matched_credits;
                 gen_ed_elective_total_credits += matched_credits;
                 sub_cat_credits += matched_credits;
+                sub_cat_courses += 1;
                 used_indices.insert(idx);
+                matches.insert(
+                    idx,
+                    CourseMatch {
+                        requirement: format!("{} > {}", curriculum.electives.name, sub_cat.name),
+                        credited: matched_credits,
+                    },
+                );
             }
         }
 
+        // Sub-categories without a course or credit floor (every one in the
+        // current data) never report here, so a short student sees only the
+        // overall elective shortfall below, not one line per sub-category.
+        if sub_cat_courses < sub_cat.min_courses {
+            missing_courses.push(MissingCourse {
+                category: "General Education".to_string(),
+                description: format!(
+                    "GenEd Elective > {}: take at least {} courses ({} taken)",
+                    sub_cat.name, sub_cat.min_courses, sub_cat_courses
+                ),
+                reason: None,
+            });
+        }
+
         if sub_cat_credits < sub_cat.required_credits {
             missing_courses.push(MissingCourse {
                 category: "General Education".to_string(),
@@ -228,6 +534,7 @@ pub fn audit_gen_ed(
                     sub_cat.name,
                     sub_cat.required_credits - sub_cat_credits
                 ),
+                reason: None,
             });
         }
     }
@@ -240,6 +547,7 @@ pub fn audit_gen_ed(
                 curriculum.electives.name,
                 curriculum.electives.total_required_credits - gen_ed_elective_total_credits
             ),
+            reason: None,
         });
     }
 
@@ -256,76 +564,142 @@ pub fn audit_gen_ed(
                     "Overall General Education: missing {:.1} credits",
                     curriculum.total_required_credits - completed_credits
                 ),
+                reason: None,
             });
         }
     }
 
-    (completed_credits, missing_courses, used_indices)
+    GenEdSummary {
+        credits: CreditSummary {
+            completed: completed_credits,
+            required: curriculum.total_required_credits,
+            missing: missing_courses,
+            used: used_indices,
+            matches,
+        },
+        strands,
+    }
 }
 
 /// Audits courses against the major curriculum, including greedy matching for
 /// special-topics and other elective buckets. Credits are taken from curriculum
 /// data to avoid PDF parsing drift.
+///
+/// `pre_claimed` holds transcript indices already consumed by an earlier audit
+/// phase (normally GenEd); they are never matched again, so one enrollment
+/// cannot count toward both GenEd and Major. Pass an empty set to audit the
+/// major independently. `options.elective_policy` decides whether cluster
+/// courses claimed elsewhere still count toward cluster completion. When
+/// `options.pinned_clusters` is set, the per-cluster missing lines name the
+/// remaining courses of the pinned clusters only.
 pub fn audit_major(
     courses: &[ParsedCourse],
     curriculum: &MajorCurriculum,
-) -> (f32, f32, Vec<MissingCourse>, HashSet<usize>) {
+    pre_claimed: &HashSet<usize>,
+    options: &AuditOptions,
+) -> MajorSummary {
+    debug_assert_credits_sane(courses);
     let mut completed_credits = 0.0;
     let mut elective_credits = 0.0;
     let mut missing_courses: Vec<MissingCourse> = Vec::new();
-    let mut used_indices = HashSet::new();
+    let mut used_indices = pre_claimed.clone();
+    let mut matches: HashMap<usize, CourseMatch> = HashMap::new();
+    let index = CourseIndex::new(courses, options).with_curriculum_credits(
+        curriculum
+            .basic_science
+            .courses
+            .iter()
+            .chain(&curriculum.core_courses.courses)
+            .chain(&curriculum.capstone.options)
+            .chain(&curriculum.electives.others)
+            .chain(
+                curriculum
+                    .electives
+                    .domains
+                    .iter()
+                    .flat_map(|domain| &domain.clusters)
+                    .flat_map(|cluster| &cluster.courses),
+            )
+            .map(|course| (course.code.as_str(), course.credits)),
+    );
 
     for course in &curriculum.basic_science.courses {
-        if let Some((idx, parsed)) = courses.iter().enumerate().find(|(idx, parsed)| {
-            !used_indices.contains(idx)
-                && parsed.code == course.code
-                && is_passing_grade(&parsed.grade)
-        }) {
-            let matched_credits = matched_course_credits(course.credits, parsed);
+        if let Some((idx, parsed)) = index.find_unused(&course.code, &used_indices) {
+            let matched_credits = index.matched_credits(course.credits, parsed);
             completed_credits += matched_credits;
             used_indices.insert(idx);
+            matches.insert(
+                idx,
+                CourseMatch {
+                    requirement: curriculum.basic_science.name.clone(),
+                    credited: matched_credits,
+                },
+            );
         } else {
             missing_courses.push(MissingCourse {
                 category: "Basic Science".to_string(),
                 description: format!("{} - {}", course.code, course.name),
+                reason: None,
             });
         }
     }
 
     for course in &curriculum.core_courses.courses {
-        if let Some((idx, parsed)) = courses.iter().enumerate().find(|(idx, parsed)| {
-            !used_indices.contains(idx)
-                && parsed.code == course.code
-                && is_passing_grade(&parsed.grade)
-        }) {
-            let matched_credits = matched_course_credits(course.credits, parsed);
+        if let Some((idx, parsed)) = index.find_unused(&course.code, &used_indices) {
+            let matched_credits = index.matched_credits(course.credits, parsed);
             completed_credits += matched_credits;
             used_indices.insert(idx);
+            matches.insert(
+                idx,
+                CourseMatch {
+                    requirement: curriculum.core_courses.name.clone(),
+                    credited: matched_credits,
+                },
+            );
         } else {
             missing_courses.push(MissingCourse {
                 category: "Core Courses".to_string(),
                 description: format!("{} - {}", course.code, course.name),
+                reason: None,
             });
         }
     }
 
-    let mut capstone_completed = false;
+    let mut capstone_taken: Option<&MajorCourse> = None;
     for option in &curriculum.capstone.options {
-        if let Some((idx, parsed)) = courses.iter().enumerate().find(|(idx, parsed)| {
-            !used_indices.contains(idx)
-                && parsed.code == option.code
-                && is_passing_grade(&parsed.grade)
-        }) {
-            let matched_credits = matched_course_credits(option.credits, parsed);
+        if let Some((idx, parsed)) = index.find_unused(&option.code, &used_indices) {
+            let matched_credits = index.matched_credits(option.credits, parsed);
             completed_credits += matched_credits;
             used_indices.insert(idx);
-            capstone_completed = true;
+            matches.insert(
+                idx,
+                CourseMatch {
+                    requirement: curriculum.capstone.name.clone(),
+                    credited: matched_credits,
+                },
+            );
+            capstone_taken = Some(option);
 
             break;
         }
     }
 
-    if !capstone_completed {
+    // Both capstone options on one transcript (e.g. after a path change): only
+    // the first counts, and the other is set aside instead of becoming a free
+    // elective.
+    let mut duplicate_capstones = Vec::new();
+    if let Some(taken) = capstone_taken {
+        for option in &curriculum.capstone.options {
+            if option.code == taken.code {
+                continue;
+            }
+            if let Some((idx, _)) = index.find_unused(&option.code, &used_indices) {
+                duplicate_capstones.push(idx);
+            }
+        }
+    }
+
+    if capstone_taken.is_none() {
         let options_desc = curriculum
             .capstone
             .options
@@ -337,33 +711,58 @@ pub fn audit_major(
         missing_courses.push(MissingCourse {
             category: "Capstone".to_string(),
             description: format!("Choose 1: {}", options_desc),
+            reason: None,
         });
     }
 
+    // Everything claimed so far belongs to the required (non-elective) portion.
+    let required_missing_count = missing_courses.len();
+    let required_used = used_indices.clone();
+
+    // Required basic science and core codes never count toward a cluster,
+    // even when a cluster lists them too (`validate_curriculum` flags this)
+    let required_codes = major_required_codes(curriculum);
+
     let mut completed_clusters_count = 0;
+    // Incomplete clusters with how many courses are still needed and the
+    // codes not yet found
+    let mut incomplete_clusters: Vec<(&MajorCluster, u32, Vec<&str>)> = Vec::new();
     for domain in &curriculum.electives.domains {
         for cluster in &domain.clusters {
             let mut courses_found_in_cluster = 0;
-            for course in &cluster.courses {
-                if let Some((idx, parsed)) = courses.iter().enumerate().find(|(idx, parsed)| {
-                    !used_indices.contains(idx)
-                        && parsed.code == course.code
-                        && is_passing_grade(&parsed.grade)
-                }) {
-                    let matched_credits = matched_course_credits(course.credits, parsed);
+            let mut remaining_codes = Vec::new();
+            let cluster_courses = cluster
+                .courses
+                .iter()
+                .filter(|course| !required_codes.contains(&canonical_code(&course.code)));
+            for course in cluster_courses {
+                if let Some((idx, parsed)) = index.find_unused(&course.code, &used_indices) {
+                    let matched_credits = index.matched_credits(course.credits, parsed);
                     elective_credits += matched_credits;
                     used_indices.insert(idx);
+                    matches.insert(
+                        idx,
+                        CourseMatch {
+                            requirement: format!("Cluster {} {}", cluster.id, cluster.name),
+                            credited: matched_credits,
+                        },
+                    );
                     courses_found_in_cluster += 1;
-                } else if courses
-                    .iter()
-                    .any(|c| c.code == course.code && is_passing_grade(&c.grade))
+                } else if options.elective_policy == ElectivePolicy::Lenient
+                    && index.has_passing(&course.code)
                 {
-                    // Course taken but used elsewhere (or duplicate). Still counts towards completion of the cluster.
+                    // Course taken but used elsewhere (GenEd, another cluster or a duplicate). Still counts towards completion of the cluster.
                     courses_found_in_cluster += 1;
+                } else {
+                    remaining_codes.push(course.code.as_str());
                 }
             }
             if courses_found_in_cluster >= cluster.min_courses {
                 completed_clusters_count += 1;
+            } else if courses_found_in_cluster > 0 || options.pinned_clusters.contains(&cluster.id)
+            {
+                let still_needed = cluster.min_courses - courses_found_in_cluster;
+                incomplete_clusters.push((cluster, still_needed, remaining_codes));
             }
         }
     }
@@ -377,58 +776,2239 @@ pub fn audit_major(
                 completed_clusters_count,
                 curriculum.electives.clusters_to_complete
             ),
+            reason: Some(format!(
+                "You completed {} of the {} required clusters",
+                completed_clusters_count, curriculum.electives.clusters_to_complete
+            )),
         });
+
+        // Point at the concrete courses for the clusters the student pinned,
+        // or else for the started clusters closest to completion
+        let targeted: Vec<_> = if options.pinned_clusters.is_empty() {
+            incomplete_clusters.sort_by_key(|(_, still_needed, _)| *still_needed);
+            let clusters_short =
+                curriculum.electives.clusters_to_complete - completed_clusters_count;
+            incomplete_clusters
+                .into_iter()
+                .take(clusters_short as usize)
+                .collect()
+        } else {
+            incomplete_clusters
+                .into_iter()
+                .filter(|(cluster, _, _)| options.pinned_clusters.contains(&cluster.id))
+                .collect()
+        };
+        for (cluster, still_needed, remaining_codes) in targeted {
+            let codes = remaining_codes.join(", ");
+            let description = if remaining_codes.len() as u32 > still_needed {
+                format!(
+                    "Cluster {} {}: still need {} of {}",
+                    cluster.id, cluster.name, still_needed, codes
+                )
+            } else {
+                format!(
+                    "Cluster {} {}: still need {}",
+                    cluster.id, cluster.name, codes
+                )
+            };
+            missing_courses.push(MissingCourse {
+                category: "Major Electives".to_string(),
+                description,
+                reason: Some(format!(
+                    "You completed {} of the {} required courses",
+                    cluster.min_courses - still_needed,
+                    cluster.min_courses
+                )),
+            });
+        }
     }
 
-    // Greedy match "others" electives so repeated special topics accumulate credits.
+    // Greedy match "others" electives so repeated special topics accumulate
+    // credits, up to the special-topic limit and only until the elective
+    // requirement is met; the rest become free electives.
+    let required_electives = required_elective_credits(curriculum, capstone_taken);
+    let limit = options.special_topic_limit;
+    let mut special_topic_courses = 0;
+    let mut special_topic_credits = 0.0;
     for course in &curriculum.electives.others {
-        for (idx, parsed) in courses.iter().enumerate() {
-            if !used_indices.contains(&idx)
-                && parsed.code == course.code
-                && is_passing_grade(&parsed.grade)
+        let capped = is_special_topic(&course.code);
+        while let Some((idx, parsed)) = index.find_unused(&course.code, &used_indices) {
+            if elective_credits >= required_electives {
+                break;
+            }
+            let matched_credits = index.matched_credits(course.credits, parsed);
+            if capped
+                && (special_topic_courses >= limit.max_courses
+                    || special_topic_credits + matched_credits > limit.max_credits)
             {
-                let matched_credits = matched_course_credits(course.credits, parsed);
-                elective_credits += matched_credits;
-                used_indices.insert(idx);
+                break;
+            }
+            if capped {
+                special_topic_courses += 1;
+                special_topic_credits += matched_credits;
             }
+            elective_credits += matched_credits;
+            used_indices.insert(idx);
+            matches.insert(
+                idx,
+                CourseMatch {
+                    requirement: curriculum.electives.name.clone(),
+                    credited: matched_credits,
+                },
+            );
+        }
+    }
+
+    if elective_credits < required_electives {
+        missing_courses.push(MissingCourse {
+            category: "Major Electives".to_string(),
+            description: format!(
+                "{}: missing {:.1} credits",
+                curriculum.electives.name,
+                required_electives - elective_credits
+            ),
+            reason: None,
+        });
+    }
+
+    let mut elective_missing = missing_courses.split_off(required_missing_count);
+    let elective_used: HashSet<usize> = used_indices.difference(&required_used).copied().collect();
+    let (elective_matches, required_matches) = matches
+        .into_iter()
+        .partition(|(idx, _)| elective_used.contains(idx));
+
+    // Overall shortfall is listed last (after the elective lines), as in the GenEd audit.
+    if completed_credits + elective_credits < curriculum.total_required_credits {
+        elective_missing.push(MissingCourse {
+            category: "Major Courses".to_string(),
+            description: format!(
+                "Overall Major: missing {:.1} credits",
+                curriculum.total_required_credits - completed_credits - elective_credits
+            ),
+            reason: None,
+        });
+    }
+
+    let capstone_credits = capstone_taken
+        .map(|option| option.credits)
+        .unwrap_or(curriculum.capstone.credits_per_option);
+
+    MajorSummary {
+        courses: CreditSummary {
+            completed: completed_credits,
+            required: curriculum.basic_science.required_credits
+                + curriculum.core_courses.required_credits
+                + capstone_credits,
+            missing: missing_courses,
+            used: required_used.difference(pre_claimed).copied().collect(),
+            matches: required_matches,
+        },
+        electives: CreditSummary {
+            completed: elective_credits,
+            required: required_electives,
+            missing: elective_missing,
+            used: elective_used,
+            matches: elective_matches,
+        },
+        duplicate_capstones,
+    }
+}
+
+/// Audits a minor over the courses GenEd and the major left unclaimed
+/// (`pre_claimed`), so one enrollment never counts toward both. Required
+/// courses are matched first; listed electives then fill the credits still
+/// short of the minor's total.
+pub fn audit_minor(
+    courses: &[ParsedCourse],
+    curriculum: &MinorCurriculum,
+    pre_claimed: &HashSet<usize>,
+    options: &AuditOptions,
+) -> CreditSummary {
+    debug_assert_credits_sane(courses);
+    let mut completed = 0.0;
+    let mut missing = Vec::new();
+    let mut used_indices = pre_claimed.clone();
+    let mut matches: HashMap<usize, CourseMatch> = HashMap::new();
+    let index = CourseIndex::new(courses, options).with_curriculum_credits(
+        curriculum
+            .required_courses
+            .iter()
+            .chain(&curriculum.electives)
+            .map(|course| (course.code.as_str(), course.credits)),
+    );
+
+    for course in &curriculum.required_courses {
+        if let Some((idx, parsed)) = index.find_unused(&course.code, &used_indices) {
+            let credited = index.matched_credits(course.credits, parsed);
+            completed += credited;
+            used_indices.insert(idx);
+            matches.insert(
+                idx,
+                CourseMatch {
+                    requirement: curriculum.name.clone(),
+                    credited,
+                },
+            );
+        } else {
+            missing.push(MissingCourse {
+                category: curriculum.name.clone(),
+                description: format!("{} - {}", course.code, course.name),
+                reason: None,
+            });
         }
     }
 
-    (
-        completed_credits,
-        elective_credits,
-        missing_courses,
-        used_indices,
-    )
+    for course in &curriculum.electives {
+        if completed >= curriculum.total_required_credits {
+            break;
+        }
+        if let Some((idx, parsed)) = index.find_unused(&course.code, &used_indices) {
+            let credited = index
+                .matched_credits(course.credits, parsed)
+                .min(curriculum.total_required_credits - completed);
+            completed += credited;
+            used_indices.insert(idx);
+            matches.insert(
+                idx,
+                CourseMatch {
+                    requirement: curriculum.name.clone(),
+                    credited,
+                },
+            );
+        }
+    }
+
+    if completed < curriculum.total_required_credits && !curriculum.electives.is_empty() {
+        missing.push(MissingCourse {
+            category: curriculum.name.clone(),
+            description: format!(
+                "Electives: {:.1} more credits",
+                curriculum.total_required_credits - completed
+            ),
+            reason: None,
+        });
+    }
+
+    CreditSummary {
+        completed,
+        required: curriculum.total_required_credits,
+        missing,
+        used: used_indices.difference(pre_claimed).copied().collect(),
+        matches,
+    }
 }
 
 /// Calculates free-elective credits from unused courses, pulling credit values
-/// directly from the PDF when the course is not mapped elsewhere.
+/// directly from the PDF when the course is not mapped elsewhere. Courses the
+/// policy rejects (0-credit, remedial or below the minimum grade) are returned
+/// separately with a reason. `P` courses are skipped when `passing_policy`
+/// gives them no credits.
 pub fn calculate_free_electives(
     courses: &[ParsedCourse],
     used_indices: &HashSet<usize>,
-) -> (f32, Vec<String>) {
-    let mut free_elective_credits = 0.0;
-    let mut free_elective_list = Vec::new();
+    policy: &FreeElectivePolicy,
+    assume_in_progress_pass: bool,
+    passing_policy: PassingPolicy,
+) -> FreeElectiveSummary {
+    debug_assert_credits_sane(courses);
+    let mut summary = FreeElectiveSummary::default();
     let mut seen_free_electives: HashSet<String> = HashSet::new();
 
     for (idx, parsed) in courses.iter().enumerate() {
-        if !used_indices.contains(&idx) {
-            if is_passing_grade(&parsed.grade) {
-                let dedupe_key = free_elective_dedupe_key(&parsed.code, &parsed.name);
-                if !seen_free_electives.insert(dedupe_key) {
-                    continue;
-                }
+        if !used_indices.contains(&idx)
+            && counts_as_passed(&parsed.grade, assume_in_progress_pass)
+            && passing_policy.earns_credits(&parsed.grade)
+        {
+            let dedupe_key = free_elective_dedupe_key(&parsed.code, &parsed.name);
+            if !seen_free_electives.insert(dedupe_key) {
+                continue;
+            }
+
+            let exclusion = if policy
+                .remedial_codes
+                .contains(&canonical_code(&parsed.code))
+            {
+                Some(ExclusionReason::Remedial)
+            } else if policy.exclude_zero_credit && parsed.parsed_credit <= 0.0 {
+                Some(ExclusionReason::ZeroCredit)
+            } else if policy.min_grade_point.is_some_and(|min| {
+                // Pass-only grades (S/P/G) carry no grade point and are not held to the minimum
+                grade_point(&parsed.grade).is_some_and(|points| points < min)
+            }) {
+                Some(ExclusionReason::BelowMinimumGrade)
+            } else {
+                None
+            };
+
+            if let Some(reason) = exclusion {
+                summary.excluded.push(ExcludedCourse {
+                    course: to_course(parsed),
+                    reason,
+                });
+                continue;
+            }
+
+            let credits = parsed.parsed_credit;
+            summary.credits += credits;
+            summary.indices.push(idx);
+            summary.list.push(format!(
+                "{} (Grade: {}, {} cr)",
+                parsed.code, parsed.grade, credits
+            ));
+        }
+    }
+
+    summary
+}
+
+/// Lists every course definition in the GenEd and Major curricula (strands,
+/// sub-groups, electives, basic science, core, capstone, others, clusters) in
+/// that order. A code defined in several places appears once per place.
+pub fn curriculum_courses(
+    gen_ed: &GenEdCurriculum,
+    major: &MajorCurriculum,
+) -> Vec<CurriculumCourseRef> {
+    let mut refs = Vec::new();
+    let mut add = |code: &str, name: &str, credits: f32, location: CurriculumLocation| {
+        refs.push(CurriculumCourseRef {
+            code: code.to_string(),
+            name: name.to_string(),
+            credits,
+            location,
+        });
+    };
+
+    for strand in &gen_ed.strands {
+        for course in strand.courses.iter().flatten() {
+            let location = CurriculumLocation::GenEdStrand(strand.name.clone());
+            add(&course.code, &course.name, course.credits, location);
+        }
+        for sub_group in strand.sub_groups.iter().flatten() {
+            for course in &sub_group.courses {
+                let location = CurriculumLocation::GenEdSubGroup {
+                    strand: strand.name.clone(),
+                    sub_group: sub_group.name.clone(),
+                };
+                add(&course.code, &course.name, course.credits, location);
+            }
+        }
+    }
+    for sub_cat in &gen_ed.electives.sub_categories {
+        for course in &sub_cat.courses {
+            let location = CurriculumLocation::GenEdElective(sub_cat.name.clone());
+            add(&course.code, &course.name, course.credits, location);
+        }
+    }
+
+    let major_sections = [
+        (
+            &major.basic_science.courses,
+            CurriculumLocation::BasicScience,
+        ),
+        (&major.core_courses.courses, CurriculumLocation::Core),
+        (&major.capstone.options, CurriculumLocation::Capstone),
+        (&major.electives.others, CurriculumLocation::OtherElective),
+    ];
+    for (courses, location) in major_sections {
+        for course in courses {
+            add(&course.code, &course.name, course.credits, location.clone());
+        }
+    }
+    for cluster in major.electives.domains.iter().flat_map(|d| &d.clusters) {
+        for course in &cluster.courses {
+            let location = CurriculumLocation::Cluster {
+                id: cluster.id.clone(),
+                name: cluster.name.clone(),
+            };
+            add(&course.code, &course.name, course.credits, location);
+        }
+    }
+
+    refs
+}
+
+/// Finds the first curriculum definition of `code` (compared in canonical
+/// form), e.g. to fill in the name and credits of a manually added course.
+pub fn lookup_course(
+    code: &str,
+    gen_ed: &GenEdCurriculum,
+    major: &MajorCurriculum,
+) -> Option<CurriculumCourseRef> {
+    let code = canonical_code(code);
+    curriculum_courses(gen_ed, major)
+        .into_iter()
+        .find(|course| course.code == code)
+}
+
+/// Requirement name for `location`, worded like `CourseMatch::requirement`
+/// (e.g. "GenEd Electives (GE8) > Sub-category", "Cluster 1 Name").
+fn requirement_label(
+    location: &CurriculumLocation,
+    gen_ed: &GenEdCurriculum,
+    major: &MajorCurriculum,
+) -> String {
+    match location {
+        CurriculumLocation::GenEdStrand(strand) => strand.clone(),
+        CurriculumLocation::GenEdSubGroup { strand, sub_group } => {
+            format!("{} > {}", strand, sub_group)
+        }
+        CurriculumLocation::GenEdElective(sub_cat) => {
+            format!("{} > {}", gen_ed.electives.name, sub_cat)
+        }
+        CurriculumLocation::BasicScience => major.basic_science.name.clone(),
+        CurriculumLocation::Core => major.core_courses.name.clone(),
+        CurriculumLocation::Capstone => major.capstone.name.clone(),
+        CurriculumLocation::OtherElective => major.electives.name.clone(),
+        CurriculumLocation::Cluster { id, name } => format!("Cluster {} {}", id, name),
+    }
+}
+
+/// Every requirement that lists `code` (compared in canonical form), in
+/// curriculum order and without repeats, whichever one the audit actually
+/// matched it to. Empty for a course only free electives can take.
+pub fn course_eligibility(
+    code: &str,
+    gen_ed: &GenEdCurriculum,
+    major: &MajorCurriculum,
+) -> Vec<String> {
+    let code = canonical_code(code);
+    let mut requirements: Vec<String> = Vec::new();
+    for course in curriculum_courses(gen_ed, major) {
+        if canonical_code(&course.code) != code {
+            continue;
+        }
+        let label = requirement_label(&course.location, gen_ed, major);
+        if !requirements.contains(&label) {
+            requirements.push(label);
+        }
+    }
+    requirements
+}
+
+/// Autocomplete suggestions for a partly typed course: courses whose code
+/// starts with `query` or whose name contains it (case-insensitive), one per
+/// code, in curriculum order, at most `limit`. An empty query suggests nothing.
+pub fn suggest_courses<'a>(
+    query: &str,
+    catalog: &'a [CurriculumCourseRef],
+    limit: usize,
+) -> Vec<&'a CurriculumCourseRef> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut seen = HashSet::new();
+    catalog
+        .iter()
+        .filter(|course| {
+            course.code.starts_with(&query) || course.name.to_lowercase().contains(&query)
+        })
+        .filter(|course| seen.insert(course.code.as_str()))
+        .take(limit)
+        .collect()
+}
+
+/// Maps every course code referenced anywhere in the GenEd and Major curricula
+/// to its English curriculum name. The first definition of a code wins.
+pub fn curriculum_course_names(
+    gen_ed: &GenEdCurriculum,
+    major: &MajorCurriculum,
+) -> HashMap<String, String> {
+    let mut names = HashMap::new();
+    for course in curriculum_courses(gen_ed, major) {
+        names.entry(course.code).or_insert(course.name);
+    }
+    names
+}
+
+/// Collects every course code referenced anywhere in the GenEd and Major curricula.
+pub fn curriculum_codes(gen_ed: &GenEdCurriculum, major: &MajorCurriculum) -> HashSet<String> {
+    curriculum_course_names(gen_ed, major).into_keys().collect()
+}
+
+/// Canonical codes of the major's required basic science and core courses.
+pub fn major_required_codes(major: &MajorCurriculum) -> HashSet<String> {
+    major
+        .basic_science
+        .courses
+        .iter()
+        .chain(&major.core_courses.courses)
+        .map(|course| canonical_code(&course.code))
+        .collect()
+}
+
+/// Codes every student must pass: basic science, core courses and the courses
+/// of "choose_all" GenEd strands.
+pub fn required_course_codes(gen_ed: &GenEdCurriculum, major: &MajorCurriculum) -> HashSet<String> {
+    let gen_ed_codes = gen_ed
+        .strands
+        .iter()
+        .filter(|strand| strand.selection_rule.as_deref().unwrap_or("choose_all") == "choose_all")
+        .flat_map(|strand| strand.courses.iter().flatten())
+        .map(|course| course.code.as_str());
+    gen_ed_codes
+        .map(canonical_code)
+        .chain(major_required_codes(major))
+        .collect()
+}
+
+/// Failed courses still to be retaken, each marked `required` when the
+/// curriculum demands that exact code (see `required_course_codes`).
+pub fn retake_courses(
+    courses: &[ParsedCourse],
+    gen_ed: &GenEdCurriculum,
+    major: &MajorCurriculum,
+) -> Vec<RetakeCourse> {
+    let required_codes = required_course_codes(gen_ed, major);
+    failed_courses(courses)
+        .iter()
+        .map(|parsed| RetakeCourse {
+            course: to_course(parsed),
+            required: required_codes.contains(&canonical_code(&parsed.code)),
+        })
+        .collect()
+}
+
+/// Counts how many passed courses landed in a category: claimed by a
+/// requirement (`used_indices`), counted as a free elective (`free_list`), or
+/// neither. Claimed courses count even when not passed (e.g. assumed `IP`).
+pub fn coverage(
+    courses: &[ParsedCourse],
+    used_indices: &HashSet<usize>,
+    free_list: &[usize],
+) -> Coverage {
+    let free: HashSet<usize> = free_list.iter().copied().collect();
+    let mut coverage = Coverage::default();
+    for (idx, parsed) in courses.iter().enumerate() {
+        if used_indices.contains(&idx) {
+            coverage.matched += 1;
+        } else if free.contains(&idx) {
+            coverage.free += 1;
+        } else if is_passing_grade(&parsed.grade) {
+            coverage.uncounted += 1;
+        } else {
+            continue;
+        }
+        coverage.total += 1;
+    }
+    coverage
+}
+
+/// Returns passing, unclaimed courses whose codes appear in no curriculum
+/// requirement. These still count as free electives, but an unknown code often
+/// points at a parser error or a cross-faculty course worth double-checking.
+pub fn unrecognized_courses(
+    courses: &[ParsedCourse],
+    used_indices: &HashSet<usize>,
+    gen_ed: &GenEdCurriculum,
+    major: &MajorCurriculum,
+) -> Vec<ParsedCourse> {
+    let known_codes = curriculum_codes(gen_ed, major);
+
+    courses
+        .iter()
+        .enumerate()
+        .filter(|(idx, parsed)| {
+            !used_indices.contains(idx)
+                && is_passing_grade(&parsed.grade)
+                && !known_codes.contains(&canonical_code(&parsed.code))
+        })
+        .map(|(_, parsed)| parsed.clone())
+        .collect()
+}
+
+/// Converts a parsed transcript row into a displayable course.
+fn to_course(parsed: &ParsedCourse) -> Course {
+    Course {
+        code: parsed.code.clone(),
+        name: parsed.name.clone(),
+        credit: parsed.parsed_credit,
+        grade: parsed.grade.clone(),
+        curriculum_name: None,
+        substitutes_for: None,
+        section: parsed.section.clone(),
+        matched: None,
+    }
+}
+
+/// Passed-course counts for every elective cluster in curriculum order. A
+/// course counts wherever it was claimed (GenEd or another cluster), so the
+/// board shows how close each cluster is regardless of policy. Required core
+/// codes a cluster lists are left out, as in the audit.
+pub fn cluster_progress(
+    courses: &[ParsedCourse],
+    curriculum: &MajorCurriculum,
+    options: &AuditOptions,
+) -> Vec<ClusterProgress> {
+    let index = CourseIndex::new(courses, options);
+    let required_codes = major_required_codes(curriculum);
+    curriculum
+        .electives
+        .domains
+        .iter()
+        .flat_map(|domain| domain.clusters.iter().map(move |cluster| (domain, cluster)))
+        .map(|(domain, cluster)| {
+            let cluster_courses: Vec<&MajorCourse> = cluster
+                .courses
+                .iter()
+                .filter(|course| !required_codes.contains(&canonical_code(&course.code)))
+                .collect();
+            ClusterProgress {
+                domain: domain.name.clone(),
+                cluster_id: cluster.id.clone(),
+                name: cluster.name.clone(),
+                completed_courses: cluster_courses
+                    .iter()
+                    .filter(|course| index.has_passing(&course.code))
+                    .count() as u32,
+                required_courses: cluster.min_courses,
+                total_courses: cluster_courses.len() as u32,
+            }
+        })
+        .collect()
+}
+
+/// Flags major courses passed without their co-requisites (a lecture without
+/// its lab, or the other way round). Advisory only: credits are unaffected.
+pub fn corequisite_warnings(
+    courses: &[ParsedCourse],
+    major: &MajorCurriculum,
+    options: &AuditOptions,
+) -> Vec<CorequisiteWarning> {
+    let index = CourseIndex::new(courses, options);
+    let major_courses: Vec<&MajorCourse> = major
+        .basic_science
+        .courses
+        .iter()
+        .chain(&major.core_courses.courses)
+        .collect();
+    let name_of = |code: &str| {
+        major_courses
+            .iter()
+            .find(|c| canonical_code(&c.code) == canonical_code(code))
+            .map(|c| c.name.clone())
+            .unwrap_or_default()
+    };
+
+    let mut warnings = Vec::new();
+    for course in &major_courses {
+        let Some((_, parsed)) = index.find_unused(&course.code, &HashSet::new()) else {
+            continue;
+        };
+        for coreq in &course.corequisites {
+            if !index.has_passing(coreq) {
+                warnings.push(CorequisiteWarning {
+                    course: Course {
+                        curriculum_name: Some(course.name.clone()),
+                        ..to_course(parsed)
+                    },
+                    missing_code: coreq.clone(),
+                    missing_name: name_of(coreq),
+                });
+            }
+        }
+    }
+    warnings
+}
+
+/// Transcript indices a previous audit placed in a requirement category or
+/// excluded as a duplicate capstone, rebuilt from the listed courses so a
+/// result restored from JSON (which carries no indices) keeps its matching.
+/// Each listed course claims the first unclaimed row with the same code,
+/// grade, credit and section.
+fn claimed_indices_from_result(result: &AuditResult, courses: &[ParsedCourse]) -> HashSet<usize> {
+    let placed = result
+        .categories
+        .iter()
+        .filter(|category| category.name != "Free Electives")
+        .flat_map(|category| &category.courses)
+        .chain(
+            result
+                .excluded_courses
+                .iter()
+                .filter(|excluded| excluded.reason == ExclusionReason::DuplicateCapstone)
+                .map(|excluded| &excluded.course),
+        );
+
+    let mut claimed = HashSet::new();
+    for course in placed {
+        let row = courses.iter().enumerate().position(|(idx, parsed)| {
+            !claimed.contains(&idx)
+                && parsed.code == course.code
+                && parsed.grade == course.grade
+                && parsed.parsed_credit == course.credit
+                && parsed.section == course.section
+        });
+        if let Some(idx) = row {
+            claimed.insert(idx);
+        }
+    }
+    claimed
+}
+
+/// Lighter re-audit after courses were added or edited that cannot match any
+/// GenEd or Major requirement: GenEd/Major matching is kept as-is (the claimed
+/// rows are rebuilt from the requirement categories), and only the Free
+/// Electives category, total credits (with the degree-minimum check) and the
+/// course-level lists (excluded, unrecognized, weak grades, GPA) are
+/// recomputed from `courses`.
+///
+/// Callers must run `audit_transcript` instead when a changed course carries a
+/// curriculum code, since it could change which requirements are satisfied.
+pub fn recompute_free_electives(
+    result: &mut AuditResult,
+    courses: &[ParsedCourse],
+    gen_ed: &GenEdCurriculum,
+    major: &MajorCurriculum,
+    options: &AuditOptions,
+) {
+    result.claimed_indices = claimed_indices_from_result(result, courses);
+    let free_electives = calculate_free_electives(
+        courses,
+        &result.claimed_indices,
+        &options.free_elective_policy,
+        options.assume_in_progress_pass,
+        options.passing_policy,
+    );
+
+    if let Some(category) = result
+        .categories
+        .iter_mut()
+        .find(|c| c.name == "Free Electives")
+    {
+        result.total_credits += free_electives.credits - category.collected_credits;
+        category.collected_credits = free_electives.credits;
+        let curriculum_names = curriculum_course_names(gen_ed, major);
+        category.courses = free_electives
+            .indices
+            .iter()
+            .map(|&idx| Course {
+                curriculum_name: curriculum_names
+                    .get(&canonical_code(&courses[idx].code))
+                    .cloned(),
+                ..to_course(&courses[idx])
+            })
+            .collect();
+    }
+    result
+        .missing_subjects
+        .retain(|m| m.category != TOTAL_CREDITS_CATEGORY);
+    if let Some(min_credits) = options.degree_min_credits {
+        result
+            .missing_subjects
+            .extend(total_credits_missing(result.total_credits, min_credits));
+    }
+
+    // Duplicate capstones come from the major audit and are kept as-is
+    result
+        .excluded_courses
+        .retain(|excluded| excluded.reason == ExclusionReason::DuplicateCapstone);
+    result.excluded_courses.extend(free_electives.excluded);
+    result.unrecognized_courses =
+        unrecognized_courses(courses, &result.claimed_indices, gen_ed, major)
+            .iter()
+            .map(to_course)
+            .collect();
+    result.weak_grade_courses = weak_grade_courses(courses, WEAK_GRADE_THRESHOLD)
+        .iter()
+        .map(to_course)
+        .collect();
+    result.withdrawn_courses = withdrawn_courses(courses).iter().map(to_course).collect();
+    result.retake_courses = retake_courses(courses, gen_ed, major);
+    result.coverage = coverage(courses, &result.claimed_indices, &free_electives.indices);
+    result.gpa = calculate_gpa(courses);
+}
+
+/// Runs the full audit pipeline (GenEd → Major → Free Electives) over parsed
+/// transcript courses and assembles the displayable `AuditResult`.
+pub fn audit_transcript(
+    courses: &[ParsedCourse],
+    gen_ed: &GenEdCurriculum,
+    major: &MajorCurriculum,
+    options: &AuditOptions,
+) -> AuditResult {
+    // Two-phase audit: GenEd claims first, and its indices are pre-claimed
+    // for the major so no enrollment is counted in both. GenEd electives
+    // leave codes the major's required groups list, per `MatchPriority`.
+    let reserved = reserved_codes(gen_ed, major, MatchPriority::GenEdElective);
+    let gen_ed_summary = audit_gen_ed(courses, gen_ed, &reserved, options);
+    let major_summary = audit_major(courses, major, &gen_ed_summary.credits.used, options);
+    let gen_ed_credits = gen_ed_summary.credits.completed;
+    let gen_ed_required = gen_ed_summary.credits.required;
+    let gen_ed_used = gen_ed_summary.credits.used;
+    let mut requirement_matches = gen_ed_summary.credits.matches;
+    requirement_matches.extend(major_summary.courses.matches.clone());
+    requirement_matches.extend(major_summary.electives.matches.clone());
+    let major_credits = major_summary.completed();
+    let major_used = major_summary.used();
+
+    let mut all_used_courses = gen_ed_used.clone();
+    all_used_courses.extend(major_used.clone());
+    all_used_courses.extend(&major_summary.duplicate_capstones);
+
+    // The minor runs third and only sees what GenEd and the major left
+    let minor_summary = options.minor.as_ref().map(|minor| {
+        (
+            minor,
+            audit_minor(courses, minor, &all_used_courses, options),
+        )
+    });
+    let minor_used = minor_summary
+        .as_ref()
+        .map(|(_, summary)| summary.used.clone())
+        .unwrap_or_default();
+    let minor_credits = minor_summary
+        .as_ref()
+        .map_or(0.0, |(_, summary)| summary.completed);
+    if let Some((_, summary)) = &minor_summary {
+        requirement_matches.extend(summary.matches.clone());
+    }
+    all_used_courses.extend(&minor_used);
+
+    let free_electives = calculate_free_electives(
+        courses,
+        &all_used_courses,
+        &options.free_elective_policy,
+        options.assume_in_progress_pass,
+        options.passing_policy,
+    );
+    let free_elective_credits = free_electives.credits;
+
+    let mut all_missing: Vec<MissingCourse> = gen_ed_summary.credits.missing;
+    all_missing.extend(major_summary.missing());
+
+    // Drop missing entries for GenEd if total GenEd credits are already met.
+    // DO NOT drop Major Core/Basic Science misses, as they are strictly required regardless of total accumulated elective credits.
+    // 0-credit prerequisites are gates, not credits, so they are never dropped either.
+    all_missing.retain(|m| match m.category.as_str() {
+        "General Education" => {
+            gen_ed_credits < gen_ed_required || m.description.starts_with("Prerequisite")
+        }
+        _ => true,
+    });
+    if let Some((_, summary)) = &minor_summary {
+        all_missing.extend(summary.missing.iter().cloned());
+    }
+    if let Some(marker) = &options.english_exit_marker {
+        all_missing.extend(english_exit_missing(courses, marker));
+    }
+
+    let total_credits = gen_ed_credits + major_credits + minor_credits + free_elective_credits;
+    if let Some(min_credits) = options.degree_min_credits {
+        all_missing.extend(total_credits_missing(total_credits, min_credits));
+    }
+
+    let mut gen_ed_courses = Vec::new();
+    let mut major_courses = Vec::new();
+    let mut minor_courses = Vec::new();
+    let mut free_elective_courses = Vec::new();
+    let curriculum_names = curriculum_course_names(gen_ed, major);
+    let matched_course = |idx: usize| {
+        let parsed = &courses[idx];
+        Course {
+            curriculum_name: curriculum_names.get(&canonical_code(&parsed.code)).cloned(),
+            substitutes_for: options.substitution_for(&parsed.code),
+            matched: requirement_matches.get(&idx).cloned(),
+            ..to_course(parsed)
+        }
+    };
+
+    let course_coverage = coverage(courses, &all_used_courses, &free_electives.indices);
+    for (idx, parsed) in courses.iter().enumerate() {
+        if gen_ed_used.contains(&idx) {
+            gen_ed_courses.push(matched_course(idx));
+        } else if major_used.contains(&idx) {
+            major_courses.push(matched_course(idx));
+        } else if minor_used.contains(&idx) {
+            minor_courses.push(matched_course(idx));
+        } else if free_electives.indices.contains(&idx) {
+            free_elective_courses.push(Course {
+                curriculum_name: curriculum_names.get(&canonical_code(&parsed.code)).cloned(),
+                ..to_course(parsed)
+            });
+        }
+    }
+
+    let minor_category = minor_summary.map(|(minor, summary)| Category {
+        name: minor.name.clone(),
+        required_credits: summary.required,
+        collected_credits: summary.completed,
+        courses: minor_courses,
+    });
+
+    AuditResult {
+        schema_version: AUDIT_SCHEMA_VERSION,
+        total_credits,
+        categories: [
+            Category {
+                name: "General Education".to_string(),
+                required_credits: gen_ed_required,
+                collected_credits: gen_ed_credits,
+                courses: gen_ed_courses,
+            },
+            Category {
+                name: "Major Courses".to_string(),
+                required_credits: major.total_required_credits,
+                collected_credits: major_credits,
+                courses: major_courses,
+            },
+        ]
+        .into_iter()
+        .chain(minor_category)
+        .chain([Category {
+            name: "Free Electives".to_string(),
+            required_credits: 6.0,
+            collected_credits: free_elective_credits,
+            courses: free_elective_courses,
+        }])
+        .collect(),
+        missing_subjects: all_missing,
+        excluded_courses: major_summary
+            .duplicate_capstones
+            .iter()
+            .map(|&idx| ExcludedCourse {
+                course: matched_course(idx),
+                reason: ExclusionReason::DuplicateCapstone,
+            })
+            .chain(free_electives.excluded)
+            .collect(),
+        unrecognized_courses: unrecognized_courses(courses, &all_used_courses, gen_ed, major)
+            .iter()
+            .map(to_course)
+            .collect(),
+        weak_grade_courses: weak_grade_courses(courses, WEAK_GRADE_THRESHOLD)
+            .iter()
+            .map(to_course)
+            .collect(),
+        withdrawn_courses: withdrawn_courses(courses).iter().map(to_course).collect(),
+        gpa: calculate_gpa(courses),
+        gen_ed_strands: gen_ed_summary.strands,
+        retake_courses: retake_courses(courses, gen_ed, major),
+        corequisite_warnings: corequisite_warnings(courses, major, options),
+        cluster_progress: cluster_progress(courses, major, options),
+        coverage: course_coverage,
+        claimed_indices: all_used_courses,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::gen_ed::get_gen_ed_curriculum;
+    use crate::data::major::get_major_curriculum;
+    use crate::logic::advisory::ENGLISH_EXIT_CATEGORY;
+    use crate::logic::parser::{parse_transcript, ParseOptions};
+    use crate::models::SpecialTopicLimit;
+
+    fn parse(text: &str) -> Vec<ParsedCourse> {
+        parse_transcript(text, &ParseOptions::default()).expect("transcript should parse")
+    }
+
+    fn descriptions(missing: &[MissingCourse]) -> Vec<&str> {
+        missing.iter().map(|m| m.description.as_str()).collect()
+    }
+
+    #[test]
+    fn co_op_capstone_reduces_required_electives() {
+        let major = get_major_curriculum();
+        let option = |code: &str| major.capstone.options.iter().find(|o| o.code == code);
+
+        assert_eq!(required_elective_credits(&major, None), 12.0);
+        assert_eq!(required_elective_credits(&major, option("344-492")), 12.0);
+        assert_eq!(required_elective_credits(&major, option("344-495")), 9.0);
+    }
+
+    #[test]
+    fn co_op_and_project_report_different_elective_shortfalls() {
+        let major = get_major_curriculum();
+        let electives = "344-471 COMPUTER VISION 01 3 A\n";
+        let audit = |capstone: &str| {
+            let courses = parse(&format!("{electives}{capstone}"));
+            audit_major(&courses, &major, &HashSet::new(), &AuditOptions::default())
+        };
+
+        let project = audit("344-492 PROJECTS 01 3 A");
+        let co_op = audit("344-495 COOPERATIVE EDUCATION 01 6 S");
+
+        assert_eq!(project.electives.completed, co_op.electives.completed);
+        assert!(
+            descriptions(&project.electives.missing).contains(&"Electives: missing 9.0 credits")
+        );
+        assert!(descriptions(&co_op.electives.missing).contains(&"Electives: missing 6.0 credits"));
+    }
+
+    #[test]
+    fn gen_ed_sub_category_credits_at_most_max_courses() {
+        let gen_ed = get_gen_ed_curriculum();
+        let courses = parse(
+            "890-811 ENGLISH GRAMMAR 01 2 A\n\
+             890-821 ENGLISH PRONUNCIATION 01 2 A\n\
+             890-831 STRATEGIC READING 01 2 B\n\
+             890-841 ENGLISH PRESENTATIONS 01 2 B",
+        );
+
+        let summary = audit_gen_ed(&courses, &gen_ed, &HashSet::new(), &AuditOptions::default());
+
+        // English Language caps at 3 courses; the fourth is left for free electives
+        assert_eq!(summary.credits.completed, 6.0);
+        assert_eq!(summary.credits.used, HashSet::from([0, 1, 2]));
+    }
+
+    #[test]
+    fn gen_ed_sub_category_below_min_courses_is_reported() {
+        let mut gen_ed = get_gen_ed_curriculum();
+        gen_ed.electives.sub_categories[0].min_courses = 2;
+        let courses = parse("890-811 ENGLISH GRAMMAR 01 2 A");
+
+        let summary = audit_gen_ed(&courses, &gen_ed, &HashSet::new(), &AuditOptions::default());
+
+        assert!(descriptions(&summary.credits.missing)
+            .contains(&"GenEd Elective > English Language: take at least 2 courses (1 taken)"));
+    }
+
+    #[test]
+    fn summaries_match_the_audit_result_categories() {
+        let gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+        let courses = parse(
+            "322-101 CALCULUS I 01 3 B+\n\
+             890-102 EVERYDAY ENGLISH 01 2 B\n\
+             890-103 ENGLISH ON THE GO 01 2 A\n\
+             344-492 PROJECTS 01 3 A\n\
+             344-471 COMPUTER VISION 01 3 A\n\
+             999-101 PHOTOGRAPHY 01 3 A",
+        );
+        let options = AuditOptions::default();
+
+        let gen_ed_summary = audit_gen_ed(&courses, &gen_ed, &HashSet::new(), &options);
+        let major_summary = audit_major(&courses, &major, &gen_ed_summary.credits.used, &options);
+        let result = audit_transcript(&courses, &gen_ed, &major, &options);
+
+        assert_eq!(gen_ed_summary.credits.completed, 4.0);
+        assert_eq!(
+            gen_ed_summary.credits.required,
+            gen_ed.total_required_credits
+        );
+        assert_eq!(major_summary.courses.completed, 6.0);
+        assert_eq!(major_summary.electives.completed, 3.0);
+        assert_eq!(major_summary.completed(), 9.0);
+        assert!(major_summary
+            .used()
+            .is_disjoint(&gen_ed_summary.credits.used));
+        assert_eq!(
+            result.categories[0].collected_credits,
+            gen_ed_summary.credits.completed
+        );
+        assert_eq!(
+            result.categories[1].collected_credits,
+            major_summary.completed()
+        );
+        assert_eq!(result.total_credits, 4.0 + 9.0 + 3.0);
+    }
+
+    #[test]
+    fn zero_credit_and_remedial_courses_are_not_free_electives() {
+        let courses = parse(
+            "890-101 ESSENTIAL ENGLISH 01 3 S\n\
+             999-100 ORIENTATION 01 0 S\n\
+             999-101 PHOTOGRAPHY 01 3 A",
+        );
+
+        let summary = calculate_free_electives(
+            &courses,
+            &HashSet::new(),
+            &FreeElectivePolicy::default(),
+            false,
+            PassingPolicy::default(),
+        );
+
+        assert_eq!(summary.credits, 3.0);
+        assert_eq!(summary.indices, vec![2]);
+        let reasons: Vec<_> = summary
+            .excluded
+            .iter()
+            .map(|excluded| (excluded.course.code.as_str(), excluded.reason))
+            .collect();
+        assert_eq!(
+            reasons,
+            [
+                ("890-101", ExclusionReason::Remedial),
+                ("999-100", ExclusionReason::ZeroCredit),
+            ]
+        );
+    }
+
+    #[test]
+    fn only_unknown_codes_are_reported_as_unrecognized() {
+        let courses = parse(
+            "890-811 ENGLISH GRAMMAR 01 2 A\n\
+             890-821 ENGLISH PRONUNCIATION 01 2 A\n\
+             890-831 STRATEGIC READING 01 2 B\n\
+             890-841 ENGLISH PRESENTATIONS 01 2 B\n\
+             999-999 MADE UP 01 3 A",
+        );
+
+        let result = audit_transcript(
+            &courses,
+            &get_gen_ed_curriculum(),
+            &get_major_curriculum(),
+            &AuditOptions::default(),
+        );
+
+        let free_electives = &result.categories.last().unwrap().courses;
+        assert!(free_electives.iter().any(|c| c.code == "890-841"));
+        let unrecognized: Vec<_> = result
+            .unrecognized_courses
+            .iter()
+            .map(|c| c.code.as_str())
+            .collect();
+        assert_eq!(unrecognized, ["999-999"]);
+    }
+
+    #[test]
+    fn course_eligible_for_gen_ed_and_major_counts_once() {
+        let mut gen_ed = get_gen_ed_curriculum();
+        gen_ed.electives.sub_categories[0]
+            .courses
+            .push(GenEdCourse {
+                code: "344-471".to_string(),
+                name: "Computer Vision and Applications".to_string(),
+                credits: 3.0,
+            });
+        let courses = parse("344-471 COMPUTER VISION 01 3 A");
+
+        let result = audit_transcript(
+            &courses,
+            &gen_ed,
+            &get_major_curriculum(),
+            &AuditOptions::default(),
+        );
+
+        let placements: Vec<_> = result
+            .categories
+            .iter()
+            .filter(|c| c.courses.iter().any(|course| course.code == "344-471"))
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(placements, ["General Education"]);
+        assert_eq!(result.total_credits, 3.0);
+    }
+
+    #[test]
+    fn g_graded_required_course_satisfies_the_requirement() {
+        let courses = parse("322-101 CALCULUS I 01 3 G");
+
+        let summary = audit_major(
+            &courses,
+            &get_major_curriculum(),
+            &HashSet::new(),
+            &AuditOptions::default(),
+        );
+
+        assert_eq!(summary.courses.completed, 3.0);
+        assert!(!descriptions(&summary.courses.missing)
+            .iter()
+            .any(|d| d.contains("322-101")));
+    }
+
+    #[test]
+    fn partial_major_reports_one_overall_shortfall() {
+        let courses = parse(
+            "322-101 CALCULUS I 01 3 B+\n\
+             344-492 PROJECTS 01 3 A\n\
+             344-471 COMPUTER VISION 01 3 A",
+        );
+
+        let summary = audit_major(
+            &courses,
+            &get_major_curriculum(),
+            &HashSet::new(),
+            &AuditOptions::default(),
+        );
+
+        let overall: Vec<_> = summary
+            .missing()
+            .into_iter()
+            .filter(|m| m.description.starts_with("Overall Major"))
+            .collect();
+        assert_eq!(overall.len(), 1);
+        assert_eq!(overall[0].category, "Major Courses");
+        assert_eq!(
+            overall[0].description,
+            "Overall Major: missing 87.0 credits"
+        );
+    }
+
+    #[test]
+    fn recomputing_free_electives_leaves_requirements_alone() {
+        let gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+        let options = AuditOptions::default();
+        let mut courses = parse(
+            "322-101 CALCULUS I 01 3 B+\n\
+             890-102 EVERYDAY ENGLISH 01 2 B\n\
+             890-103 ENGLISH ON THE GO 01 2 A\n\
+             344-492 PROJECTS 01 3 A",
+        );
+        let before = audit_transcript(&courses, &gen_ed, &major, &options);
+        // A result restored from a save file carries no claimed indices
+        let mut result = before.clone();
+        result.claimed_indices.clear();
+
+        courses.extend(parse("999-101 PHOTOGRAPHY 01 3 A"));
+        recompute_free_electives(&mut result, &courses, &gen_ed, &major, &options);
+
+        assert_eq!(result.categories.len(), before.categories.len());
+        for (after, before) in result.categories.iter().zip(&before.categories) {
+            if after.name == "Free Electives" {
+                assert_eq!(after.collected_credits, before.collected_credits + 3.0);
+            } else {
+                assert_eq!(after.collected_credits, before.collected_credits);
+                assert_eq!(after.courses.len(), before.courses.len());
+            }
+        }
+        assert_eq!(result.total_credits, before.total_credits + 3.0);
+        assert_eq!(result.claimed_indices, before.claimed_indices);
+    }
+
+    #[test]
+    fn free_electives_with_curriculum_codes_keep_their_curriculum_name() {
+        let gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+        let options = AuditOptions::default();
+        let courses = parse(
+            "890-811 ENGLISH GRAMMAR 01 2 A\n\
+             890-821 ENGLISH PRONUNCIATION 01 2 A\n\
+             890-831 STRATEGIC READING 01 2 B\n\
+             890-841 ENGLISH PRESENTATIONS 01 2 B",
+        );
+        let expected = Some("English for Presentations and Visual Aids Design");
+
+        let mut result = audit_transcript(&courses, &gen_ed, &major, &options);
+        let free_elective_name = |result: &AuditResult| {
+            result.categories.last().unwrap().courses[0]
+                .curriculum_name
+                .clone()
+        };
+        assert_eq!(free_elective_name(&result).as_deref(), expected);
+
+        recompute_free_electives(&mut result, &courses, &gen_ed, &major, &options);
+        assert_eq!(free_elective_name(&result).as_deref(), expected);
+    }
+
+    #[test]
+    fn oversized_course_counts_only_up_to_its_sub_group() {
+        let options = AuditOptions {
+            credit_source: CreditSource::TranscriptOnly,
+            ..AuditOptions::default()
+        };
+        let courses = parse("315-201 LIFE IN THE FUTURE 01 3 A");
+
+        let summary = audit_gen_ed(
+            &courses,
+            &get_gen_ed_curriculum(),
+            &HashSet::new(),
+            &options,
+        );
+
+        let strand = summary
+            .strands
+            .iter()
+            .find(|strand| {
+                strand
+                    .sub_groups
+                    .iter()
+                    .any(|g| g.name == "Living with Awareness")
+            })
+            .unwrap();
+        assert_eq!(strand.completed, 2.0);
+        assert_eq!(strand.sub_groups[0].completed, 2.0);
+        assert_eq!(summary.credits.completed, 2.0);
+    }
+
+    #[test]
+    fn strict_policy_ignores_cluster_courses_claimed_elsewhere() {
+        let major = get_major_curriculum();
+        let cluster = &major.electives.domains[0].clusters[0];
+        let transcript: Vec<String> = cluster
+            .courses
+            .iter()
+            .map(|course| format!("{} COURSE 01 3 A", course.code))
+            .collect();
+        let courses = parse(&transcript.join("\n"));
+        // The first cluster course was already claimed by another requirement
+        let claimed = HashSet::from([0]);
+        let clusters_line = |elective_policy| {
+            let options = AuditOptions {
+                elective_policy,
+                ..AuditOptions::default()
+            };
+            audit_major(&courses, &major, &claimed, &options)
+                .missing()
+                .into_iter()
+                .find(|m| m.description.starts_with("Required:"))
+                .map(|m| m.description)
+                .unwrap()
+        };
+
+        assert!(clusters_line(ElectivePolicy::Lenient).contains("Completed: 1."));
+        assert!(clusters_line(ElectivePolicy::Strict).contains("Completed: 0."));
+    }
+
+    /// `CourseIndex::find_unused` without the index: scans every row for the
+    /// best passing, unclaimed attempt (highest grade point, then earliest).
+    fn find_unused_by_scan(
+        courses: &[ParsedCourse],
+        code: &str,
+        used: &HashSet<usize>,
+    ) -> Option<usize> {
+        let rank = |parsed: &ParsedCourse| grade_point(&parsed.grade).unwrap_or(0.0);
+        courses
+            .iter()
+            .enumerate()
+            .filter(|(idx, parsed)| {
+                !used.contains(idx)
+                    && canonical_code(&parsed.code) == canonical_code(code)
+                    && counts_as_passed(&parsed.grade, false)
+            })
+            .min_by(|(a_idx, a), (b_idx, b)| rank(b).total_cmp(&rank(a)).then(a_idx.cmp(b_idx)))
+            .map(|(idx, _)| idx)
+    }
+
+    #[test]
+    fn course_index_matches_a_linear_scan() {
+        let courses = parse(
+            "322-101 CALCULUS I 01 3 F\n\
+             322-101 CALCULUS I 02 3 C\n\
+             344-492 PROJECTS 01 3 A\n\
+             322-101 CALCULUS I 03 3 B+\n\
+             890-102 EVERYDAY ENGLISH 01 2 S\n\
+             344-496 SPECIAL TOPIC 01 3 B\n\
+             344-496 SPECIAL TOPIC 02 3 B\n\
+             344-496 SPECIAL TOPIC 03 3 W",
+        );
+        let index = CourseIndex::new(&courses, &AuditOptions::default());
+        let codes = ["322-101", "344-492", "890-102", "344-496", "999-999"];
+
+        // Every subset of claimed rows, so each candidate is at some point taken
+        for mask in 0..(1u32 << courses.len()) {
+            let used: HashSet<usize> = (0..courses.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .collect();
+            for code in codes {
+                assert_eq!(
+                    index.find_unused(code, &used).map(|(idx, _)| idx),
+                    find_unused_by_scan(&courses, code, &used),
+                    "{} with {:?} claimed",
+                    code,
+                    used
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn auditing_a_large_transcript_stays_fast() {
+        let gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+        let codes: Vec<String> = curriculum_codes(&gen_ed, &major).into_iter().collect();
+        let transcript: Vec<String> = (0..2_000)
+            .map(|i| format!("{} COURSE {:02} 3 B", codes[i % codes.len()], i % 90 + 1))
+            .collect();
+        let courses = parse(&transcript.join("\n"));
+        assert_eq!(courses.len(), 2_000);
+
+        let started = std::time::Instant::now();
+        let result = audit_transcript(&courses, &gen_ed, &major, &AuditOptions::default());
+
+        // A generous bound that only a return to per-course transcript scans would break
+        assert!(
+            started.elapsed() < std::time::Duration::from_secs(2),
+            "audit took {:?}",
+            started.elapsed()
+        );
+        assert!(result.total_credits > 0.0);
+    }
+
+    #[test]
+    fn second_capstone_is_excluded_not_counted() {
+        let gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+        let options = AuditOptions::default();
+        let courses = parse("344-492 PROJECTS 01 3 A\n344-495 COOPERATIVE EDUCATION 01 6 S");
+
+        let mut result = audit_transcript(&courses, &gen_ed, &major, &options);
+
+        assert_eq!(result.categories[1].collected_credits, 3.0);
+        assert_eq!(result.categories.last().unwrap().collected_credits, 0.0);
+        let excluded: Vec<_> = result
+            .excluded_courses
+            .iter()
+            .map(|excluded| (excluded.course.code.as_str(), excluded.reason))
+            .collect();
+        assert_eq!(excluded, [("344-495", ExclusionReason::DuplicateCapstone)]);
+
+        // The note survives a free-elective recompute and the co-op stays out
+        recompute_free_electives(&mut result, &courses, &gen_ed, &major, &options);
+        assert_eq!(result.excluded_courses.len(), 1);
+        assert_eq!(result.categories.last().unwrap().collected_credits, 0.0);
+    }
+
+    #[test]
+    fn partially_completed_cluster_lists_its_remaining_codes() {
+        let courses = parse(
+            "344-331 DATA SCIENCE 01 3 A\n\
+             344-332 DATA MINING 01 3 A\n\
+             344-212 WEB PROGRAMMING 01 3 A",
+        );
+
+        let result = audit_transcript(
+            &courses,
+            &get_gen_ed_curriculum(),
+            &get_major_curriculum(),
+            &AuditOptions::default(),
+        );
+
+        assert!(descriptions(&result.missing_subjects)
+            .contains(&"Cluster 1.1 Big Data: still need 344-431"));
+    }
+
+    fn audit(text: &str) -> AuditResult {
+        audit_transcript(
+            &parse(text),
+            &get_gen_ed_curriculum(),
+            &get_major_curriculum(),
+            &AuditOptions::default(),
+        )
+    }
+
+    #[test]
+    fn passed_zero_credit_prerequisite_satisfies_its_requirement() {
+        let result = audit("890-101 ESSENTIAL ENGLISH 01 0 S\n890-102 EVERYDAY ENGLISH 01 2 A");
+
+        assert!(!descriptions(&result.missing_subjects)
+            .iter()
+            .any(|d| d.contains("890-101")));
+        assert!(result.categories[0]
+            .courses
+            .iter()
+            .any(|c| c.code == "890-101"));
+        assert!(result.excluded_courses.is_empty());
+    }
+
+    #[test]
+    fn absent_zero_credit_prerequisite_is_reported() {
+        let result = audit("890-102 EVERYDAY ENGLISH 01 2 A");
+
+        assert!(descriptions(&result.missing_subjects)
+            .iter()
+            .any(|d| d.starts_with("Prerequisite 890-101")));
+    }
+
+    #[test]
+    fn special_topics_beyond_the_limit_become_free_electives() {
+        let result = audit(
+            "344-496 SPECIAL TOPIC 01 3 A\n\
+             344-496 SPECIAL TOPIC 02 3 B\n\
+             344-496 SPECIAL TOPIC 03 3 A",
+        );
+
+        assert_eq!(SpecialTopicLimit::default().max_courses, 2);
+        assert_eq!(result.categories[1].collected_credits, 6.0);
+        let free_electives = result.categories.last().unwrap();
+        assert_eq!(free_electives.collected_credits, 3.0);
+        assert_eq!(free_electives.courses[0].code, "344-496");
+    }
+
+    #[test]
+    fn short_gen_ed_electives_give_one_shortfall_line() {
+        let result = audit("895-811 PSYCHOLOGY 01 2 A");
+
+        let elective_lines: Vec<_> = result
+            .missing_subjects
+            .iter()
+            .filter(|m| m.category == "General Education" && m.description.contains("Elective"))
+            .map(|m| m.description.as_str())
+            .collect();
+        assert_eq!(
+            elective_lines,
+            ["GenEd Electives (GE8): missing 4.0 credits"]
+        );
+    }
+
+    #[test]
+    fn lookup_finds_core_cluster_and_gen_ed_courses() {
+        let gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+
+        let core = lookup_course("344-341", &gen_ed, &major).unwrap();
+        assert_eq!(core.location, CurriculumLocation::Core);
+        assert_eq!(core.credits, 3.0);
+        assert_eq!(
+            lookup_course("344-341a", &gen_ed, &major).unwrap().name,
+            "Software Engineering"
+        );
+
+        let cluster = lookup_course("344-431", &gen_ed, &major).unwrap();
+        assert!(
+            matches!(cluster.location, CurriculumLocation::Cluster { ref id, .. } if id == "1.1")
+        );
+
+        let gen_ed_elective = lookup_course("895-811", &gen_ed, &major).unwrap();
+        assert!(matches!(
+            gen_ed_elective.location,
+            CurriculumLocation::GenEdElective(_)
+        ));
+
+        assert!(lookup_course("999-999", &gen_ed, &major).is_none());
+    }
+
+    #[test]
+    fn suggestions_match_a_partial_code_once_per_course() {
+        let catalog = curriculum_courses(&get_gen_ed_curriculum(), &get_major_curriculum());
+
+        let suggestions = suggest_courses("344-33", &catalog, 10);
+        let codes: Vec<_> = suggestions.iter().map(|c| c.code.as_str()).collect();
+
+        assert!(!codes.is_empty());
+        assert!(codes.iter().all(|code| code.starts_with("344-33")));
+        // 344-335 is listed in two clusters but suggested once
+        assert_eq!(codes.iter().filter(|code| **code == "344-335").count(), 1);
+        assert!(suggest_courses("", &catalog, 10).is_empty());
+        assert_eq!(suggest_courses("machine", &catalog, 10)[0].code, "344-362");
+    }
+
+    #[test]
+    fn each_sub_group_reports_its_own_completion() {
+        let result = audit("315-104 DIGITAL TECHNOLOGY LITERACY 01 2 A");
+
+        let strand = result
+            .gen_ed_strands
+            .iter()
+            .find(|strand| {
+                strand
+                    .sub_groups
+                    .iter()
+                    .any(|g| g.name == "Digital Literacy")
+            })
+            .unwrap();
+        let sub_groups: Vec<_> = strand
+            .sub_groups
+            .iter()
+            .map(|g| (g.name.as_str(), g.completed, g.required))
+            .collect();
+        assert_eq!(
+            sub_groups,
+            [
+                ("Living with Awareness", 0.0, 2.0),
+                ("Digital Literacy", 2.0, 2.0)
+            ]
+        );
+        assert!(descriptions(&result.missing_subjects)
+            .iter()
+            .any(|d| d.contains("Living with Awareness")));
+    }
+
+    #[test]
+    fn in_progress_course_satisfies_a_strand_only_when_assumed_passed() {
+        let courses = parse("315-104 DIGITAL TECHNOLOGY LITERACY 01 2 IP");
+        let digital_literacy = |assume_in_progress_pass| {
+            let options = AuditOptions {
+                assume_in_progress_pass,
+                ..AuditOptions::default()
+            };
+            let summary = audit_gen_ed(
+                &courses,
+                &get_gen_ed_curriculum(),
+                &HashSet::new(),
+                &options,
+            );
+            summary
+                .strands
+                .iter()
+                .flat_map(|strand| &strand.sub_groups)
+                .find(|g| g.name == "Digital Literacy")
+                .map(|g| g.completed)
+                .unwrap()
+        };
+
+        assert_eq!(digital_literacy(false), 0.0);
+        assert_eq!(digital_literacy(true), 2.0);
+    }
+
+    #[test]
+    fn lecture_without_its_lab_is_warned() {
+        let major = get_major_curriculum();
+        let options = AuditOptions::default();
 
-                let credits = parsed.parsed_credit;
-                free_elective_credits += credits;
-                free_elective_list.push(format!(
-                    "{} (Grade: {}, {} cr)",
-                    parsed.code, parsed.grade, credits
-                ));
+        let lecture_only = parse("324-101 GENERAL CHEMISTRY I 01 3 B");
+        let warnings = corequisite_warnings(&lecture_only, &major, &options);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].missing_code, "325-101");
+
+        let both = parse(
+            "324-101 GENERAL CHEMISTRY I 01 3 B\n\
+             325-101 GENERAL CHEMISTRY LAB I 01 1 A",
+        );
+        assert!(corequisite_warnings(&both, &major, &options).is_empty());
+    }
+
+    #[test]
+    fn substitution_satisfies_an_otherwise_missing_requirement() {
+        let gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+        let courses = parse("344-999 PETITIONED COURSE 01 3 A");
+        let misses_calculus = |result: &AuditResult| {
+            descriptions(&result.missing_subjects)
+                .iter()
+                .any(|d| d.starts_with("322-101"))
+        };
+
+        let without = audit_transcript(&courses, &gen_ed, &major, &AuditOptions::default());
+        assert!(misses_calculus(&without));
+
+        let mut options = AuditOptions::default();
+        options
+            .substitutions
+            .insert("344-999".to_string(), "322-101".to_string());
+        let with = audit_transcript(&courses, &gen_ed, &major, &options);
+        assert!(!misses_calculus(&with));
+        assert_eq!(
+            with.categories[1].courses[0].substitutes_for.as_deref(),
+            Some("322-101")
+        );
+        assert!(with.unrecognized_courses.is_empty());
+    }
+
+    #[test]
+    fn cross_listed_gen_ed_elective_counts_once() {
+        let mut gen_ed = get_gen_ed_curriculum();
+        let shared = gen_ed.electives.sub_categories[0].courses[0].clone();
+        gen_ed.electives.sub_categories[1]
+            .courses
+            .insert(0, shared.clone());
+        // A retake would otherwise give the second sub-category its own enrollment
+        let courses = parse(&format!(
+            "{code} ENGLISH 01 2 A\n{code} ENGLISH 02 2 B",
+            code = shared.code
+        ));
+
+        let summary = audit_gen_ed(&courses, &gen_ed, &HashSet::new(), &AuditOptions::default());
+
+        assert_eq!(summary.credits.used.len(), 1);
+        assert_eq!(summary.credits.completed, shared.credits);
+    }
+
+    #[test]
+    fn special_topic_beyond_a_met_elective_requirement_is_a_free_elective() {
+        let major = get_major_curriculum();
+        let clusters: Vec<_> = major
+            .electives
+            .domains
+            .iter()
+            .flat_map(|domain| &domain.clusters)
+            .collect();
+        let mut transcript: Vec<String> = clusters[0]
+            .courses
+            .iter()
+            .chain(&clusters[1].courses)
+            .take(4)
+            .map(|course| format!("{} CLUSTER COURSE 01 3 A", course.code))
+            .collect();
+        transcript.push("344-496 SPECIAL TOPIC 01 3 A".to_string());
+
+        let result = audit(&transcript.join("\n"));
+
+        let major_courses = &result.categories[1];
+        assert_eq!(major_courses.collected_credits, 12.0);
+        assert!(!major_courses.courses.iter().any(|c| c.code == "344-496"));
+        let free_electives = result.categories.last().unwrap();
+        assert_eq!(free_electives.collected_credits, 3.0);
+        assert_eq!(free_electives.courses[0].code, "344-496");
+    }
+
+    #[test]
+    fn english_exit_check_runs_only_with_a_marker() {
+        let courses = parse("890-102 EVERYDAY ENGLISH 01 2 A");
+        let exit_lines = |english_exit_marker: Option<&str>| {
+            let options = AuditOptions {
+                english_exit_marker: english_exit_marker.map(str::to_string),
+                ..AuditOptions::default()
+            };
+            audit_transcript(
+                &courses,
+                &get_gen_ed_curriculum(),
+                &get_major_curriculum(),
+                &options,
+            )
+            .missing_subjects
+            .iter()
+            .filter(|m| m.category == ENGLISH_EXIT_CATEGORY)
+            .count()
+        };
+
+        assert_eq!(exit_lines(None), 0);
+        assert_eq!(exit_lines(Some("890-001")), 1);
+    }
+
+    #[test]
+    fn cluster_progress_counts_courses_in_each_cluster() {
+        let major = get_major_curriculum();
+        let clusters: Vec<_> = major
+            .electives
+            .domains
+            .iter()
+            .flat_map(|domain| &domain.clusters)
+            .collect();
+        let (first, second) = (clusters[0], clusters[1]);
+        let courses = parse(&format!(
+            "{} COURSE 01 3 A\n{} COURSE 01 3 B\n{} COURSE 01 3 C",
+            first.courses[0].code, first.courses[1].code, second.courses[0].code
+        ));
+
+        let progress = cluster_progress(&courses, &major, &AuditOptions::default());
+
+        assert_eq!(progress.len(), clusters.len());
+        assert_eq!(progress[0].completed_courses, 2);
+        assert_eq!(progress[0].total_courses as usize, first.courses.len());
+        assert_eq!(progress[1].completed_courses, 1);
+    }
+
+    #[test]
+    fn d_graded_free_elective_depends_on_the_minimum_grade() {
+        let courses = parse("999-101 PHOTOGRAPHY 01 3 D");
+        let free_credits = |min_grade_point| {
+            let policy = FreeElectivePolicy {
+                min_grade_point: Some(min_grade_point),
+                ..FreeElectivePolicy::default()
+            };
+            calculate_free_electives(
+                &courses,
+                &HashSet::new(),
+                &policy,
+                false,
+                PassingPolicy::default(),
+            )
+        };
+
+        assert_eq!(free_credits(1.0).credits, 3.0);
+        let strict = free_credits(2.0);
+        assert_eq!(strict.credits, 0.0);
+        assert_eq!(
+            strict.excluded[0].reason,
+            ExclusionReason::BelowMinimumGrade
+        );
+    }
+
+    #[test]
+    fn only_one_english_pair_is_claimed_and_the_extra_course_is_free() {
+        let result = audit(
+            "890-101 ESSENTIAL ENGLISH 01 0 S\n\
+             890-102 EVERYDAY ENGLISH 01 2 B\n\
+             890-103 ENGLISH ON THE GO 01 2 A\n\
+             890-104 ENGLISH FOR WORK 01 2 A",
+        );
+        let codes = |name: &str| {
+            result
+                .categories
+                .iter()
+                .find(|c| c.name == name)
+                .unwrap()
+                .courses
+                .iter()
+                .map(|c| c.code.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            codes("General Education"),
+            ["890-101", "890-102", "890-103"]
+        );
+        assert_eq!(codes("Free Electives"), ["890-104"]);
+    }
+
+    #[test]
+    fn failed_required_course_is_flagged_for_retake() {
+        let result = audit(
+            "322-101 CALCULUS I 01 3 F\n\
+             322-102 CALCULUS II 01 3 F\n\
+             322-102 CALCULUS II 01 3 C\n\
+             999-101 POTTERY 01 3 F",
+        );
+
+        let flags: Vec<_> = result
+            .retake_courses
+            .iter()
+            .map(|r| (r.course.code.as_str(), r.required))
+            .collect();
+        assert_eq!(flags, [("322-101", true), ("999-101", false)]);
+    }
+
+    #[test]
+    fn core_claims_a_code_shared_with_gen_ed_electives() {
+        let mut gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+        let core = major.core_courses.courses[0].clone();
+        gen_ed.electives.sub_categories[0].courses.insert(
+            0,
+            crate::models::GenEdCourse {
+                code: core.code.clone(),
+                name: core.name.clone(),
+                credits: core.credits,
+            },
+        );
+
+        assert!(reserved_codes(&gen_ed, &major, MatchPriority::GenEdElective).contains(&core.code));
+
+        let courses = parse(&format!("{} SHARED 01 {} A", core.code, core.credits));
+        let result = audit_transcript(&courses, &gen_ed, &major, &AuditOptions::default());
+        let claimed = result
+            .categories
+            .iter()
+            .flat_map(|c| &c.courses)
+            .find(|c| c.code == core.code)
+            .unwrap();
+        assert_eq!(
+            claimed.matched.as_ref().unwrap().requirement,
+            "Core Courses"
+        );
+    }
+
+    #[test]
+    fn coverage_counts_matched_free_and_uncounted_courses() {
+        let courses = parse(
+            "322-101 CALCULUS I 01 3 B+\n\
+             999-101 POTTERY 01 3 A\n\
+             890-101 ESSENTIAL ENGLISH 01 0 S\n\
+             322-102 CALCULUS II 01 3 F",
+        );
+
+        let counts = coverage(&courses, &[0].into_iter().collect(), &[1]);
+        assert_eq!(
+            counts,
+            Coverage {
+                total: 3,
+                matched: 1,
+                free: 1,
+                uncounted: 1,
             }
+        );
+        assert_eq!(counts.categorized(), 2);
+
+        let result = audit_transcript(
+            &courses,
+            &get_gen_ed_curriculum(),
+            &get_major_curriculum(),
+            &AuditOptions::default(),
+        );
+        assert_eq!(result.coverage.total, 3);
+        assert_eq!(result.coverage.uncounted, 0);
+    }
+
+    #[test]
+    fn pinned_clusters_are_targeted_by_the_missing_lines() {
+        let gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+        let clusters: Vec<_> = major
+            .electives
+            .domains
+            .iter()
+            .flat_map(|d| &d.clusters)
+            .collect();
+        let (first, second, started) = (clusters[0], clusters[3], clusters[5]);
+        let courses = parse(&format!("{} STARTED 01 3 A", started.courses[0].code));
+        let cluster_lines = |options: &AuditOptions| {
+            audit_transcript(&courses, &gen_ed, &major, options)
+                .missing_subjects
+                .into_iter()
+                .map(|m| m.description)
+                .filter(|d| d.starts_with("Cluster"))
+                .collect::<Vec<_>>()
+        };
+
+        let pinned = AuditOptions {
+            pinned_clusters: [first.id.clone(), second.id.clone()].into_iter().collect(),
+            ..AuditOptions::default()
+        };
+        let lines = cluster_lines(&pinned);
+        assert_eq!(lines.len(), 2, "{:?}", lines);
+        assert!(lines[0].starts_with(&format!("Cluster {} ", first.id)));
+        assert!(lines[1].starts_with(&format!("Cluster {} ", second.id)));
+
+        let lines = cluster_lines(&AuditOptions::default());
+        assert_eq!(lines.len(), 1, "{:?}", lines);
+        assert!(lines[0].starts_with(&format!("Cluster {} ", started.id)));
+    }
+
+    /// Minimal minor for the secondary-curriculum tests. Calculus I is also a
+    /// major core course.
+    fn minor() -> MinorCurriculum {
+        let course = |code: &str, name: &str| MajorCourse {
+            code: code.to_string(),
+            name: name.to_string(),
+            credits: 3.0,
+            corequisites: Vec::new(),
+        };
+        MinorCurriculum {
+            name: "Minor in Mathematics".to_string(),
+            total_required_credits: 15.0,
+            required_courses: vec![
+                course("322-101", "Calculus I"),
+                course("322-231", "Linear Algebra"),
+                course("322-241", "Discrete Mathematics"),
+            ],
+            electives: vec![
+                course("322-201", "Calculus III"),
+                course("322-251", "Differential Equations"),
+            ],
         }
     }
 
-    (free_elective_credits, free_elective_list)
+    fn audit_with_minor(text: &str) -> AuditResult {
+        let options = AuditOptions {
+            minor: Some(minor()),
+            ..AuditOptions::default()
+        };
+        audit_transcript(
+            &parse(text),
+            &get_gen_ed_curriculum(),
+            &get_major_curriculum(),
+            &options,
+        )
+    }
+
+    fn minor_lines(result: &AuditResult) -> Vec<&str> {
+        result
+            .missing_subjects
+            .iter()
+            .filter(|m| m.category == "Minor in Mathematics")
+            .map(|m| m.description.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn minor_course_consumed_by_the_major_is_not_credited_again() {
+        let result = audit_with_minor(
+            "322-101 CALCULUS I 01 3 B\n\
+             322-231 LINEAR ALGEBRA 01 3 A\n\
+             322-201 CALCULUS III 01 3 A",
+        );
+
+        let minor = result
+            .categories
+            .iter()
+            .find(|c| c.name == "Minor in Mathematics")
+            .unwrap();
+        assert_eq!(minor.collected_credits, 6.0);
+        assert_eq!(
+            minor_lines(&result),
+            [
+                "322-101 - Calculus I",
+                "322-241 - Discrete Mathematics",
+                "Electives: 9.0 more credits",
+            ]
+        );
+        assert_eq!(result.categories.last().unwrap().name, "Free Electives");
+    }
+
+    #[test]
+    fn second_enrollment_left_by_the_major_counts_toward_the_minor() {
+        let result = audit_with_minor(
+            "322-101 CALCULUS I 01 3 B\n\
+             322-231 LINEAR ALGEBRA 01 3 A\n\
+             322-101 CALCULUS I 01 3 A",
+        );
+
+        let minor = result
+            .categories
+            .iter()
+            .find(|c| c.name == "Minor in Mathematics")
+            .unwrap();
+        assert_eq!(minor.collected_credits, 6.0);
+        assert!(!minor_lines(&result).contains(&"322-101 - Calculus I"));
+        assert!(!descriptions(&result.missing_subjects)
+            .iter()
+            .any(|d| d.starts_with("Core") && d.contains("322-101")));
+    }
+
+    #[test]
+    fn no_minor_adds_no_category() {
+        assert_eq!(audit("322-101 CALCULUS I 01 3 B").categories.len(), 3);
+    }
+
+    #[test]
+    fn best_attempt_is_claimed_whatever_its_position() {
+        let result = audit("322-101 CALCULUS I 01 3 B\n322-101 CALCULUS I 01 3 A");
+
+        assert!(result.claimed_indices.contains(&1));
+        assert!(!result.claimed_indices.contains(&0));
+        let major = result
+            .categories
+            .iter()
+            .find(|c| c.name == "Major Courses")
+            .unwrap();
+        assert!(major
+            .courses
+            .iter()
+            .any(|c| c.code == "322-101" && c.grade == "A"));
+    }
+
+    #[test]
+    fn credit_source_picks_curriculum_or_transcript_credits() {
+        // Calculus I is 3 credits in the curriculum, 6 on this transcript
+        let courses = parse("322-101 CALCULUS I 01 6 A");
+
+        for (source, expected) in [
+            (CreditSource::CurriculumOnly, 3.0),
+            (CreditSource::TranscriptOnly, 6.0),
+            (CreditSource::MinOfBoth, 3.0),
+            (CreditSource::MaxOfBoth, 6.0),
+        ] {
+            let options = AuditOptions {
+                credit_source: source,
+                ..AuditOptions::default()
+            };
+            let result = audit_transcript(
+                &courses,
+                &get_gen_ed_curriculum(),
+                &get_major_curriculum(),
+                &options,
+            );
+            assert_eq!(
+                result.categories[1].collected_credits, expected,
+                "{:?}",
+                source
+            );
+            assert_eq!(source.credited(3.0, 6.0), expected);
+        }
+        assert_eq!(CreditSource::default(), CreditSource::MinOfBoth);
+    }
+
+    #[test]
+    fn withdrawn_courses_are_listed_but_never_counted() {
+        let courses = parse(
+            "322-101 CALCULUS I 01 3 W\n\
+             322-101 CALCULUS I 02 3 B\n\
+             344-211 SOMETHING 01 3 W",
+        );
+        assert_eq!(courses.len(), 3);
+
+        let result = audit_transcript(
+            &courses,
+            &get_gen_ed_curriculum(),
+            &get_major_curriculum(),
+            &AuditOptions::default(),
+        );
+
+        assert_eq!(result.withdrawn_courses.len(), 2);
+        assert_eq!(result.total_credits, 3.0);
+        assert_eq!(result.gpa, Some(3.0));
+        assert!(!result.claimed_indices.contains(&0));
+        assert!(!result.claimed_indices.contains(&2));
+    }
+
+    #[test]
+    fn missing_lines_explain_why_they_are_missing() {
+        let result = audit("344-331 X 01 3 A\n322-101 CALCULUS I 01 3 B");
+
+        let reason = |prefix: &str| {
+            result
+                .missing_subjects
+                .iter()
+                .find(|m| m.description.contains(prefix))
+                .and_then(|m| m.reason.clone())
+                .unwrap_or_default()
+        };
+        assert!(
+            reason("choose 1").starts_with("None of the "),
+            "{}",
+            reason("choose 1")
+        );
+        assert!(
+            reason("Cluster ").starts_with("You completed 1 of the "),
+            "{}",
+            reason("Cluster ")
+        );
+    }
+
+    #[test]
+    fn english_prerequisite_is_consumed_by_its_strand_without_credits() {
+        let result = audit(
+            "890-101 ESSENTIAL ENGLISH 01 0 S\n\
+             890-102 EVERYDAY ENGLISH 01 2 B\n\
+             890-103 ENGLISH ON THE GO 01 2 A",
+        );
+
+        assert!(result.claimed_indices.contains(&0));
+        assert!(result.categories[0]
+            .courses
+            .iter()
+            .any(|c| c.code == "890-101"));
+        assert!(result
+            .categories
+            .last()
+            .unwrap()
+            .courses
+            .iter()
+            .all(|c| c.code != "890-101"));
+        let english = result
+            .gen_ed_strands
+            .iter()
+            .find(|s| s.name.contains("English") || s.name.contains("Language"))
+            .unwrap();
+        assert_eq!(english.completed, 4.0);
+    }
+
+    #[test]
+    fn p_grade_credits_follow_the_passing_policy() {
+        let courses = parse("322-101 CALCULUS I 01 3 P");
+        assert_eq!(courses[0].grade, "P");
+        let audit_with = |p_counts_credits: bool| {
+            let options = AuditOptions {
+                passing_policy: PassingPolicy { p_counts_credits },
+                ..AuditOptions::default()
+            };
+            audit_transcript(
+                &courses,
+                &get_gen_ed_curriculum(),
+                &get_major_curriculum(),
+                &options,
+            )
+        };
+        let lists_calculus = |result: &AuditResult| {
+            descriptions(&result.missing_subjects)
+                .iter()
+                .any(|d| d.contains("322-101"))
+        };
+
+        let counted = audit_with(true);
+        assert!(!lists_calculus(&counted));
+        assert_eq!(counted.total_credits, 3.0);
+
+        let uncounted = audit_with(false);
+        assert!(lists_calculus(&uncounted));
+        assert_eq!(uncounted.total_credits, 0.0);
+    }
+
+    #[test]
+    fn complete_categories_still_report_a_short_degree_total() {
+        let options = AuditOptions {
+            degree_min_credits: Some(132.0),
+            ..AuditOptions::default()
+        };
+        let gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+        let mut result = audit_transcript(&[], &gen_ed, &major, &options);
+        assert_eq!(
+            result
+                .missing_subjects
+                .iter()
+                .filter(|m| m.category == TOTAL_CREDITS_CATEGORY)
+                .count(),
+            1
+        );
+
+        result.categories = vec![Category {
+            name: "General Education".to_string(),
+            required_credits: 30.0,
+            collected_credits: 30.0,
+            courses: Vec::new(),
+        }];
+        result.missing_subjects.clear();
+        result.total_credits = 30.0;
+        recompute_free_electives(&mut result, &[], &gen_ed, &major, &options);
+
+        assert_eq!(
+            descriptions(&result.missing_subjects),
+            ["Total credits: missing 102"]
+        );
+        assert!(!result.is_complete());
+    }
+
+    #[test]
+    fn duplicate_rows_prefer_the_one_matching_curriculum_credits() {
+        let courses = parse("344-201 MODULE COMPUTING 01 3 A\n344-201 MODULE COMPUTING 01 6 B");
+        assert_eq!(courses.len(), 2);
+        let credited = |prefer_curriculum_credit: bool| {
+            let options = AuditOptions {
+                prefer_curriculum_credit,
+                ..AuditOptions::default()
+            };
+            audit_transcript(
+                &courses,
+                &get_gen_ed_curriculum(),
+                &get_major_curriculum(),
+                &options,
+            )
+            .categories[1]
+                .courses
+                .iter()
+                .find(|c| c.code == "344-201")
+                .map(|c| c.credit)
+        };
+
+        assert_eq!(credited(false), Some(3.0));
+        assert_eq!(credited(true), Some(6.0));
+    }
+
+    #[test]
+    fn core_course_listed_in_a_cluster_never_counts_toward_it() {
+        let mut major = get_major_curriculum();
+        let core = major.core_courses.courses[0].clone();
+        let cluster = &mut major.electives.domains[0].clusters[0];
+        cluster.courses.push(core.clone());
+        let cluster_id = cluster.id.clone();
+        let other = cluster
+            .courses
+            .iter()
+            .find(|c| c.code != core.code)
+            .unwrap()
+            .code
+            .clone();
+
+        // Both enrollments of the core course are left out of the cluster
+        let courses = parse(&format!(
+            "{0} CORE 01 3 A\n{0} CORE 01 3 B\n{1} CLUSTER 01 3 A",
+            core.code, other
+        ));
+        let result = audit_transcript(
+            &courses,
+            &get_gen_ed_curriculum(),
+            &major,
+            &AuditOptions::default(),
+        );
+
+        let progress = result
+            .cluster_progress
+            .iter()
+            .find(|p| p.cluster_id == cluster_id)
+            .unwrap();
+        assert_eq!(progress.completed_courses, 1);
+        assert!(!result.categories[1]
+            .courses
+            .iter()
+            .any(|c| c.code == core.code
+                && c.matched
+                    .as_ref()
+                    .is_some_and(|m| m.requirement.contains(&cluster_id))));
+    }
+
+    #[test]
+    fn eligibility_lists_every_group_a_course_can_count_toward() {
+        let gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+
+        let shared = course_eligibility("344-335", &gen_ed, &major);
+        assert_eq!(shared.len(), 2, "{:?}", shared);
+        assert!(shared[0].starts_with("Cluster 3.2"), "{:?}", shared);
+        assert!(shared[1].starts_with("Cluster 3.4"), "{:?}", shared);
+
+        let core = &major.core_courses.courses[0].code;
+        assert_eq!(
+            course_eligibility(core, &gen_ed, &major),
+            [major.core_courses.name.as_str()]
+        );
+        assert!(course_eligibility("999-999", &gen_ed, &major).is_empty());
+    }
 }
@@ -0,0 +1,103 @@
+//! Missing-Requirements Checklist
+//!
+//! Renders the missing list as a markdown to-do checklist that students can
+//! paste into a note app while planning the next semester.
+
+use crate::models::{AuditResult, MissingCourse};
+
+/// File name suggested when downloading the checklist.
+pub const CHECKLIST_FILE_NAME: &str = "todo.md";
+
+/// Groups missing requirement descriptions by category, keeping the order in
+/// which categories first appear so output is deterministic.
+pub fn group_missing(missing: &[MissingCourse]) -> Vec<(String, Vec<String>)> {
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    for m in missing {
+        match groups
+            .iter_mut()
+            .find(|(category, _)| category == &m.category)
+        {
+            Some((_, items)) => items.push(m.description.clone()),
+            None => groups.push((m.category.clone(), vec![m.description.clone()])),
+        }
+    }
+    groups
+}
+
+/// Markdown checklist of the missing requirements: one `##` heading per
+/// category (in first-seen order) with a `- [ ]` item per requirement.
+pub fn missing_to_markdown(result: &AuditResult) -> String {
+    let mut markdown = String::from("# Missing Requirements\n");
+    for (category, items) in group_missing(&result.missing_subjects) {
+        markdown.push_str(&format!("\n## {}\n\n", category));
+        for item in items {
+            markdown.push_str(&format!("- [ ] {}\n", item));
+        }
+    }
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn missing(category: &str, description: &str) -> MissingCourse {
+        MissingCourse {
+            category: category.to_string(),
+            description: description.to_string(),
+            reason: None,
+        }
+    }
+
+    #[test]
+    fn missing_items_are_grouped_in_first_seen_category_order() {
+        let items = [
+            missing("Major Courses", "344-211 - Data Structures"),
+            missing(
+                "General Education",
+                "Overall General Education: missing 4.0 credits",
+            ),
+            missing("Major Courses", "Electives: missing 6.0 credits"),
+        ];
+
+        assert_eq!(
+            group_missing(&items),
+            [
+                (
+                    "Major Courses".to_string(),
+                    vec![
+                        "344-211 - Data Structures".to_string(),
+                        "Electives: missing 6.0 credits".to_string(),
+                    ]
+                ),
+                (
+                    "General Education".to_string(),
+                    vec!["Overall General Education: missing 4.0 credits".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn markdown_lists_each_category_as_a_checklist() {
+        let mut result: AuditResult = serde_json::from_str(
+            r#"{"schema_version": 1, "total_credits": 0, "categories": [], "missing_subjects": []}"#,
+        )
+        .unwrap();
+        result.missing_subjects = vec![
+            missing("Core Courses", "322-101 - Calculus I"),
+            missing("Capstone", "Choose 1: 344-492 OR 344-495"),
+            missing("Core Courses", "322-102 - Calculus II"),
+        ];
+
+        assert_eq!(
+            missing_to_markdown(&result),
+            "# Missing Requirements\n\
+             \n## Core Courses\n\n\
+             - [ ] 322-101 - Calculus I\n\
+             - [ ] 322-102 - Calculus II\n\
+             \n## Capstone\n\n\
+             - [ ] Choose 1: 344-492 OR 344-495\n"
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Debug Logging
+//!
+//! Diagnostic console output for maintainers (parsed row counts, detected
+//! layouts). It is off by default so students see a clean console; errors
+//! and curriculum warnings are logged regardless. Add `?debug` to the page
+//! URL to turn it on.
+
+use leptos::logging;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static DEBUG_LOGGING: AtomicBool = AtomicBool::new(false);
+
+/// Query parameter that enables debug logging at startup.
+pub const DEBUG_QUERY_PARAM: &str = "debug";
+
+/// Turns debug logging on or off for the rest of the session.
+pub fn set_debug_logging(enabled: bool) {
+    DEBUG_LOGGING.store(enabled, Ordering::Relaxed);
+}
+
+pub fn debug_logging_enabled() -> bool {
+    DEBUG_LOGGING.load(Ordering::Relaxed)
+}
+
+/// Whether a URL query string (e.g. `"?debug"` or `"?lang=en&debug=1"`)
+/// asks for debug logging.
+pub fn debug_requested(search: &str) -> bool {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .any(|param| param.split('=').next() == Some(DEBUG_QUERY_PARAM))
+}
+
+/// Logs the message built by `message` when debug logging is on. The message
+/// is only formatted when it will actually be printed.
+pub fn debug_log(message: impl FnOnce() -> String) {
+    if debug_logging_enabled() {
+        logging::log!("[DEBUG] {}", message());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn debug_query_param_is_matched_by_name() {
+        assert!(debug_requested("?debug"));
+        assert!(debug_requested("?lang=en&debug=1"));
+        assert!(!debug_requested("?debugger=1"));
+        assert!(!debug_requested(""));
+    }
+
+    #[test]
+    fn messages_are_only_built_while_logging_is_on() {
+        let built = Cell::new(0);
+        let message = || {
+            built.set(built.get() + 1);
+            String::new()
+        };
+
+        set_debug_logging(false);
+        debug_log(message);
+        assert_eq!(built.get(), 0);
+
+        set_debug_logging(true);
+        debug_log(message);
+        assert_eq!(built.get(), 1);
+
+        set_debug_logging(false);
+    }
+}
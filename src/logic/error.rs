@@ -0,0 +1,107 @@
+//! Error Types for the Logic Layer
+//!
+//! Every failure in the analysis flow (file read → PDF text → parse → audit)
+//! and in loading a saved result is surfaced as an `AuditError` so the UI can
+//! map each variant to its own message instead of matching on ad-hoc strings.
+
+use std::fmt;
+
+/// Failure raised while turning an uploaded transcript into an audit result.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuditError {
+    /// The uploaded file could not be read into memory.
+    FileRead,
+    /// The selected file is larger than the upload limit (in megabytes).
+    FileTooLarge(u32),
+    /// The selected file is not of the expected type (e.g. not a PDF).
+    UnsupportedFileType,
+    /// PDF.js returned no usable text (scanned image, empty document, etc.).
+    PdfTextEmpty,
+    /// "Parse pasted text" was clicked with nothing pasted.
+    PastedTextEmpty,
+    /// Text was extracted but no course rows matched the transcript pattern.
+    NoCoursesParsed,
+    /// Curriculum data could not be parsed or is malformed.
+    #[allow(dead_code)] // Curricula are compiled in today; reserved for runtime loading.
+    CurriculumParse(String),
+    /// A JavaScript interop call (e.g. PDF.js extraction) failed.
+    Interop(String),
+    /// A saved result was written by an incompatible schema version.
+    UnsupportedSchemaVersion(u32),
+    /// A saved result file is not valid audit-result JSON.
+    SavedResultInvalid(String),
+}
+
+impl fmt::Display for AuditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditError::FileRead => write!(f, "Failed to read the uploaded file"),
+            AuditError::FileTooLarge(max_mb) => write!(f, "The file is larger than {} MB", max_mb),
+            AuditError::UnsupportedFileType => write!(f, "The file type is not supported"),
+            AuditError::PdfTextEmpty => write!(f, "No text could be extracted from the PDF"),
+            AuditError::PastedTextEmpty => write!(f, "No transcript text was pasted"),
+            AuditError::NoCoursesParsed => write!(f, "No courses were found in the transcript"),
+            AuditError::CurriculumParse(detail) => {
+                write!(f, "Failed to parse curriculum data: {}", detail)
+            }
+            AuditError::Interop(detail) => write!(f, "JavaScript interop failed: {}", detail),
+            AuditError::UnsupportedSchemaVersion(version) => {
+                write!(f, "Unsupported saved result version: {}", version)
+            }
+            AuditError::SavedResultInvalid(detail) => {
+                write!(f, "Invalid saved result: {}", detail)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AuditError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_variant_has_a_display_message() {
+        let cases = [
+            (AuditError::FileRead, "Failed to read the uploaded file"),
+            (
+                AuditError::FileTooLarge(20),
+                "The file is larger than 20 MB",
+            ),
+            (
+                AuditError::UnsupportedFileType,
+                "The file type is not supported",
+            ),
+            (
+                AuditError::PdfTextEmpty,
+                "No text could be extracted from the PDF",
+            ),
+            (AuditError::PastedTextEmpty, "No transcript text was pasted"),
+            (
+                AuditError::NoCoursesParsed,
+                "No courses were found in the transcript",
+            ),
+            (
+                AuditError::CurriculumParse("missing strands".to_string()),
+                "Failed to parse curriculum data: missing strands",
+            ),
+            (
+                AuditError::Interop("pdf.js not loaded".to_string()),
+                "JavaScript interop failed: pdf.js not loaded",
+            ),
+            (
+                AuditError::UnsupportedSchemaVersion(9),
+                "Unsupported saved result version: 9",
+            ),
+            (
+                AuditError::SavedResultInvalid("expected value".to_string()),
+                "Invalid saved result: expected value",
+            ),
+        ];
+
+        for (error, expected) in cases {
+            assert_eq!(error.to_string(), expected);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Parsed-Course Export
+//!
+//! Serializes the raw parsed course list (before any auditing) so students
+//! and maintainers can check what the parser extracted from a transcript.
+
+use crate::models::ParsedCourse;
+
+/// File name suggested when downloading the parsed courses as JSON.
+pub const PARSED_COURSES_JSON_FILE_NAME: &str = "parsed-courses.json";
+/// File name suggested when downloading the parsed courses as CSV.
+pub const PARSED_COURSES_CSV_FILE_NAME: &str = "parsed-courses.csv";
+
+/// Parsed courses as a pretty-printed JSON array.
+pub fn parsed_courses_to_json(courses: &[ParsedCourse]) -> String {
+    serde_json::to_string_pretty(courses).unwrap_or_default()
+}
+
+/// Quotes a CSV field when it contains a delimiter, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Parsed courses as CSV with a header row, one course per line in
+/// transcript order. A missing section is left empty.
+pub fn parsed_courses_to_csv(courses: &[ParsedCourse]) -> String {
+    let mut csv = String::from("code,name,section,credit,grade\n");
+    for course in courses {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            csv_field(&course.code),
+            csv_field(&course.name),
+            csv_field(course.section.as_deref().unwrap_or_default()),
+            course.parsed_credit,
+            csv_field(&course.grade)
+        ));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::parser::{parse_transcript, ParseOptions};
+
+    fn courses() -> Vec<ParsedCourse> {
+        let mut courses = parse_transcript(
+            "322-101 CALCULUS I 01 3 B+\n344-201 MODULE COMPUTING 3 A",
+            &ParseOptions::default(),
+        )
+        .expect("transcript should parse");
+        courses[0].name = "Calc, \"one\"".to_string();
+        courses
+    }
+
+    #[test]
+    fn json_export_round_trips() {
+        let courses = courses();
+
+        let back: Vec<ParsedCourse> =
+            serde_json::from_str(&parsed_courses_to_json(&courses)).unwrap();
+        assert_eq!(back, courses);
+    }
+
+    #[test]
+    fn csv_export_quotes_fields_and_leaves_missing_sections_empty() {
+        let csv = parsed_courses_to_csv(&courses());
+        let lines: Vec<_> = csv.lines().collect();
+
+        assert_eq!(lines[0], "code,name,section,credit,grade");
+        assert_eq!(lines[1], "322-101,\"Calc, \"\"one\"\"\",01,3,B+");
+        assert_eq!(lines[2], "344-201,MODULE COMPUTING,,3,A");
+    }
+}
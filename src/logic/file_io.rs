@@ -0,0 +1,109 @@
+//! File Reading
+//!
+//! Reads a browser `File` into memory with a `FileReader`, wrapped in a
+//! promise so callers can simply `await` the bytes. Every failure (reader
+//! unavailable, read error, abort, unexpected result) rejects with a JS
+//! `Error` whose message comes from `ReadFailure`, which holds no JS values
+//! and can be checked off the browser.
+
+use js_sys::{ArrayBuffer, Promise, Uint8Array};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Event, File, FileReader};
+
+/// Why reading a file failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadFailure {
+    ReaderUnavailable, // `FileReader` could not be created
+    StartFailed,       // The read was refused before it began
+    ReadError,         // The reader fired `error` (e.g. file removed or unreadable)
+    Aborted,           // The reader fired `abort`
+    NotArrayBuffer,    // The reader finished without an `ArrayBuffer` result
+}
+
+impl ReadFailure {
+    /// Message carried by the rejected promise.
+    pub fn message(self) -> &'static str {
+        match self {
+            ReadFailure::ReaderUnavailable => "File reading is not available in this browser",
+            ReadFailure::StartFailed => "The file could not be opened for reading",
+            ReadFailure::ReadError => "Error reading file",
+            ReadFailure::Aborted => "Reading the file was aborted",
+            ReadFailure::NotArrayBuffer => "The file was read but returned no data",
+        }
+    }
+}
+
+/// JS `Error` for `failure`, the value a failed read rejects with.
+fn read_error(failure: ReadFailure) -> JsValue {
+    js_sys::Error::new(failure.message()).into()
+}
+
+/// Reads `file` into memory and resolves with its raw bytes. Rejects with a
+/// JS `Error` describing the `ReadFailure` when the read cannot complete.
+pub async fn read_file_bytes(file: File) -> Result<Uint8Array, JsValue> {
+    let reader = FileReader::new().map_err(|_| read_error(ReadFailure::ReaderUnavailable))?;
+
+    let promise = Promise::new(&mut |resolve, reject| {
+        let onload_reader = reader.clone();
+        let onload_reject = reject.clone();
+        let onload = Closure::once(move |_event: Event| match onload_reader.result() {
+            Ok(result) if result.is_instance_of::<ArrayBuffer>() => {
+                let _ = resolve.call1(&JsValue::NULL, &result);
+            }
+            _ => {
+                let _ =
+                    onload_reject.call1(&JsValue::NULL, &read_error(ReadFailure::NotArrayBuffer));
+            }
+        });
+        reader.set_onload(Some(onload.as_ref().unchecked_ref()));
+        // SAFETY: Closure::forget leaks memory but is the standard
+        // wasm-bindgen pattern for one-shot callbacks. Each read leaks a
+        // small, bounded amount — acceptable for this use case.
+        onload.forget();
+
+        let onerror_reject = reject.clone();
+        let onerror = Closure::once(move |_event: Event| {
+            let _ = onerror_reject.call1(&JsValue::NULL, &read_error(ReadFailure::ReadError));
+        });
+        reader.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+        onerror.forget(); // See onload.forget() comment above
+
+        let onabort = Closure::once(move |_event: Event| {
+            let _ = reject.call1(&JsValue::NULL, &read_error(ReadFailure::Aborted));
+        });
+        reader.set_onabort(Some(onabort.as_ref().unchecked_ref()));
+        onabort.forget(); // See onload.forget() comment above
+    });
+
+    reader
+        .read_as_array_buffer(&file)
+        .map_err(|_| read_error(ReadFailure::StartFailed))?;
+
+    let result = JsFuture::from(promise).await?;
+    Ok(Uint8Array::new(&result.unchecked_into::<ArrayBuffer>()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_failure_has_its_own_message() {
+        let failures = [
+            ReadFailure::ReaderUnavailable,
+            ReadFailure::StartFailed,
+            ReadFailure::ReadError,
+            ReadFailure::Aborted,
+            ReadFailure::NotArrayBuffer,
+        ];
+
+        assert_eq!(ReadFailure::ReadError.message(), "Error reading file");
+        for (i, a) in failures.iter().enumerate() {
+            assert!(!a.message().is_empty());
+            for b in &failures[i + 1..] {
+                assert_ne!(a.message(), b.message(), "{:?} / {:?}", a, b);
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Edit History
+//!
+//! Bounded undo stack of prior states, used to take back manual course edits.
+
+/// Default number of states kept for undo.
+pub const EDIT_HISTORY_DEPTH: usize = 20;
+
+/// Stack of prior states, newest last. Pushing past `capacity` drops the
+/// oldest state.
+#[derive(Debug, Clone)]
+pub struct EditHistory<T> {
+    states: Vec<T>,
+    capacity: usize,
+}
+
+impl<T> Default for EditHistory<T> {
+    fn default() -> Self {
+        Self::new(EDIT_HISTORY_DEPTH)
+    }
+}
+
+impl<T> EditHistory<T> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            states: Vec::new(),
+            capacity,
+        }
+    }
+
+    /// Records `state` (the value before an edit) as the next undo target.
+    pub fn push(&mut self, state: T) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.states.len() == self.capacity {
+            self.states.remove(0);
+        }
+        self.states.push(state);
+    }
+
+    /// Takes back the most recent edit, returning the state to restore.
+    /// `None` when there is nothing to undo.
+    pub fn undo(&mut self) -> Option<T> {
+        self.states.pop()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.states.is_empty()
+    }
+
+    /// Forgets every recorded state (e.g. after a fresh analysis).
+    pub fn clear(&mut self) {
+        self.states.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_returns_states_newest_first() {
+        let mut history = EditHistory::new(EDIT_HISTORY_DEPTH);
+        history.push(1);
+        history.push(2);
+
+        assert!(history.can_undo());
+        assert_eq!(history.undo(), Some(2));
+        assert_eq!(history.undo(), Some(1));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn undo_with_empty_history_does_nothing() {
+        let mut history: EditHistory<u32> = EditHistory::default();
+
+        assert_eq!(history.undo(), None);
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn oldest_state_drops_past_capacity() {
+        let mut history = EditHistory::new(2);
+        for state in 1..=3 {
+            history.push(state);
+        }
+
+        assert_eq!(history.undo(), Some(3));
+        assert_eq!(history.undo(), Some(2));
+        assert_eq!(history.undo(), None);
+    }
+
+    #[test]
+    fn clear_forgets_every_state() {
+        let mut history = EditHistory::new(EDIT_HISTORY_DEPTH);
+        history.push(1);
+
+        history.clear();
+
+        assert_eq!(history.undo(), None);
+    }
+}
@@ -6,6 +6,39 @@
 //! - Major course requirement matching
 //! - Free elective detection and credit accumulation
 //! - Greedy matching for repeatable courses
+//!
+//! **Advisory:** Informational checks that don't affect credits (e.g. weak grades)
+//! **Anonymize:** Advisor-facing result copy with elective course names hidden
+//! **Checklist:** Missing requirements as a markdown to-do list
+//! **Debug:** Opt-in `[DEBUG]` console logging for maintainers
+//! **Error:** `AuditError` covers every failure in the analysis flow
+//! **Export:** Raw parsed-course list as JSON or CSV, for checking extraction
+//! **File I/O:** Reads an uploaded `File` into bytes via `FileReader`
+//! **History:** Bounded undo stack for manual course edits
+//! **Progress:** Stages of the analysis flow for the loading indicator
+//! **Saved:** Versioned JSON save files for full audit results
+//! **Schema:** JSON Schema for the curriculum data types
+//! **Share:** Privacy-safe progress summary for shareable links and chat text
+//! **Sort:** Course-list orderings (code, grade, credits)
+//! **Storage:** localStorage JSON values, discarded when unreadable
+//! **Upload:** Size and type checks before a selected file is read
+//! **Validate:** Consistency checks over the curriculum data
 
+pub mod advisory;
+pub mod anonymize;
 pub mod auditor;
+pub mod checklist;
+pub mod debug;
+pub mod error;
+pub mod export;
+pub mod file_io;
+pub mod history;
 pub mod parser;
+pub mod progress;
+pub mod saved;
+pub mod schema;
+pub mod share;
+pub mod sort;
+pub mod storage;
+pub mod upload;
+pub mod validate;
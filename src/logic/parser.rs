@@ -4,8 +4,15 @@
 //! Uses JavaScript interop (via `wasm-bindgen`) to access PDF.js for text extraction,
 //! then parses course entries (code, name, credits, grade) from extracted text.
 
-use crate::models::ParsedCourse;
+use crate::data::modules::get_module_components;
+use crate::logic::debug::debug_log;
+use crate::logic::error::AuditError;
+use crate::models::{
+    free_elective_dedupe_key, grade_point, is_passing_grade, is_special_topic, GradingScheme,
+    ModuleComponents, ParsedCourse,
+};
 use regex::Regex;
+use std::collections::HashSet;
 use wasm_bindgen::prelude::*;
 
 fn normalize_course_code(raw_code: &str) -> String {
@@ -25,6 +32,146 @@ fn normalize_course_code(raw_code: &str) -> String {
     }
 }
 
+/// Normalizes a raw grade token to its canonical form: surrounding and inner
+/// whitespace removed, letters uppercased (e.g. `"a +"`, `"A+ "` → `"A+"`).
+pub fn normalize_grade(raw_grade: &str) -> String {
+    raw_grade
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase()
+}
+
+/// Returns a credit value safe to sum, and whether it had to be changed: a
+/// non-finite (NaN, infinity) or negative credit becomes 0.0, so one bad row
+/// cannot poison every total and comparison downstream.
+pub fn sanitize_credit(credit: f32) -> (f32, bool) {
+    if credit.is_finite() && credit >= 0.0 {
+        (credit, false)
+    } else {
+        (0.0, true)
+    }
+}
+
+/// Numeric columns printed between the course name and the grade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreditLayout {
+    /// `section credit` (e.g. `04 3 B+`), the standard PSU layout.
+    SectionCredit,
+    /// `section attempted earned` (e.g. `04 3 3 B+`); the earned column is used.
+    AttemptedEarned,
+    /// `section grade credit` (e.g. `04 B+ 3`), used by some exporters.
+    GradeCredit,
+}
+
+/// Options controlling how transcript text is parsed.
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    /// Curriculum codes used to repair OCR digit confusions in scanned PDFs.
+    /// `None` (the default) leaves captured codes untouched.
+    pub ocr_known_codes: Option<HashSet<String>>,
+    /// Column layout hint. `None` (the default) detects it from the text.
+    pub credit_layout: Option<CreditLayout>,
+    /// Letter grades accepted. Rows with a grade outside the scheme (e.g.
+    /// `B-` under the default plus-only scheme) are skipped.
+    pub grading_scheme: GradingScheme,
+}
+
+/// Grade token; may be lowercase or padded before the modifier (e.g. "a+", "B +").
+const GRADE_PATTERN: &str = r"(?P<grade>[Ii][Pp]|[A-Da-d][ \t]?[+\-]?|[FWPSUGEfwpsuge])";
+
+/// Builds the course-row pattern for a layout. Named groups are the same for
+/// every layout: `code`, `name`, `section`, `credit` and `grade`. The section
+/// is 1-3 digits and may be missing outside the attempted/earned layout.
+fn course_row_pattern(code_digits: &str, layout: CreditLayout) -> Regex {
+    let columns = match layout {
+        CreditLayout::SectionCredit => {
+            format!(
+                r"(?:(?P<section>\d{{1,3}})\s+)?(?P<credit>\d+)\s+{}",
+                GRADE_PATTERN
+            )
+        }
+        CreditLayout::AttemptedEarned => format!(
+            r"(?P<section>\d{{1,3}})\s+\d+\s+(?P<credit>\d+)\s+{}",
+            GRADE_PATTERN
+        ),
+        // Credits are at most two digits, so a following course code is not
+        // mistaken for the credit column.
+        CreditLayout::GradeCredit => format!(
+            r"(?:(?P<section>\d{{1,3}})\s+)?{}\s+(?P<credit>\d{{1,2}})",
+            GRADE_PATTERN
+        ),
+    };
+    Regex::new(&format!(
+        r"(?P<code>[A-Za-z0-9]{{3}}-?{}[A-Za-z]?\d*[A-Za-z]?)\s+(?P<name>[A-Za-z0-9\s:()&\-\.,'/\*]+?)\s+{}(?:\s|$)",
+        code_digits, columns
+    ))
+    .unwrap()
+}
+
+/// Detects the credit layout by column count: when most rows carry three
+/// numeric columns before the grade, the attempted/earned layout is assumed.
+/// A grade-before-credit row also fits the section/credit pattern (its section
+/// read as the credit), so that layout wins when it matches at least as many
+/// rows; on standard transcripts it matches none.
+pub fn detect_credit_layout(text: &str) -> CreditLayout {
+    let rows = |layout| course_row_pattern(r"\d{3}", layout).find_iter(text).count();
+    let two_columns = rows(CreditLayout::SectionCredit);
+    let three_columns = rows(CreditLayout::AttemptedEarned);
+    let grade_first = rows(CreditLayout::GradeCredit);
+
+    if three_columns > 0 && three_columns * 2 > two_columns {
+        CreditLayout::AttemptedEarned
+    } else if grade_first > 0 && grade_first >= two_columns {
+        CreditLayout::GradeCredit
+    } else {
+        CreditLayout::SectionCredit
+    }
+}
+
+/// Characters commonly confused by OCR with the given character.
+fn ocr_alternatives(c: char) -> &'static [char] {
+    match c {
+        'O' | 'o' => &['0'],
+        '0' => &['O'],
+        'I' | 'l' => &['1'],
+        '1' => &['I', 'l'],
+        'S' | 's' => &['5'],
+        '5' => &['S'],
+        _ => &[],
+    }
+}
+
+/// Repairs an OCR-garbled course code (e.g. `344-1O1` → `344-101`) against the
+/// known curriculum codes. Returns the code unchanged when it already matches,
+/// or when zero or several substitutions produce a known code.
+pub fn correct_ocr_code(code: &str, known_codes: &HashSet<String>) -> String {
+    if known_codes.contains(code) {
+        return code.to_string();
+    }
+
+    let mut candidates = vec![String::new()];
+    for c in code.chars() {
+        candidates = candidates
+            .into_iter()
+            .flat_map(|prefix| {
+                std::iter::once(c)
+                    .chain(ocr_alternatives(c).iter().copied())
+                    .map(move |alt| format!("{}{}", prefix, alt))
+            })
+            .collect();
+    }
+
+    let matches: HashSet<String> = candidates
+        .into_iter()
+        .filter(|candidate| known_codes.contains(candidate))
+        .collect();
+    match matches.len() {
+        1 => matches.into_iter().next().unwrap(),
+        _ => code.to_string(),
+    }
+}
+
 /// JavaScript interop function exposed by the PDF extractor in the frontend runtime.
 #[wasm_bindgen]
 extern "C" {
@@ -34,39 +181,80 @@ extern "C" {
 
 /// Parses transcript text into structured course entries, normalizing codes and
 /// greedily numbering special-topic courses (e.g., 344-496 Topic 1, Topic 2).
-pub fn parse_transcript(text: &str) -> Vec<ParsedCourse> {
+/// The credit column follows `options.credit_layout` (detected when `None`).
+/// When `options.ocr_known_codes` is set, the code pattern also accepts the
+/// letters O, I, l and S in digit positions and repairs them via `correct_ocr_code`.
+///
+/// Returns `AuditError::PdfTextEmpty` for blank input and
+/// `AuditError::NoCoursesParsed` when no course rows are recognized.
+pub fn parse_transcript(
+    text: &str,
+    options: &ParseOptions,
+) -> Result<Vec<ParsedCourse>, AuditError> {
+    if text.trim().is_empty() {
+        return Err(AuditError::PdfTextEmpty);
+    }
+
     let mut courses = Vec::new();
+    // Code of each row as printed (uppercased), for MODULE component merging
+    let mut printed_codes = Vec::new();
     let mut special_topics_count: std::collections::HashMap<String, u32> =
         std::collections::HashMap::new();
 
     // Pattern: course code followed by name, section, credit, then grade
     // Example: 322-101   CALCULUS I   04   3   B+
-    let pattern = Regex::new(
-        r"([A-Za-z0-9]{3}-?\d{3}[A-Za-z]?\d*[A-Za-z]?)\s+([A-Za-z0-9\s:()&\-\.,'/\*]+?)\s+(\d+)\s+(\d+)\s+([A-D][+]?|[FWPSUGE])",
-    )
-    .unwrap();
+    //          322-101   CALCULUS I   3   B+        (no section)
+    //          322-101   CALCULUS I   04   3   3   B+   (attempted/earned layout)
+    //          322-101   CALCULUS I   04   B+   3        (grade/credit layout)
+    // The row ends at the last column (whitespace or end of text follows). The grade may
+    // be lowercase or padded before the "+" (e.g. "a+", "B +"). Minus grades
+    // always match, so the row is not absorbed into the next one, but are
+    // kept only under `GradingScheme::PlusMinus`.
+    let code_digits = if options.ocr_known_codes.is_some() {
+        r"[\dOoIlSs]{3}"
+    } else {
+        r"\d{3}"
+    };
+    let layout = options
+        .credit_layout
+        .unwrap_or_else(|| detect_credit_layout(text));
+    let pattern = course_row_pattern(code_digits, layout);
+    debug_log(|| format!("parser: {:?} layout", layout));
 
     for captures in pattern.captures_iter(text) {
-        let raw_code = captures.get(1).unwrap().as_str();
-        let name = captures.get(2).unwrap().as_str().trim();
-        let parsed_credit_str = captures.get(4).unwrap().as_str();
-        let grade = captures.get(5).unwrap().as_str().to_uppercase();
+        let raw_code = &captures["code"];
+        let name = captures["name"].trim();
+        let section = captures.name("section").map(|m| m.as_str().to_string());
+        let parsed_credit_str = &captures["credit"];
+        let grade = normalize_grade(&captures["grade"]);
+        if !options.grading_scheme.accepts(&grade) {
+            continue;
+        }
 
-        let parsed_credit = parsed_credit_str.parse::<f32>().unwrap_or(3.0);
+        let (parsed_credit, low_confidence) = match parsed_credit_str.parse::<f32>() {
+            Ok(credit) => sanitize_credit(credit),
+            Err(_) => (3.0, true),
+        };
+        if low_confidence {
+            debug_log(|| {
+                format!(
+                    "parser: unreadable credit {:?} in {}",
+                    parsed_credit_str, raw_code
+                )
+            });
+        }
 
         // Normalize course code by trimming suffix (e.g., 890-103G1 -> 890-103)
         // and applying known equivalence mappings used by the curriculum.
         let normalized_code = normalize_course_code(raw_code);
+        let normalized_code = match &options.ocr_known_codes {
+            Some(known_codes) => correct_ocr_code(&normalized_code, known_codes),
+            None => normalized_code,
+        };
 
-        // Greedy match: Special topics (344-496 to 344-499) might be repeated.
-        // We handle any course starting with 344-49, EXCEPT the specific Capstone/Core ones.
-        let is_special_topic = normalized_code.starts_with("344-49")
-            && !matches!(
-                normalized_code.as_str(),
-                "344-491" | "344-492" | "344-493" | "344-494" | "344-495"
-            );
-
-        let final_name = if is_special_topic {
+        // Special topics (344-496 to 344-499) might be repeated, so each
+        // enrollment is numbered to keep it distinct.
+        let final_name = if is_special_topic(&normalized_code) {
             let counter = special_topics_count
                 .entry(normalized_code.clone())
                 .or_insert(0);
@@ -76,13 +264,345 @@ pub fn parse_transcript(text: &str) -> Vec<ParsedCourse> {
             name.to_string()
         };
 
+        printed_codes.push(raw_code.to_uppercase());
         courses.push(ParsedCourse {
             code: normalized_code,
             name: final_name,
             grade,
             parsed_credit,
+            section,
+            low_confidence,
         });
     }
 
+    if courses.is_empty() {
+        return Err(AuditError::NoCoursesParsed);
+    }
+
+    let courses = merge_module_components(courses, &printed_codes, &get_module_components());
+    debug_log(|| format!("parser: {} course rows", courses.len()));
+    Ok(courses)
+}
+
+/// Merges MODULE component rows (e.g. `344-201A` and `344-201B`, matched by
+/// `printed_codes`) into one row under the module code, so the module is
+/// credited once with its full credits. A module is merged only when every
+/// component appears; the best attempt of each component is used, and the
+/// merged row takes the lowest of their grades. Other attempts stay as-is.
+fn merge_module_components(
+    mut courses: Vec<ParsedCourse>,
+    printed_codes: &[String],
+    modules: &[ModuleComponents],
+) -> Vec<ParsedCourse> {
+    let mut merged_rows: Vec<(usize, ParsedCourse)> = Vec::new();
+    let mut consumed: HashSet<usize> = HashSet::new();
+    for module in modules {
+        let best_attempts: Option<Vec<usize>> = module
+            .components
+            .iter()
+            .map(|component| {
+                printed_codes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, printed)| *printed == component)
+                    .map(|(idx, _)| idx)
+                    .max_by(|&a, &b| merge_rank(&courses[a]).total_cmp(&merge_rank(&courses[b])))
+            })
+            .collect();
+        let Some(attempts) = best_attempts else {
+            continue;
+        };
+        let Some(weakest) = attempts
+            .iter()
+            .copied()
+            .min_by(|&a, &b| merge_rank(&courses[a]).total_cmp(&merge_rank(&courses[b])))
+        else {
+            continue;
+        };
+        let first = *attempts.iter().min().unwrap();
+        merged_rows.push((
+            first,
+            ParsedCourse {
+                code: module.code.clone(),
+                name: module.name.to_uppercase(),
+                grade: courses[weakest].grade.clone(),
+                parsed_credit: attempts.iter().map(|&idx| courses[idx].parsed_credit).sum(),
+                section: courses[first].section.clone(),
+                low_confidence: attempts.iter().any(|&idx| courses[idx].low_confidence),
+            },
+        ));
+        consumed.extend(attempts);
+        debug_log(|| format!("parser: merged {} components", module.code));
+    }
+
+    if merged_rows.is_empty() {
+        return courses;
+    }
+    // Each merged row takes the place of its first component
+    for (position, row) in merged_rows {
+        courses[position] = row;
+        consumed.remove(&position);
+    }
     courses
+        .into_iter()
+        .enumerate()
+        .filter(|(idx, _)| !consumed.contains(idx))
+        .map(|(_, course)| course)
+        .collect()
+}
+
+/// Merges course lists parsed from several transcripts (e.g. a transfer
+/// student's previous and current institutions) into one list.
+///
+/// Rows within a single transcript are kept as-is (retakes are handled by the
+/// auditor). A course that reappears in a later transcript is treated as the
+/// same enrollment: only one copy is kept, preferring a passing result, then
+/// the higher grade point, then the earlier transcript.
+pub fn merge_transcripts(transcripts: Vec<Vec<ParsedCourse>>) -> Vec<ParsedCourse> {
+    let mut merged: Vec<ParsedCourse> = Vec::new();
+    // Dedupe key -> positions in `merged` contributed by earlier transcripts
+    let mut seen: std::collections::HashMap<String, Vec<usize>> = std::collections::HashMap::new();
+
+    for transcript in transcripts {
+        let mut added: Vec<(String, usize)> = Vec::new();
+
+        for course in transcript {
+            let key = free_elective_dedupe_key(&course.code, &course.name);
+            match seen.get(&key) {
+                Some(positions) => {
+                    let best = positions
+                        .iter()
+                        .copied()
+                        .max_by(|&a, &b| merge_rank(&merged[a]).total_cmp(&merge_rank(&merged[b])))
+                        .unwrap();
+                    if merge_rank(&course) > merge_rank(&merged[best]) {
+                        merged[best] = course;
+                    }
+                }
+                None => {
+                    added.push((key, merged.len()));
+                    merged.push(course);
+                }
+            }
+        }
+
+        for (key, position) in added {
+            seen.entry(key).or_default().push(position);
+        }
+    }
+
+    merged
+}
+
+/// Ranks duplicate rows for `merge_transcripts`: failing rows rank lowest,
+/// passing rows rank by grade point (grades without one rank as 0.0).
+fn merge_rank(course: &ParsedCourse) -> f32 {
+    if is_passing_grade(&course.grade) {
+        grade_point(&course.grade).unwrap_or(0.0)
+    } else {
+        -1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Vec<ParsedCourse> {
+        parse_transcript(text, &ParseOptions::default()).expect("transcript should parse")
+    }
+
+    fn grades(courses: &[ParsedCourse]) -> Vec<(&str, &str)> {
+        courses
+            .iter()
+            .map(|c| (c.code.as_str(), c.grade.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn grade_spacing_and_case_variants_are_normalized() {
+        let courses = parse(
+            "344-111 PROG 01 3 A \n\
+             344-112 PROG 01 3 A+\t\n\
+             344-113 PROG 01 3 a+ 344-114 PROG 01 3 b\n\
+             344-115 PROG 01 3 B +",
+        );
+
+        assert_eq!(
+            grades(&courses),
+            [
+                ("344-111", "A"),
+                ("344-112", "A+"),
+                ("344-113", "A+"),
+                ("344-114", "B"),
+                ("344-115", "B+"),
+            ]
+        );
+    }
+
+    #[test]
+    fn ocr_digit_confusions_are_corrected_against_known_codes() {
+        let known: HashSet<String> = ["344-101", "344-105", "344-10S"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+
+        assert_eq!(correct_ocr_code("344-1O1", &known), "344-101");
+        // 344-105 and 344-10S are both one confusion away, so nothing is guessed
+        assert_eq!(correct_ocr_code("344-1O5", &known), "344-1O5");
+
+        let text = "344-1O1 INTRO 01 3 A";
+        let options = ParseOptions {
+            ocr_known_codes: Some(known),
+            ..Default::default()
+        };
+        assert_eq!(parse_transcript(text, &options).unwrap()[0].code, "344-101");
+        assert!(parse_transcript(text, &ParseOptions::default()).is_err());
+    }
+
+    #[test]
+    fn merged_transcripts_keep_one_copy_of_a_shared_course() {
+        let first = parse("322-101 CALCULUS I 01 3 C\n344-101 INTRO 01 3 A");
+        let second = parse(
+            "322-101 CALCULUS I 01 3 B\n\
+             344-102 PROGRAMMING 01 3 F\n\
+             344-102 PROGRAMMING 01 3 A",
+        );
+
+        let merged = merge_transcripts(vec![first, second]);
+
+        // The shared course keeps the better grade; retakes within one transcript stay
+        assert_eq!(
+            grades(&merged),
+            [
+                ("322-101", "B"),
+                ("344-101", "A"),
+                ("344-102", "F"),
+                ("344-102", "A"),
+            ]
+        );
+    }
+
+    fn credits(courses: &[ParsedCourse]) -> Vec<f32> {
+        courses.iter().map(|c| c.parsed_credit).collect()
+    }
+
+    #[test]
+    fn section_credit_layout_reads_the_credit_after_the_section() {
+        let text = "322-101 CALCULUS I 04 3 B+ 344-101 INTRO 01 2 A";
+
+        assert_eq!(detect_credit_layout(text), CreditLayout::SectionCredit);
+        assert_eq!(credits(&parse(text)), [3.0, 2.0]);
+    }
+
+    #[test]
+    fn attempted_earned_layout_reads_the_earned_credit() {
+        let text = "322-101 CALCULUS I 04 4 3 B+ 344-101 INTRO 01 3 2 A";
+
+        assert_eq!(detect_credit_layout(text), CreditLayout::AttemptedEarned);
+        let courses = parse(text);
+        assert_eq!(credits(&courses), [3.0, 2.0]);
+        assert_eq!(courses[0].name, "CALCULUS I");
+    }
+
+    #[test]
+    fn sections_of_any_width_are_separated_from_the_credit() {
+        let courses = parse(
+            "344-101 Intro Programming 3 A\n\
+             344-102 Data Structures 1 3 B+\n\
+             344-103 Databases 101 2 C",
+        );
+
+        let rows: Vec<_> = courses
+            .iter()
+            .map(|c| {
+                (
+                    c.name.as_str(),
+                    c.section.as_deref(),
+                    c.parsed_credit,
+                    c.grade.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("Intro Programming", None, 3.0, "A"),
+                ("Data Structures", Some("1"), 3.0, "B+"),
+                ("Databases", Some("101"), 2.0, "C"),
+            ]
+        );
+    }
+
+    #[test]
+    fn minus_grades_are_kept_only_under_plus_minus() {
+        let text = "322-101 CALCULUS I 01 3 B-\n322-102 CALCULUS II 01 3 A";
+        let plus_minus = ParseOptions {
+            grading_scheme: GradingScheme::PlusMinus,
+            ..ParseOptions::default()
+        };
+
+        let courses = parse_transcript(text, &plus_minus).unwrap();
+        assert_eq!(grades(&courses), [("322-101", "B-"), ("322-102", "A")]);
+        assert_eq!(grade_point(&courses[0].grade), Some(2.75));
+
+        assert_eq!(grades(&parse(text)), [("322-102", "A")]);
+    }
+
+    #[test]
+    fn grade_before_credit_layout_is_detected_and_parsed() {
+        let text = "322-101 CALCULUS I 01 B+ 3\n\
+                    344-201 MODULE COMPUTING 01 A 6\n\
+                    890-101 ESSENTIAL ENGLISH 0 S 0";
+        assert_eq!(detect_credit_layout(text), CreditLayout::GradeCredit);
+
+        let courses = parse(text);
+        assert_eq!(courses.len(), 3);
+        assert_eq!(
+            (
+                courses[0].grade.as_str(),
+                courses[0].parsed_credit,
+                courses[0].section.as_deref()
+            ),
+            ("B+", 3.0, Some("01"))
+        );
+        assert_eq!(
+            (courses[1].grade.as_str(), courses[1].parsed_credit),
+            ("A", 6.0)
+        );
+
+        assert_eq!(
+            detect_credit_layout("322-101 CALCULUS I 01 3 B+ 344-201 MODULE COMPUTING 01 6 A"),
+            CreditLayout::SectionCredit
+        );
+    }
+
+    #[test]
+    fn unusable_credits_are_zeroed_and_marked_low_confidence() {
+        assert_eq!(sanitize_credit(f32::NAN), (0.0, true));
+        assert_eq!(sanitize_credit(-3.0), (0.0, true));
+        assert_eq!(sanitize_credit(f32::INFINITY), (0.0, true));
+        assert_eq!(sanitize_credit(3.0), (3.0, false));
+
+        let courses = parse("322-101 CALCULUS I 01 99999999999999999999999999999999999999999 A");
+        assert!(courses.iter().all(|c| c.parsed_credit.is_finite()));
+    }
+
+    #[test]
+    fn module_components_merge_into_one_row() {
+        let courses = parse(
+            "344-201A COMPUTING PART A 01 3 A\n\
+             322-101 CALCULUS I 01 3 B\n\
+             344-201B COMPUTING PART B 01 3 B+",
+        );
+
+        assert_eq!(courses.len(), 2);
+        assert_eq!(courses[0].code, "344-201");
+        assert_eq!(courses[0].parsed_credit, 6.0);
+        assert_eq!(courses[0].grade, "B+");
+
+        let partial = parse("344-201A COMPUTING PART A 01 3 A\n322-101 CALCULUS I 01 3 B");
+        assert_eq!(partial.len(), 2);
+        assert_eq!(partial[0].parsed_credit, 3.0);
+    }
 }
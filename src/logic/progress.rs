@@ -0,0 +1,127 @@
+//! Analysis Progress
+//!
+//! Stages of the transcript analysis flow, shown under the loading spinner
+//! so it is clear where time goes on large PDFs, and the upload-panel state
+//! a run moves through.
+
+/// One step of `analyze_files`, in the order they run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AnalysisStage {
+    ReadingFile,
+    ExtractingText,
+    ParsingCourses,
+    Auditing,
+}
+
+impl AnalysisStage {
+    /// Every stage, in run order.
+    pub const ALL: [AnalysisStage; 4] = [
+        AnalysisStage::ReadingFile,
+        AnalysisStage::ExtractingText,
+        AnalysisStage::ParsingCourses,
+        AnalysisStage::Auditing,
+    ];
+
+    /// 1-based position of the stage, e.g. 2 for "step 2 of 4".
+    pub fn step(self) -> usize {
+        self as usize + 1
+    }
+
+    pub fn label(self, is_thai: bool) -> &'static str {
+        match (self, is_thai) {
+            (AnalysisStage::ReadingFile, true) => "กำลังอ่านไฟล์",
+            (AnalysisStage::ReadingFile, false) => "Reading file",
+            (AnalysisStage::ExtractingText, true) => "กำลังดึงข้อความจาก PDF",
+            (AnalysisStage::ExtractingText, false) => "Extracting text",
+            (AnalysisStage::ParsingCourses, true) => "กำลังแยกรายวิชา",
+            (AnalysisStage::ParsingCourses, false) => "Parsing courses",
+            (AnalysisStage::Auditing, true) => "กำลังตรวจสอบข้อกำหนด",
+            (AnalysisStage::Auditing, false) => "Auditing",
+        }
+    }
+}
+
+/// Upload-panel state driven by an analysis run. Generic over the file type
+/// so the transitions can be checked without browser `File`s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalysisState<F> {
+    pub selected_files: Vec<F>,
+    pub is_loading: bool,
+    pub error_msg: Option<String>,
+    pub stage: Option<AnalysisStage>, // None when idle
+}
+
+impl<F> AnalysisState<F> {
+    /// Starts a run: loading, with any previous error cleared. Returns
+    /// `false` and changes nothing when no file is selected.
+    pub fn start(&mut self) -> bool {
+        if self.selected_files.is_empty() {
+            return false;
+        }
+        self.is_loading = true;
+        self.error_msg = None;
+        true
+    }
+
+    /// Ends a run with `error` (`None` on success). The stage is reset either
+    /// way so a retry starts from the top; the selection is kept so "Try
+    /// again" needs no re-upload.
+    pub fn finish(&mut self, error: Option<String>) {
+        self.stage = None;
+        self.is_loading = false;
+        self.error_msg = error;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stages_run_in_step_order() {
+        for (i, stage) in AnalysisStage::ALL.iter().enumerate() {
+            assert_eq!(stage.step(), i + 1);
+        }
+        assert!(AnalysisStage::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(AnalysisStage::Auditing.label(false), "Auditing");
+        assert_eq!(AnalysisStage::ReadingFile.label(true), "กำลังอ่านไฟล์");
+    }
+
+    #[test]
+    fn failed_run_keeps_the_selection_and_allows_a_retry() {
+        let mut state = AnalysisState {
+            selected_files: vec!["transcript.pdf"],
+            is_loading: false,
+            error_msg: Some("Previous failure".to_string()),
+            stage: None,
+        };
+
+        assert!(state.start());
+        assert!(state.is_loading);
+        assert_eq!(state.error_msg, None);
+
+        state.stage = Some(AnalysisStage::ExtractingText);
+        state.finish(Some("No text found".to_string()));
+
+        assert_eq!(state.selected_files, ["transcript.pdf"]);
+        assert!(!state.is_loading);
+        assert_eq!(state.stage, None);
+        assert_eq!(state.error_msg.as_deref(), Some("No text found"));
+        // "Try again" is offered while files are still selected
+        assert!(!state.selected_files.is_empty());
+        assert!(state.start());
+    }
+
+    #[test]
+    fn run_without_files_does_not_start() {
+        let mut state = AnalysisState::<&str> {
+            selected_files: vec![],
+            is_loading: false,
+            error_msg: None,
+            stage: None,
+        };
+
+        assert!(!state.start());
+        assert!(!state.is_loading);
+    }
+}
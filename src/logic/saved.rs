@@ -0,0 +1,94 @@
+//! Saved Audit Results
+//!
+//! Serializes a full `AuditResult` to JSON so it can be downloaded and opened
+//! again later. Every file carries `schema_version`; files written by another
+//! version are rejected instead of being loaded into an inconsistent state.
+
+use crate::logic::error::AuditError;
+use crate::models::{AuditResult, AUDIT_SCHEMA_VERSION};
+use serde::Deserialize;
+
+/// File name suggested when downloading a saved result.
+pub const SAVED_RESULT_FILE_NAME: &str = "audit-result.json";
+
+/// Just the version header, read before the full payload is trusted.
+#[derive(Deserialize)]
+struct SchemaHeader {
+    #[serde(default)]
+    schema_version: u32, // Files from before versioning read as 0
+}
+
+/// Serializes an audit result (including its schema version) as JSON.
+pub fn save_result(result: &AuditResult) -> String {
+    serde_json::to_string_pretty(result).unwrap_or_default()
+}
+
+/// Loads a result written by `save_result`. The schema version is checked
+/// first, so a file from another version fails with `UnsupportedSchemaVersion`
+/// rather than a field-level parse error.
+pub fn load_result(json: &str) -> Result<AuditResult, AuditError> {
+    let header: SchemaHeader =
+        serde_json::from_str(json).map_err(|e| AuditError::SavedResultInvalid(e.to_string()))?;
+    if header.schema_version != AUDIT_SCHEMA_VERSION {
+        return Err(AuditError::UnsupportedSchemaVersion(header.schema_version));
+    }
+
+    serde_json::from_str(json).map_err(|e| AuditError::SavedResultInvalid(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{gen_ed::get_gen_ed_curriculum, major::get_major_curriculum};
+    use crate::logic::auditor::audit_transcript;
+    use crate::logic::parser::{parse_transcript, ParseOptions};
+
+    fn saved_json() -> (AuditResult, String) {
+        let courses = parse_transcript(
+            "322-101 CALCULUS I 01 3 B+\n344-492 PROJECTS 01 3 A",
+            &ParseOptions::default(),
+        )
+        .unwrap();
+        let result = audit_transcript(
+            &courses,
+            &get_gen_ed_curriculum(),
+            &get_major_curriculum(),
+            &Default::default(),
+        );
+        let json = save_result(&result);
+        (result, json)
+    }
+
+    #[test]
+    fn current_version_loads() {
+        let (result, json) = saved_json();
+
+        let loaded = load_result(&json).expect("saved result should load");
+
+        assert_eq!(loaded.schema_version, AUDIT_SCHEMA_VERSION);
+        assert_eq!(loaded.total_credits, result.total_credits);
+        assert_eq!(loaded.missing_subjects.len(), result.missing_subjects.len());
+    }
+
+    #[test]
+    fn other_versions_are_rejected() {
+        let (_, json) = saved_json();
+        let header = format!("\"schema_version\": {}", AUDIT_SCHEMA_VERSION);
+        assert!(json.contains(&header));
+
+        let newer = json.replacen(&header, "\"schema_version\": 99", 1);
+        assert_eq!(
+            load_result(&newer).unwrap_err(),
+            AuditError::UnsupportedSchemaVersion(99)
+        );
+        let unversioned = json.replacen(&format!("{},", header), "", 1);
+        assert_eq!(
+            load_result(&unversioned).unwrap_err(),
+            AuditError::UnsupportedSchemaVersion(0)
+        );
+        assert!(matches!(
+            load_result("not json"),
+            Err(AuditError::SavedResultInvalid(_))
+        ));
+    }
+}
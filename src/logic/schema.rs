@@ -0,0 +1,42 @@
+//! Curriculum Schema
+//!
+//! JSON Schema for the curriculum data types, derived from the Rust structs so
+//! it cannot drift from them. Maintainers editing curriculum data (or tooling
+//! that generates it) can validate against it; the page offers a download
+//! while debug logging (`?debug`) is on.
+
+use crate::models::{GenEdCurriculum, MajorCurriculum};
+use schemars::schema_for;
+use serde_json::json;
+
+/// File name suggested when downloading the curriculum schema.
+pub const CURRICULUM_SCHEMA_FILE_NAME: &str = "curriculum-schema.json";
+
+/// Pretty-printed JSON holding one schema per curriculum, keyed `gen_ed` and
+/// `major`.
+pub fn print_curriculum_schema() -> String {
+    let schemas = json!({
+        "gen_ed": schema_for!(GenEdCurriculum),
+        "major": schema_for!(MajorCurriculum),
+    });
+    serde_json::to_string_pretty(&schemas).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_is_json_describing_both_curricula() {
+        let schema: serde_json::Value = serde_json::from_str(&print_curriculum_schema()).unwrap();
+
+        let gen_ed = &schema["gen_ed"]["properties"];
+        for field in ["name", "total_required_credits", "strands", "electives"] {
+            assert!(gen_ed.get(field).is_some(), "gen_ed.{}", field);
+        }
+        let major = &schema["major"]["properties"];
+        for field in ["basic_science", "core_courses", "capstone", "electives"] {
+            assert!(major.get(field).is_some(), "major.{}", field);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Shareable Progress Summary
+//!
+//! Encodes a minimal, privacy-safe view of an `AuditResult` (total credits,
+//! per-category percentages, graduation flag — no course names or grades)
+//! into a URL-safe string carried in the link fragment (`#s=<payload>`), or
+//! as a few plain-text lines for pasting into LINE or Discord.
+
+use crate::models::{format_credits, progress_percent, AuditResult};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{Deserialize, Serialize};
+
+/// URL fragment prefix that marks a shared summary link.
+pub const SHARE_FRAGMENT_PREFIX: &str = "#s=";
+
+/// Completion percentage of a single category in a shared summary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SharedCategory {
+    pub name: String,
+    pub percent: f32,
+}
+
+/// Minimal progress snapshot that is safe to paste into a chat.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SharedSummary {
+    pub total_credits: f32,
+    pub categories: Vec<SharedCategory>,
+    pub graduation_ready: bool,
+}
+
+impl SharedSummary {
+    /// Builds the snapshot from a full audit result, dropping all course data.
+    pub fn from_result(result: &AuditResult) -> Self {
+        Self {
+            total_credits: result.total_credits,
+            categories: result
+                .categories
+                .iter()
+                .map(|c| SharedCategory {
+                    name: c.name.clone(),
+                    percent: progress_percent(c.collected_credits, c.required_credits),
+                })
+                .collect(),
+            graduation_ready: result.is_complete(),
+        }
+    }
+}
+
+/// Encodes the shareable part of an audit result as URL-safe base64 JSON.
+pub fn encode_summary(result: &AuditResult) -> String {
+    let summary = SharedSummary::from_result(result);
+    let json = serde_json::to_vec(&summary).unwrap_or_default();
+    URL_SAFE_NO_PAD.encode(json)
+}
+
+/// Decodes a payload produced by `encode_summary`. Malformed or truncated
+/// payloads yield `None` rather than a partially-filled summary.
+pub fn decode_summary(payload: &str) -> Option<SharedSummary> {
+    let bytes = URL_SAFE_NO_PAD.decode(payload.trim()).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+/// Plain-text progress summary for pasting into a chat: total credits, GPA
+/// (two decimals, "-" when there are no graded credits), one "X/Y" credits
+/// line per category in result order, and the missing-requirement count.
+pub fn audit_to_text_summary(result: &AuditResult, gpa: Option<f32>) -> String {
+    let mut lines = vec![
+        "Course Audit Summary".to_string(),
+        format!("Total credits: {}", format_credits(result.total_credits)),
+        match gpa {
+            Some(gpa) => format!("GPA: {:.2}", gpa),
+            None => "GPA: -".to_string(),
+        },
+    ];
+    lines.extend(result.categories.iter().map(|c| {
+        format!(
+            "{}: {}/{}",
+            c.name,
+            format_credits(c.collected_credits),
+            format_credits(c.required_credits)
+        )
+    }));
+    lines.push(format!(
+        "Missing requirements: {}",
+        result.missing_subjects.len()
+    ));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{gen_ed::get_gen_ed_curriculum, major::get_major_curriculum};
+    use crate::logic::auditor::audit_transcript;
+    use crate::logic::parser::{parse_transcript, ParseOptions};
+    use crate::models::Category;
+
+    fn sample_result() -> AuditResult {
+        let courses = parse_transcript(
+            "322-101 CALCULUS I 01 3 B+\n\
+             890-102 EVERYDAY ENGLISH 01 2 B\n\
+             890-103 ENGLISH ON THE GO 01 2 A\n\
+             344-492 PROJECTS 01 3 A\n\
+             999-101 PHOTOGRAPHY 01 3 A",
+            &ParseOptions::default(),
+        )
+        .unwrap();
+        audit_transcript(
+            &courses,
+            &get_gen_ed_curriculum(),
+            &get_major_curriculum(),
+            &Default::default(),
+        )
+    }
+
+    #[test]
+    fn summary_round_trips_with_percentages() {
+        let result = sample_result();
+        let decoded = decode_summary(&encode_summary(&result)).expect("payload should decode");
+
+        assert_eq!(decoded, SharedSummary::from_result(&result));
+        assert_eq!(decoded.total_credits, result.total_credits);
+        assert!(!decoded.graduation_ready);
+        let percents: Vec<_> = decoded.categories.iter().map(|c| c.percent).collect();
+        let expected: Vec<_> = result
+            .categories
+            .iter()
+            .map(|c| progress_percent(c.collected_credits, c.required_credits))
+            .collect();
+        assert_eq!(percents, expected);
+    }
+
+    #[test]
+    fn malformed_or_truncated_payloads_are_rejected() {
+        let payload = encode_summary(&sample_result());
+
+        assert_eq!(decode_summary("not base64!!"), None);
+        assert_eq!(decode_summary(&payload[..payload.len() - 5]), None);
+    }
+
+    #[test]
+    fn text_summary_lists_totals_categories_and_missing_count() {
+        let category = |name: &str, required: f32, collected: f32| Category {
+            name: name.to_string(),
+            required_credits: required,
+            collected_credits: collected,
+            courses: Vec::new(),
+        };
+        let mut result = sample_result();
+        result.total_credits = 97.5;
+        result.categories = vec![
+            category("General Education", 30.0, 30.0),
+            category("Major Courses", 96.0, 61.5),
+            category("Free Electives", 6.0, 6.0),
+        ];
+        result.missing_subjects.truncate(3);
+
+        assert_eq!(
+            audit_to_text_summary(&result, Some(3.256)),
+            "Course Audit Summary\n\
+             Total credits: 97.5\n\
+             GPA: 3.26\n\
+             General Education: 30/30\n\
+             Major Courses: 61.5/96\n\
+             Free Electives: 6/6\n\
+             Missing requirements: 3"
+        );
+        assert!(audit_to_text_summary(&result, None).contains("GPA: -"));
+    }
+}
@@ -0,0 +1,99 @@
+//! Course List Sorting
+//!
+//! Orderings offered in an expanded category's course list. Grade sorting
+//! follows grade points (B+ above B above C+), not the alphabet.
+
+use crate::models::{grade_point, Course};
+use std::cmp::Ordering;
+
+/// Sort order for a category's course list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortKey {
+    #[default]
+    Transcript, // Order as printed on the transcript
+    Code,
+    GradeBest,
+    GradeWorst,
+    Credits, // Most credits first
+}
+
+/// Orders grades by grade point; grades without one (S/P/G/...) always sort last.
+fn compare_grades(a: &str, b: &str, best_first: bool) -> Ordering {
+    match (grade_point(a), grade_point(b)) {
+        (Some(x), Some(y)) if best_first => y.total_cmp(&x),
+        (Some(x), Some(y)) => x.total_cmp(&y),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Sorts `courses` in place by `key`. The sort is stable, so ties keep
+/// transcript order; `SortKey::Transcript` leaves the list unchanged.
+pub fn sort_courses(courses: &mut [Course], key: SortKey) {
+    match key {
+        SortKey::Transcript => {}
+        SortKey::Code => courses.sort_by(|a, b| a.code.cmp(&b.code)),
+        SortKey::GradeBest => courses.sort_by(|a, b| compare_grades(&a.grade, &b.grade, true)),
+        SortKey::GradeWorst => courses.sort_by(|a, b| compare_grades(&a.grade, &b.grade, false)),
+        SortKey::Credits => courses.sort_by(|a, b| b.credit.total_cmp(&a.credit)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn course(code: &str, grade: &str, credit: f32) -> Course {
+        Course {
+            code: code.to_string(),
+            name: "Course".to_string(),
+            credit,
+            grade: grade.to_string(),
+            curriculum_name: None,
+            section: None,
+            substitutes_for: None,
+            matched: None,
+        }
+    }
+
+    fn sorted(key: SortKey) -> Vec<String> {
+        let mut courses = vec![
+            course("344-103", "C+", 1.0),
+            course("344-101", "S", 3.0),
+            course("344-102", "B+", 2.0),
+            course("344-104", "B", 4.0),
+        ];
+        sort_courses(&mut courses, key);
+        courses.into_iter().map(|c| c.code).collect()
+    }
+
+    #[test]
+    fn each_sort_key_orders_the_course_list() {
+        assert_eq!(
+            sorted(SortKey::Transcript),
+            ["344-103", "344-101", "344-102", "344-104"]
+        );
+        assert_eq!(
+            sorted(SortKey::Code),
+            ["344-101", "344-102", "344-103", "344-104"]
+        );
+        assert_eq!(
+            sorted(SortKey::Credits),
+            ["344-104", "344-101", "344-102", "344-103"]
+        );
+    }
+
+    #[test]
+    fn grades_sort_by_grade_point_with_ungraded_last() {
+        // B+ (344-102) > B (344-104) > C+ (344-103); S has no grade point
+        assert_eq!(
+            sorted(SortKey::GradeBest),
+            ["344-102", "344-104", "344-103", "344-101"]
+        );
+        assert_eq!(
+            sorted(SortKey::GradeWorst),
+            ["344-103", "344-104", "344-102", "344-101"]
+        );
+    }
+}
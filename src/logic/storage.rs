@@ -0,0 +1,81 @@
+//! Stored Settings
+//!
+//! Reads the JSON values the app keeps in localStorage (pinned clusters,
+//! substitutions, category order). A value is parsed all-or-nothing; one that
+//! no longer parses (truncated write, older shape) is logged and removed so
+//! the next start is clean instead of failing on the same value again.
+
+use leptos::logging;
+use serde::de::DeserializeOwned;
+
+/// The parts of a key-value store the loaders use. Implemented by
+/// `web_sys::Storage`; kept as a trait so loading can run without a browser.
+pub trait KeyValueStore {
+    fn get_value(&self, key: &str) -> Option<String>;
+    fn remove_value(&self, key: &str);
+}
+
+impl KeyValueStore for web_sys::Storage {
+    fn get_value(&self, key: &str) -> Option<String> {
+        self.get_item(key).ok().flatten()
+    }
+
+    fn remove_value(&self, key: &str) {
+        let _ = self.remove_item(key);
+    }
+}
+
+/// The page's localStorage; `None` when unavailable (e.g. blocked cookies).
+pub fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Parses the JSON stored under `key`. `None` when nothing is stored, or when
+/// the value fails to parse, in which case the key is also removed.
+pub fn load_json<T: DeserializeOwned>(store: &impl KeyValueStore, key: &str) -> Option<T> {
+    let json = store.get_value(key)?;
+    match serde_json::from_str(&json) {
+        Ok(value) => Some(value),
+        Err(err) => {
+            logging::warn!("Discarding unreadable stored value {}: {}", key, err);
+            store.remove_value(key);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    /// In-memory stand-in for `localStorage`.
+    struct MemoryStore(RefCell<HashMap<String, String>>);
+
+    impl KeyValueStore for MemoryStore {
+        fn get_value(&self, key: &str) -> Option<String> {
+            self.0.borrow().get(key).cloned()
+        }
+
+        fn remove_value(&self, key: &str) {
+            self.0.borrow_mut().remove(key);
+        }
+    }
+
+    #[test]
+    fn truncated_json_loads_nothing_and_clears_the_key() {
+        let store = MemoryStore(RefCell::new(HashMap::from([
+            ("truncated".to_string(), "[\"a\", \"b".to_string()),
+            ("valid".to_string(), "[\"a\"]".to_string()),
+        ])));
+
+        assert_eq!(load_json::<Vec<String>>(&store, "truncated"), None);
+        assert!(store.get_value("truncated").is_none());
+        assert_eq!(
+            load_json::<Vec<String>>(&store, "valid"),
+            Some(vec!["a".to_string()])
+        );
+        assert_eq!(load_json::<Vec<String>>(&store, "missing"), None);
+    }
+}
@@ -0,0 +1,90 @@
+//! Upload Checks
+//!
+//! Size and type checks run on a selected file before it is read, so an
+//! oversized or wrong file fails fast with a clear message instead of deep
+//! inside PDF extraction.
+
+use crate::logic::error::AuditError;
+
+/// Largest accepted upload, in megabytes.
+pub const MAX_UPLOAD_MB: u32 = 20;
+
+/// What an uploaded file is expected to be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadKind {
+    Transcript,  // Transcript PDF
+    SavedResult, // JSON written by "Save results"
+}
+
+/// Checks a file's name, MIME type and size (in bytes) against `kind`.
+/// Browsers often leave the MIME type empty, so a matching extension is
+/// enough; a non-empty MIME type must also match.
+pub fn check_upload(name: &str, mime: &str, size: f64, kind: UploadKind) -> Result<(), AuditError> {
+    let (extension, mime_type) = match kind {
+        UploadKind::Transcript => (".pdf", "application/pdf"),
+        UploadKind::SavedResult => (".json", "application/json"),
+    };
+
+    let extension_ok = name.to_lowercase().ends_with(extension);
+    let mime_ok = mime.is_empty() || mime.eq_ignore_ascii_case(mime_type);
+    if !extension_ok || !mime_ok {
+        return Err(AuditError::UnsupportedFileType);
+    }
+    if size > f64::from(MAX_UPLOAD_MB) * 1024.0 * 1024.0 {
+        return Err(AuditError::FileTooLarge(MAX_UPLOAD_MB));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MB: f64 = 1024.0 * 1024.0;
+
+    #[test]
+    fn pdf_within_the_limit_passes() {
+        assert_eq!(
+            check_upload("grades.PDF", "application/pdf", MB, UploadKind::Transcript),
+            Ok(())
+        );
+        // Browsers may report no MIME type at all
+        assert_eq!(
+            check_upload("grades.pdf", "", MB, UploadKind::Transcript),
+            Ok(())
+        );
+        assert_eq!(
+            check_upload(
+                "audit.json",
+                "application/json",
+                MB,
+                UploadKind::SavedResult
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn oversize_file_is_rejected() {
+        let size = f64::from(MAX_UPLOAD_MB + 1) * MB;
+        assert_eq!(
+            check_upload(
+                "grades.pdf",
+                "application/pdf",
+                size,
+                UploadKind::Transcript
+            ),
+            Err(AuditError::FileTooLarge(MAX_UPLOAD_MB))
+        );
+    }
+
+    #[test]
+    fn wrong_type_is_rejected() {
+        for (name, mime) in [("grades.png", "image/png"), ("grades.pdf", "image/png")] {
+            assert_eq!(
+                check_upload(name, mime, MB, UploadKind::Transcript),
+                Err(AuditError::UnsupportedFileType)
+            );
+        }
+    }
+}
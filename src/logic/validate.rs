@@ -0,0 +1,242 @@
+//! Curriculum Validation
+//!
+//! Consistency checks over the curriculum definitions. Problems are returned
+//! as human-readable warnings rather than errors: a data slip should be
+//! surfaced to maintainers without blocking the audit.
+
+use crate::logic::auditor::major_required_codes;
+use crate::models::{canonical_code, GenEdCurriculum, MajorCurriculum};
+use std::collections::HashMap;
+
+/// Largest difference, in credits, tolerated between two credit sums.
+const CREDIT_TOLERANCE: f32 = 0.1;
+
+/// Expected GenEd declared total minus its components (strands + electives).
+pub const GEN_ED_CREDIT_GAP: f32 = 0.0;
+
+/// Expected major declared total minus its components (basic science + core +
+/// standard capstone + electives). The shipped data sums to 83 of 96 credits
+/// because the declared total follows the published programme while the
+/// course lists are incomplete (see `get_major_curriculum`); the gap is
+/// recorded so any further drift is flagged.
+pub const MAJOR_CREDIT_GAP: f32 = 13.0;
+
+/// Declared GenEd total minus the sum of its strand and elective requirements.
+pub fn gen_ed_credit_gap(gen_ed: &GenEdCurriculum) -> f32 {
+    let components: f32 = gen_ed
+        .strands
+        .iter()
+        .map(|strand| strand.required_credits)
+        .sum::<f32>()
+        + gen_ed.electives.total_required_credits;
+    gen_ed.total_required_credits - components
+}
+
+/// Declared major total minus the sum of its basic science, core, standard
+/// capstone slot and elective requirements.
+pub fn major_credit_gap(major: &MajorCurriculum) -> f32 {
+    let components = major.basic_science.required_credits
+        + major.core_courses.required_credits
+        + major.capstone.credits_per_option
+        + major.electives.total_required_credits;
+    major.total_required_credits - components
+}
+
+/// Checks the curriculum data for internal inconsistencies and returns one
+/// warning per problem found (empty when the data is consistent).
+///
+/// Current checks:
+/// - every entry in a strand's `sequence_groups` is a pair (others are skipped by the audit)
+/// - every code in a strand's `sequence_groups` is listed in that strand's `courses`
+///   and earns credits (0-credit prerequisites are never pair members)
+/// - no elective cluster lists a required basic science or core code
+/// - no code is cross-listed in more than one GenEd elective sub-category
+/// - GenEd and major totals differ from their components by the expected gap
+pub fn validate_curriculum(gen_ed: &GenEdCurriculum, major: &MajorCurriculum) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    let gaps = [
+        ("GenEd", gen_ed_credit_gap(gen_ed), GEN_ED_CREDIT_GAP),
+        ("Major", major_credit_gap(major), MAJOR_CREDIT_GAP),
+    ];
+    for (curriculum, gap, expected) in gaps {
+        if (gap - expected).abs() > CREDIT_TOLERANCE {
+            warnings.push(format!(
+                "{}: declared total is {:.1} credits above its components (expected {:.1})",
+                curriculum, gap, expected
+            ));
+        }
+    }
+
+    for strand in &gen_ed.strands {
+        let strand_courses = strand.courses.as_deref().unwrap_or_default();
+        for group in strand.sequence_groups.iter().flatten() {
+            if group.len() != 2 {
+                warnings.push(format!(
+                    "Strand {} ({}): sequence group [{}] is not a pair and is ignored",
+                    strand.id,
+                    strand.name,
+                    group.join(", ")
+                ));
+            }
+        }
+        for code in strand.sequence_groups.iter().flatten().flatten() {
+            match strand_courses.iter().find(|c| &c.code == code) {
+                None => warnings.push(format!(
+                    "Strand {} ({}): sequence group code {} is not in the strand's courses",
+                    strand.id, strand.name, code
+                )),
+                Some(course) if course.credits <= 0.0 => warnings.push(format!(
+                    "Strand {} ({}): sequence group code {} carries no credits and its pair is ignored",
+                    strand.id, strand.name, code
+                )),
+                Some(_) => {}
+            }
+        }
+    }
+
+    // Required codes a cluster also lists; the cluster can never count them
+    let required_codes = major_required_codes(major);
+    for cluster in major.electives.domains.iter().flat_map(|d| &d.clusters) {
+        for course in &cluster.courses {
+            if required_codes.contains(&canonical_code(&course.code)) {
+                warnings.push(format!(
+                    "Cluster {} ({}): code {} is a required major course and never counts toward the cluster",
+                    cluster.id, cluster.name, course.code
+                ));
+            }
+        }
+    }
+
+    // First sub-category listing each elective code
+    let mut elective_homes: HashMap<String, &str> = HashMap::new();
+    for sub_cat in &gen_ed.electives.sub_categories {
+        for course in &sub_cat.courses {
+            match elective_homes.get(&canonical_code(&course.code)) {
+                Some(&first) if first != sub_cat.name => warnings.push(format!(
+                    "GenEd electives: code {} is listed in both {} and {}",
+                    course.code, first, sub_cat.name
+                )),
+                Some(_) => {}
+                None => {
+                    elective_homes.insert(canonical_code(&course.code), &sub_cat.name);
+                }
+            }
+        }
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{gen_ed::get_gen_ed_curriculum, major::get_major_curriculum};
+
+    #[test]
+    fn shipped_curricula_have_no_warnings() {
+        let warnings = validate_curriculum(&get_gen_ed_curriculum(), &get_major_curriculum());
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn sequence_code_missing_from_its_strand_is_flagged() {
+        let mut gen_ed = get_gen_ed_curriculum();
+        let strand = gen_ed
+            .strands
+            .iter_mut()
+            .find(|strand| strand.sequence_groups.is_some())
+            .expect("a strand with sequence groups");
+        strand.sequence_groups.as_mut().unwrap()[0][0] = "999-999".to_string();
+
+        let warnings = validate_curriculum(&gen_ed, &get_major_curriculum());
+
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(warnings[0].contains("999-999"));
+    }
+
+    #[test]
+    fn code_cross_listed_in_two_sub_categories_is_flagged() {
+        let mut gen_ed = get_gen_ed_curriculum();
+        let shared = gen_ed.electives.sub_categories[0].courses[0].clone();
+        gen_ed.electives.sub_categories[1]
+            .courses
+            .insert(0, shared.clone());
+
+        let warnings = validate_curriculum(&gen_ed, &get_major_curriculum());
+
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(warnings[0].contains(&shared.code));
+    }
+
+    #[test]
+    fn curriculum_totals_match_their_components() {
+        let gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+
+        assert!((gen_ed.total_required_credits - 30.0).abs() <= CREDIT_TOLERANCE);
+        assert!(gen_ed_credit_gap(&gen_ed).abs() <= CREDIT_TOLERANCE);
+
+        // Major components (12 + 56 + 3 + 12) fall 13 short of the declared 96
+        assert!((major.total_required_credits - 96.0).abs() <= CREDIT_TOLERANCE);
+        assert!(
+            (major.total_required_credits - major_credit_gap(&major) - 83.0).abs()
+                <= CREDIT_TOLERANCE
+        );
+        assert!((major_credit_gap(&major) - MAJOR_CREDIT_GAP).abs() <= CREDIT_TOLERANCE);
+
+        // The core's listed courses total 48 of its 56 required credits
+        let core_listed: f32 = major.core_courses.courses.iter().map(|c| c.credits).sum();
+        assert!(
+            (core_listed - 48.0).abs() <= CREDIT_TOLERANCE,
+            "{}",
+            core_listed
+        );
+        assert!((major.core_courses.required_credits - 56.0).abs() <= CREDIT_TOLERANCE);
+    }
+
+    #[test]
+    fn component_drift_is_flagged() {
+        let mut major = get_major_curriculum();
+        major.core_courses.required_credits += 3.0;
+
+        let warnings = validate_curriculum(&get_gen_ed_curriculum(), &major);
+
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(warnings[0].starts_with("Major:"));
+    }
+
+    #[test]
+    fn zero_credit_pair_member_is_flagged() {
+        let mut gen_ed = get_gen_ed_curriculum();
+        let strand = gen_ed
+            .strands
+            .iter_mut()
+            .find(|strand| strand.sequence_groups.is_some())
+            .expect("a strand with sequence groups");
+        strand
+            .sequence_groups
+            .as_mut()
+            .unwrap()
+            .push(vec!["890-101".to_string(), "890-102".to_string()]);
+
+        let warnings = validate_curriculum(&gen_ed, &get_major_curriculum());
+
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(warnings[0].contains("carries no credits"));
+    }
+
+    #[test]
+    fn core_code_listed_in_a_cluster_is_flagged() {
+        let mut major = get_major_curriculum();
+        let core = major.core_courses.courses[0].clone();
+        major.electives.domains[0].clusters[0]
+            .courses
+            .push(core.clone());
+
+        let warnings = validate_curriculum(&get_gen_ed_curriculum(), &major);
+
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(warnings[0].contains(&core.code) && warnings[0].contains("never counts"));
+    }
+}
@@ -13,30 +13,296 @@
 
 use leptos::*;
 use leptos_meta::*;
-use std::collections::HashSet;
-use wasm_bindgen::{closure::Closure, JsCast, JsValue};
-use web_sys::{DragEvent, Event, HtmlInputElement};
+use std::collections::HashMap;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{DragEvent, Event, HtmlInputElement, KeyboardEvent};
 
 mod components;
 mod data;
 mod logic;
 mod models;
 
-use crate::components::category_card::CategoryCard;
-use crate::data::{gen_ed::get_gen_ed_curriculum, major::get_major_curriculum};
+use crate::components::category_card::{
+    category_dom_id, progress_color_class, target_category, CategoryCard, CategoryExpansion,
+    CategoryFocus, ExpandedCategories,
+};
+use crate::components::category_order::{
+    load_category_order, move_item, ordered_categories, save_category_order, MoveDirection,
+};
+use crate::components::cluster_board::{load_pinned_clusters, save_pinned_clusters, ClusterBoard};
+use crate::components::course_editor::CourseEditor;
+use crate::components::credit_breakdown::CreditBreakdown;
+use crate::components::faculty_groups::GenEdElectivesByFaculty;
+use crate::components::graduation_report::GraduationReport;
+use crate::components::notice_panel::{NoticeItem, NoticePanel, NoticeTone};
+use crate::components::requirements_table::RequirementsTable;
+use crate::components::shared_summary::SharedSummaryView;
+use crate::components::substitution_editor::{
+    load_substitutions, save_substitutions, SubstitutionEditor,
+};
+use crate::components::term_planner::TermPlanner;
+use crate::components::toast::{ToastHost, ToastKind, ToastQueue, Toasts};
+use crate::components::viewport::use_compact_layout;
+use crate::data::{
+    demo::{DEMO_FILE_NAME, DEMO_TRANSCRIPT},
+    gen_ed::get_gen_ed_curriculum,
+    major::get_major_curriculum,
+};
 use crate::logic::{
-    auditor::{audit_gen_ed, audit_major, calculate_free_electives},
-    parser::{extract_text_from_pdf, parse_transcript},
+    advisory::{
+        credit_surpluses, gen_ed_faculty_conflicts, graduation_status, program_status,
+        ProgramStatus, ProgramStatusThresholds, DEGREE_MIN_CREDITS, MAJOR_FACULTY_PREFIXES,
+        MIN_GRADUATION_GPA, WEAK_GRADE_THRESHOLD,
+    },
+    anonymize::{anonymize_result, AnonymizePolicy, ANONYMIZED_RESULT_FILE_NAME},
+    auditor::{audit_transcript, curriculum_codes, lookup_course, recompute_free_electives},
+    checklist::{missing_to_markdown, CHECKLIST_FILE_NAME},
+    debug::{debug_log, debug_logging_enabled, debug_requested, set_debug_logging},
+    error::AuditError,
+    export::{
+        parsed_courses_to_csv, parsed_courses_to_json, PARSED_COURSES_CSV_FILE_NAME,
+        PARSED_COURSES_JSON_FILE_NAME,
+    },
+    file_io::read_file_bytes,
+    history::EditHistory,
+    parser::{extract_text_from_pdf, merge_transcripts, parse_transcript, ParseOptions},
+    progress::{AnalysisStage, AnalysisState},
+    saved::{load_result, save_result, SAVED_RESULT_FILE_NAME},
+    schema::{print_curriculum_schema, CURRICULUM_SCHEMA_FILE_NAME},
+    share::{audit_to_text_summary, decode_summary, encode_summary, SHARE_FRAGMENT_PREFIX},
+    upload::{check_upload, UploadKind, MAX_UPLOAD_MB},
+    validate::validate_curriculum,
 };
 use crate::models::{
-    free_elective_dedupe_key, is_passing_grade, AuditResult, Category, Course, MissingCourse,
+    format_credits, format_percent, grade_point_totals, progress_percent, AuditOptions,
+    AuditResult, CourseCode, CreditSource, DisplayLang, ElectivePolicy, ExclusionReason,
+    ParsedCourse, LOW_COVERAGE_PERCENT,
 };
 
 fn main() {
     console_error_panic_hook::set_once();
+
+    let search = web_sys::window()
+        .and_then(|window| window.location().search().ok())
+        .unwrap_or_default();
+    set_debug_logging(debug_requested(&search));
+
+    // Surface curriculum data slips to maintainers in the browser console
+    for warning in validate_curriculum(&get_gen_ed_curriculum(), &get_major_curriculum()) {
+        logging::warn!("Curriculum: {}", warning);
+    }
+
     mount_to_body(|| view! { <App/> })
 }
 
+/// Returns the display name of a top-level category in the selected language.
+fn category_label(name: &str, is_thai: bool) -> String {
+    match name {
+        "General Education" if is_thai => "หมวดวิชาศึกษาทั่วไป".to_string(),
+        "Major Courses" if is_thai => "หมวดวิชาเฉพาะ".to_string(),
+        "Free Electives" if is_thai => "หมวดวิชาเลือกเสรี".to_string(),
+        _ => name.to_string(),
+    }
+}
+
+/// Screen-reader announcement for a finished audit, e.g. "Audit complete:
+/// 112 of 132 credits, 3 requirements remaining".
+fn audit_announcement(result: &AuditResult, is_thai: bool) -> String {
+    let required: f32 = result.categories.iter().map(|c| c.required_credits).sum();
+    let earned = format_credits(result.total_credits);
+    let required = format_credits(required);
+    let remaining = result.missing_subjects.len();
+    if is_thai {
+        format!(
+            "ตรวจสอบเสร็จแล้ว: {} จาก {} หน่วยกิต เหลือ {} ข้อกำหนด",
+            earned, required, remaining
+        )
+    } else {
+        format!(
+            "Audit complete: {} of {} credits, {} requirement{} remaining",
+            earned,
+            required,
+            remaining,
+            if remaining == 1 { "" } else { "s" }
+        )
+    }
+}
+
+/// Maps an `AuditError` to the user-facing message in the selected language.
+fn error_message(err: &AuditError, is_thai: bool) -> String {
+    let message = match err {
+        AuditError::FileRead if is_thai => "ไม่สามารถอ่านไฟล์ที่อัปโหลดได้",
+        AuditError::FileRead => "Failed to read the uploaded file.",
+        AuditError::FileTooLarge(max_mb) => {
+            return if is_thai {
+                format!("ไฟล์มีขนาดใหญ่เกิน {} MB", max_mb)
+            } else {
+                format!("The file is larger than {} MB.", max_mb)
+            };
+        }
+        AuditError::UnsupportedFileType if is_thai => {
+            "ไม่รองรับไฟล์ประเภทนี้ กรุณาเลือกไฟล์ PDF ใบแสดงผลการเรียน (หรือไฟล์ผลลัพธ์ .json ที่บันทึกไว้)"
+        }
+        AuditError::UnsupportedFileType => {
+            "This file type isn't supported. Choose a transcript PDF (or a saved .json result)."
+        }
+        AuditError::PdfTextEmpty if is_thai => {
+            "ไม่สามารถดึงข้อความจาก PDF กรุณาตรวจสอบว่าเป็นใบแสดงผลการเรียนที่ถูกต้อง"
+        }
+        AuditError::PdfTextEmpty => {
+            "Could not extract text from the PDF. Make sure it's a valid transcript."
+        }
+        AuditError::PastedTextEmpty if is_thai => "กรุณาวางข้อความผลการเรียนก่อน",
+        AuditError::PastedTextEmpty => "Paste your grades from the registration portal first.",
+        AuditError::NoCoursesParsed if is_thai => {
+            "ไม่พบรายวิชาในใบแสดงผลการเรียน กรุณาตรวจสอบว่าเป็นไฟล์ที่ถูกต้อง"
+        }
+        AuditError::NoCoursesParsed => {
+            "No courses were found in the transcript. Make sure it's a PSU transcript."
+        }
+        AuditError::CurriculumParse(_) if is_thai => "ข้อมูลหลักสูตรไม่ถูกต้อง",
+        AuditError::CurriculumParse(_) => "The curriculum data is invalid.",
+        AuditError::Interop(_) if is_thai => "การดึงข้อมูล PDF ล้มเหลว ไฟล์อาจเสียหายหรือถูกเข้ารหัส",
+        AuditError::Interop(_) => "PDF extraction failed. The file may be corrupted or encrypted.",
+        AuditError::UnsupportedSchemaVersion(_) if is_thai => {
+            "ไฟล์ผลลัพธ์นี้บันทึกจากระบบเวอร์ชันอื่น ไม่สามารถเปิดได้ กรุณาวิเคราะห์ใบแสดงผลการเรียนใหม่"
+        }
+        AuditError::UnsupportedSchemaVersion(_) => {
+            "This saved result was made by a different version of the app and can't be opened. Please analyze the transcript again."
+        }
+        AuditError::SavedResultInvalid(_) if is_thai => "ไฟล์ผลลัพธ์ไม่ถูกต้องหรือเสียหาย",
+        AuditError::SavedResultInvalid(_) => "The saved result file is invalid or damaged.",
+    };
+    message.to_string()
+}
+
+/// Rejects a selected file that is too large or of the wrong type, before
+/// anything is read into memory.
+fn validate_upload(file: &web_sys::File, kind: UploadKind) -> Result<(), AuditError> {
+    check_upload(&file.name(), &file.type_(), file.size(), kind)
+}
+
+/// Offers `contents` as a file download named `file_name`. Returns `false`
+/// when the browser could not create the download.
+fn download_text(contents: &str, file_name: &str) -> bool {
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let Ok(blob) = web_sys::Blob::new_with_str_sequence(&parts) else {
+        return false;
+    };
+    let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) else {
+        return false;
+    };
+    let link = html::a()
+        .attr("href", url.clone())
+        .attr("download", file_name.to_string());
+    link.click();
+    let _ = web_sys::Url::revoke_object_url(&url);
+    true
+}
+
+/// Runs the full analysis flow for one or more uploaded transcripts:
+/// read files → extract PDF text → parse courses → merge → audit against the
+/// curriculum. Each step runs over every file before the next starts, and
+/// `on_stage` is called as it begins. Returns the audit result and the merged
+/// course list.
+async fn analyze_files(
+    files: Vec<web_sys::File>,
+    ocr_correction: bool,
+    audit_options: AuditOptions,
+    on_stage: impl Fn(AnalysisStage),
+) -> Result<(AuditResult, Vec<ParsedCourse>), AuditError> {
+    use wasm_bindgen_futures::JsFuture;
+
+    let gen_ed = get_gen_ed_curriculum();
+    let major = get_major_curriculum();
+
+    let options = ParseOptions {
+        ocr_known_codes: ocr_correction.then(|| curriculum_codes(&gen_ed, &major)),
+        ..Default::default()
+    };
+
+    on_stage(AnalysisStage::ReadingFile);
+    let mut contents = Vec::new();
+    for file in &files {
+        contents.push(
+            read_file_bytes(file.clone())
+                .await
+                .map_err(|_| AuditError::FileRead)?,
+        );
+    }
+
+    on_stage(AnalysisStage::ExtractingText);
+    let mut texts = Vec::new();
+    for bytes in contents {
+        let text_value = JsFuture::from(extract_text_from_pdf(bytes))
+            .await
+            .map_err(|e| AuditError::Interop(e.as_string().unwrap_or_default()))?;
+        texts.push(text_value.as_string().ok_or(AuditError::PdfTextEmpty)?);
+    }
+
+    on_stage(AnalysisStage::ParsingCourses);
+    let mut transcripts = Vec::new();
+    for (file, text) in files.iter().zip(&texts) {
+        let courses = parse_transcript(text, &options)?;
+        debug_log(|| format!("{}: {} courses", file.name(), courses.len()));
+        transcripts.push(courses);
+    }
+    let courses = merge_transcripts(transcripts);
+    debug_log(|| format!("merged transcripts: {} courses", courses.len()));
+
+    on_stage(AnalysisStage::Auditing);
+    Ok((
+        audit_transcript(&courses, &gen_ed, &major, &audit_options),
+        courses,
+    ))
+}
+
+/// Reads a result file saved by "Save results" and checks its schema version.
+async fn open_saved_result(file: web_sys::File) -> Result<AuditResult, AuditError> {
+    let bytes = read_file_bytes(file)
+        .await
+        .map_err(|_| AuditError::FileRead)?;
+    let json = String::from_utf8(bytes.to_vec())
+        .map_err(|e| AuditError::SavedResultInvalid(e.to_string()))?;
+    load_result(&json)
+}
+
+/// Runs the analysis flow on the bundled demo transcript. The text is parsed
+/// directly, skipping file reading and PDF extraction.
+fn analyze_demo(
+    audit_options: AuditOptions,
+) -> Result<(AuditResult, Vec<ParsedCourse>), AuditError> {
+    let gen_ed = get_gen_ed_curriculum();
+    let major = get_major_curriculum();
+
+    let courses = parse_transcript(DEMO_TRANSCRIPT, &ParseOptions::default())?;
+
+    Ok((
+        audit_transcript(&courses, &gen_ed, &major, &audit_options),
+        courses,
+    ))
+}
+
+/// Runs the analysis flow on grades pasted from the registration portal.
+/// Like the demo, the text is parsed directly without PDF extraction.
+fn analyze_pasted_text(
+    text: &str,
+    audit_options: AuditOptions,
+) -> Result<(AuditResult, Vec<ParsedCourse>), AuditError> {
+    if text.trim().is_empty() {
+        return Err(AuditError::PastedTextEmpty);
+    }
+    let gen_ed = get_gen_ed_curriculum();
+    let major = get_major_curriculum();
+
+    let courses = parse_transcript(text, &ParseOptions::default())?;
+
+    Ok((
+        audit_transcript(&courses, &gen_ed, &major, &audit_options),
+        courses,
+    ))
+}
+
 /// Main application component
 ///
 /// Manages state for file upload, PDF preview, audit results, and loading state.
@@ -46,18 +312,256 @@ fn App() -> impl IntoView {
     provide_meta_context();
 
     // State management for file upload and audit processing
-    let (file_name, set_file_name) = create_signal(String::new());
     let (preview_url, set_preview_url) = create_signal(Option::<String>::None);
     let (audit_result, set_audit_result) = create_signal(Option::<AuditResult>::None);
     let (is_loading, set_is_loading) = create_signal(false);
+    // Step of the running analysis, shown under the spinner; None when idle
+    let (analysis_stage, set_analysis_stage) = create_signal(None::<AnalysisStage>);
     let (error_msg, set_error_msg) = create_signal(Option::<String>::None);
-    // Stores the file from drag-and-drop (file input is not updated by drop events)
-    let (dropped_file, set_dropped_file) = create_signal(Option::<web_sys::File>::None);
+    // Stores the selected files (from the input or drag-and-drop) so a failed
+    // analysis can be retried without re-selecting them. Several transcripts
+    // (e.g. for transfer students) are merged into a single audit.
+    let (selected_files, set_selected_files) = create_signal(Vec::<web_sys::File>::new());
+    // Number of courses in the merged transcripts of the last successful analysis
+    let (course_count, set_course_count) = create_signal(Option::<usize>::None);
+    // Course list behind the current result, including manually added courses
+    let (parsed_courses, set_parsed_courses) = create_signal(Vec::<ParsedCourse>::new());
+    // Course lists before each manual edit, for "Undo"
+    let course_history = create_rw_signal(EditHistory::<Vec<ParsedCourse>>::default());
+    // Set while the result comes from the bundled demo transcript
+    let (file_name, set_file_name) = create_signal(Option::<String>::None);
+    // Repair OCR digit confusions (e.g. 344-1O1) for scanned transcripts
+    let (ocr_correction, set_ocr_correction) = create_signal(false);
+    // Audit policies (e.g. strict vs lenient elective clusters)
+    let (audit_options, set_audit_options) = create_signal(AuditOptions {
+        substitutions: load_substitutions(),
+        pinned_clusters: load_pinned_clusters(),
+        degree_min_credits: Some(DEGREE_MIN_CREDITS),
+        ..Default::default()
+    });
 
     // Language toggle: true = Thai (default), false = English
     let (is_thai, set_is_thai) = create_signal(true);
     provide_context(is_thai);
 
+    // Course-name language: transcript (Thai) names or curriculum English names
+    let (display_lang, set_display_lang) = create_signal(DisplayLang::Thai);
+    // List withdrawn (W) enrollments in their own informational panel
+    let (show_withdrawn, set_show_withdrawn) = create_signal(false);
+    // Student-chosen category display order (names), kept in localStorage
+    let (category_order, set_category_order) = create_signal(load_category_order());
+    // Course details as the dense requirements table instead of category cards
+    let (show_requirements_table, set_show_requirements_table) = create_signal(false);
+    provide_context(display_lang);
+
+    // Transient feedback (saved, failed, …) shown as auto-dismissing toasts
+    let toasts = Toasts(create_rw_signal(ToastQueue::default()));
+    provide_context(toasts);
+
+    // Phone-sized viewport: condensed progress rows and shorter placeholders
+    let compact = use_compact_layout();
+    let placeholder_height = move || {
+        if compact.get() {
+            "min-h-[280px]"
+        } else {
+            "h-full min-h-[500px]"
+        }
+    };
+
+    // Expanded category cards, shared so all can be expanded/collapsed at once
+    let category_expansion = create_rw_signal(ExpandedCategories::default());
+    provide_context(CategoryExpansion(category_expansion));
+
+    // Missing-requirement clicks expand the matching category card and scroll to it
+    let category_focus = create_rw_signal(Option::<String>::None);
+    provide_context(CategoryFocus(category_focus));
+    let jump_to_category = move |category: &str| {
+        category_focus.set(Some(category.to_string()));
+        if let Some(card) = document().get_element_by_id(&category_dom_id(category)) {
+            card.scroll_into_view();
+        }
+    };
+
+    // Read-only summary decoded from a `#s=<payload>` share link, if present.
+    // Outer Option: link present; inner Option: payload decoded successfully.
+    let shared_summary = web_sys::window()
+        .and_then(|w| w.location().hash().ok())
+        .and_then(|hash| hash.strip_prefix(SHARE_FRAGMENT_PREFIX).map(decode_summary));
+    let (share_copied, set_share_copied) = create_signal(false);
+    let (summary_copied, set_summary_copied) = create_signal(false);
+    // Shows the printable advising report in place of the results dashboard
+    let (show_report, set_show_report) = create_signal(false);
+
+    // Copy a privacy-safe progress link (no course data) to the clipboard
+    let on_share = move |_| {
+        let (Some(result), Some(window)) = (audit_result.get_untracked(), web_sys::window()) else {
+            return;
+        };
+        let location = window.location();
+        let link = format!(
+            "{}{}{}{}",
+            location.origin().unwrap_or_default(),
+            location.pathname().unwrap_or_default(),
+            SHARE_FRAGMENT_PREFIX,
+            encode_summary(&result)
+        );
+        let _ = window.navigator().clipboard().write_text(&link);
+        set_share_copied.set(true);
+        spawn_local(async move {
+            gloo_timers::future::TimeoutFuture::new(2000).await;
+            set_share_copied.set(false);
+        });
+    };
+
+    // Copy a short plain-text summary (credits, GPA, per-category) for chats
+    let on_copy_summary = move |_| {
+        let (Some(result), Some(window)) = (audit_result.get_untracked(), web_sys::window()) else {
+            return;
+        };
+        let summary = audit_to_text_summary(&result, result.gpa);
+        let _ = window.navigator().clipboard().write_text(&summary);
+        set_summary_copied.set(true);
+        spawn_local(async move {
+            gloo_timers::future::TimeoutFuture::new(2000).await;
+            set_summary_copied.set(false);
+        });
+    };
+
+    // Download the full result as a versioned JSON file
+    let on_save_result = move |_| {
+        let Some(result) = audit_result.get_untracked() else {
+            return;
+        };
+        if download_text(&save_result(&result), SAVED_RESULT_FILE_NAME) {
+            toasts.show(
+                ToastKind::Success,
+                if is_thai.get_untracked() {
+                    "บันทึกผลลัพธ์แล้ว"
+                } else {
+                    "Results saved"
+                },
+            );
+        }
+    };
+
+    // Download a copy for advisors with elective course names hidden
+    let on_save_anonymized = move |_| {
+        let Some(result) = audit_result.get_untracked() else {
+            return;
+        };
+        let anonymized = anonymize_result(&result, &AnonymizePolicy::default());
+        if download_text(&save_result(&anonymized), ANONYMIZED_RESULT_FILE_NAME) {
+            toasts.show(
+                ToastKind::Success,
+                if is_thai.get_untracked() {
+                    "บันทึกผลลัพธ์แบบไม่ระบุวิชาเลือกแล้ว"
+                } else {
+                    "Anonymized results saved"
+                },
+            );
+        }
+    };
+
+    // Download the missing requirements as a markdown checklist
+    let on_download_checklist = move |_| {
+        if let Some(result) = audit_result.get_untracked() {
+            download_text(&missing_to_markdown(&result), CHECKLIST_FILE_NAME);
+        }
+    };
+
+    // Download the raw parsed courses, to check what the parser extracted
+    let on_export_parsed_json = move |_| {
+        download_text(
+            &parsed_courses_to_json(&parsed_courses.get_untracked()),
+            PARSED_COURSES_JSON_FILE_NAME,
+        );
+    };
+    let on_export_parsed_csv = move |_| {
+        download_text(
+            &parsed_courses_to_csv(&parsed_courses.get_untracked()),
+            PARSED_COURSES_CSV_FILE_NAME,
+        );
+    };
+
+    // Download the curriculum JSON Schema (debug mode only)
+    let on_export_schema = move |_| {
+        download_text(&print_curriculum_schema(), CURRICULUM_SCHEMA_FILE_NAME);
+    };
+
+    // Open a previously saved result. It carries no course list, so actions
+    // that re-audit courses are unavailable until a transcript is analyzed.
+    let on_open_saved = move |ev: Event| {
+        let input = ev
+            .target()
+            .and_then(|t| t.dyn_into::<HtmlInputElement>().ok());
+        let Some(input) = input else {
+            return;
+        };
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        input.set_value("");
+
+        set_error_msg.set(None);
+        set_show_report.set(false);
+        if let Err(err) = validate_upload(&file, UploadKind::SavedResult) {
+            let message = error_message(&err, is_thai.get_untracked());
+            toasts.show(ToastKind::Error, message.clone());
+            set_error_msg.set(Some(message));
+            return;
+        }
+        spawn_local(async move {
+            match open_saved_result(file).await {
+                Ok(result) => {
+                    set_file_name.set(None);
+                    set_course_count.set(None);
+                    set_parsed_courses.set(Vec::new());
+                    course_history.update(|history| history.clear());
+                    set_audit_result.set(Some(result));
+                }
+                Err(err) => {
+                    let message = error_message(&err, is_thai.get_untracked());
+                    toasts.show(ToastKind::Error, message.clone());
+                    set_error_msg.set(Some(message));
+                }
+            }
+        });
+    };
+
+    // Add files to the selection, skipping ones already selected (by name)
+    let add_files = move |files: Vec<web_sys::File>| {
+        let Some(last) = files.last().cloned() else {
+            return;
+        };
+        if let Some(err) = files
+            .iter()
+            .find_map(|file| validate_upload(file, UploadKind::Transcript).err())
+        {
+            let message = error_message(&err, is_thai.get_untracked());
+            toasts.show(ToastKind::Error, message.clone());
+            set_error_msg.set(Some(message));
+            return;
+        }
+        set_selected_files.update(|selected| {
+            for file in files {
+                if !selected.iter().any(|f| f.name() == file.name()) {
+                    selected.push(file);
+                }
+            }
+        });
+        set_course_count.set(None);
+        set_file_name.set(None);
+
+        // Revoke the previous blob URL to avoid memory leak, then create a new one
+        if let Some(old_url) = preview_url.get_untracked() {
+            let _ = web_sys::Url::revoke_object_url(&old_url);
+        }
+        // Create blob URL for PDF preview display
+        if let Ok(url) = web_sys::Url::create_object_url_with_blob(&last) {
+            set_preview_url.set(Some(url));
+        }
+    };
+
     // Handle file selection from input field
     let on_file_change = move |ev: Event| {
         let input = ev
@@ -65,23 +569,43 @@ fn App() -> impl IntoView {
             .and_then(|t| t.dyn_into::<HtmlInputElement>().ok());
         if let Some(input) = input {
             if let Some(files) = input.files() {
-                if let Some(file) = files.get(0) {
-                    set_file_name.set(file.name());
-                    set_dropped_file.set(None); // file input takes precedence; clear any prior drop
-
-                    // Revoke the previous blob URL to avoid memory leak, then create a new one
-                    if let Some(old_url) = preview_url.get() {
-                        let _ = web_sys::Url::revoke_object_url(&old_url);
-                    }
-                    // Create blob URL for PDF preview display
-                    if let Ok(url) = web_sys::Url::create_object_url_with_blob(&file) {
-                        set_preview_url.set(Some(url));
-                    }
-                }
+                add_files((0..files.length()).filter_map(|i| files.get(i)).collect());
+                // Reset so re-selecting the same file fires `change` again
+                input.set_value("");
             }
         }
     };
 
+    // Keyboard access: Enter/Space on the focused drop zone opens the file
+    // picker, and a global "u" shortcut (either case) focuses the drop zone
+    let drop_zone_ref = create_node_ref::<html::Div>();
+    let file_input_ref = create_node_ref::<html::Input>();
+    let on_drop_zone_keydown = move |ev: KeyboardEvent| {
+        if matches!(ev.key().as_str(), "Enter" | " ") {
+            ev.prevent_default();
+            if let Some(input) = file_input_ref.get() {
+                input.click();
+            }
+        }
+    };
+    let _ = window_event_listener(ev::keydown, move |ev| {
+        let typing = ev
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+            .is_some_and(|el| matches!(el.tag_name().as_str(), "INPUT" | "SELECT" | "TEXTAREA"));
+        if ev.key().eq_ignore_ascii_case("u")
+            && !typing
+            && !ev.ctrl_key()
+            && !ev.meta_key()
+            && !ev.alt_key()
+        {
+            if let Some(drop_zone) = drop_zone_ref.get() {
+                ev.prevent_default();
+                let _ = drop_zone.focus();
+            }
+        }
+    });
+
     // Handle drag over
     let on_drag_over = move |ev: DragEvent| {
         ev.prevent_default();
@@ -93,257 +617,283 @@ fn App() -> impl IntoView {
 
         if let Some(data_transfer) = ev.data_transfer() {
             if let Some(files) = data_transfer.files() {
-                if let Some(file) = files.get(0) {
-                    if !file.name().to_lowercase().ends_with(".pdf") {
-                        set_error_msg.set(Some(if is_thai.get_untracked() {
-                            "กรุณาอัปโหลดไฟล์ PDF เท่านั้น".to_string()
-                        } else {
-                            "Please upload a PDF file.".to_string()
-                        }));
-                        return;
-                    }
-                    let file = web_sys::File::from(file);
-                    set_file_name.set(file.name());
-                    set_dropped_file.set(Some(file.clone()));
-
-                    // Revoke the previous blob URL to avoid memory leak, then create a new one
-                    if let Some(old_url) = preview_url.get() {
-                        let _ = web_sys::Url::revoke_object_url(&old_url);
-                    }
-                    // Create blob URL for PDF preview
-                    if let Ok(url) = web_sys::Url::create_object_url_with_blob(&file) {
-                        set_preview_url.set(Some(url));
-                    }
-                }
+                add_files((0..files.length()).filter_map(|i| files.get(i)).collect());
             }
         }
     };
 
-    // Handle start analysis
+    // Handle start analysis (also used by "Try again" after a failure; the
+    // selected files are kept so no re-upload is needed)
+    let analysis_state = move || AnalysisState {
+        selected_files: selected_files.get_untracked(),
+        is_loading: is_loading.get_untracked(),
+        error_msg: error_msg.get_untracked(),
+        stage: analysis_stage.get_untracked(),
+    };
+    let set_analysis_state = move |state: AnalysisState<web_sys::File>| {
+        set_is_loading.set(state.is_loading);
+        set_error_msg.set(state.error_msg);
+        set_analysis_stage.set(state.stage);
+    };
     let on_start_analysis = move |_| {
-        if file_name.get().is_empty() {
+        let mut state = analysis_state();
+        if !state.start() {
             return;
         }
+        let files = state.selected_files.clone();
+        set_analysis_state(state);
 
-        set_is_loading.set(true);
+        set_audit_result.set(None);
+        set_show_report.set(false);
+        set_course_count.set(None);
+        set_file_name.set(None);
+
+        spawn_local(async move {
+            let outcome = analyze_files(
+                files,
+                ocr_correction.get_untracked(),
+                audit_options.get_untracked(),
+                move |stage| set_analysis_stage.set(Some(stage)),
+            )
+            .await;
+            let mut state = analysis_state();
+            match outcome {
+                Ok((result, courses)) => {
+                    state.finish(None);
+                    set_audit_result.set(Some(result));
+                    set_course_count.set(Some(courses.len()));
+                    set_parsed_courses.set(courses);
+                    course_history.update(|history| history.clear());
+                }
+                Err(err) => {
+                    let message = error_message(&err, is_thai.get_untracked());
+                    toasts.show(ToastKind::Error, message.clone());
+                    state.finish(Some(message));
+                }
+            }
+            set_analysis_state(state);
+        });
+    };
+
+    // Load the bundled sample transcript and audit it, so the tool can be
+    // tried without a PDF
+    let on_try_demo = move |_| {
         set_audit_result.set(None);
         set_error_msg.set(None);
+        set_show_report.set(false);
+
+        match analyze_demo(audit_options.get_untracked()) {
+            Ok((result, courses)) => {
+                set_file_name.set(Some(DEMO_FILE_NAME.to_string()));
+                set_audit_result.set(Some(result));
+                set_course_count.set(Some(courses.len()));
+                set_parsed_courses.set(courses);
+                course_history.update(|history| history.clear());
+            }
+            Err(err) => {
+                let message = error_message(&err, is_thai.get_untracked());
+                toasts.show(ToastKind::Error, message.clone());
+                set_error_msg.set(Some(message));
+            }
+        }
+    };
+
+    // Audit grades pasted as text instead of an uploaded PDF
+    let (pasted_text, set_pasted_text) = create_signal(String::new());
+    let on_parse_pasted = move |_| {
+        set_error_msg.set(None);
+        set_show_report.set(false);
+
+        match analyze_pasted_text(&pasted_text.get_untracked(), audit_options.get_untracked()) {
+            Ok((result, courses)) => {
+                set_file_name.set(None);
+                set_course_count.set(None);
+                set_audit_result.set(Some(result));
+                set_parsed_courses.set(courses);
+                course_history.update(|history| history.clear());
+            }
+            Err(err) => {
+                let message = error_message(&err, is_thai.get_untracked());
+                toasts.show(ToastKind::Error, message.clone());
+                set_error_msg.set(Some(message));
+            }
+        }
+    };
+
+    // Add a manual course: a course with a curriculum code may satisfy a
+    // requirement and needs the full audit; anything else can only be a free
+    // elective, so only that category is recomputed
+    let on_add_course = move |mut course: ParsedCourse| {
+        let gen_ed = get_gen_ed_curriculum();
+        let major = get_major_curriculum();
+        let known = lookup_course(&course.code, &gen_ed, &major);
+        let options = audit_options.get_untracked();
+        let matches_curriculum = known.is_some()
+            || options.substitution_for(&course.code).is_some()
+            || options
+                .minor
+                .as_ref()
+                .is_some_and(|minor| minor.lists(&course.code));
+        // A blank name is filled in from the curriculum when the code is known
+        if let Some(known) = known.filter(|_| course.name.is_empty()) {
+            course.name = known.name;
+        }
+
+        course_history.update(|history| history.push(parsed_courses.get_untracked()));
+        set_parsed_courses.update(|courses| courses.push(course));
+        let courses = parsed_courses.get_untracked();
+        if matches_curriculum {
+            set_audit_result.set(Some(audit_transcript(
+                &courses,
+                &gen_ed,
+                &major,
+                &audit_options.get_untracked(),
+            )));
+        } else {
+            set_audit_result.update(|result| {
+                if let Some(result) = result {
+                    recompute_free_electives(
+                        result,
+                        &courses,
+                        &gen_ed,
+                        &major,
+                        &audit_options.get_untracked(),
+                    );
+                }
+            });
+        }
+    };
+
+    // Restore the course list from before the last manual edit
+    let on_undo_edit = move |_| {
+        let mut previous = None;
+        course_history.update(|history| previous = history.undo());
+        let Some(courses) = previous else {
+            return;
+        };
+        set_audit_result.set(Some(audit_transcript(
+            &courses,
+            &get_gen_ed_curriculum(),
+            &get_major_curriculum(),
+            &audit_options.get_untracked(),
+        )));
+        set_parsed_courses.set(courses);
+    };
 
-        // Prefer file from drag-and-drop signal; fall back to the file-input element.
-        // (drop events do not update the <input type="file"> files list)
-        let file_opt = dropped_file.get().or_else(|| {
-            web_sys::window()
-                .ok_or(())
-                .and_then(|w| w.document().ok_or(()))
-                .and_then(|d| {
-                    d.get_element_by_id("file-input")
-                        .ok_or(())
-                        .and_then(|e| e.dyn_into::<HtmlInputElement>().ok().ok_or(()))
-                })
-                .ok()
-                .and_then(|input| input.files())
-                .and_then(|files| files.get(0))
-                .map(web_sys::File::from)
+    // Re-audit the current courses after an audit option changed; nothing to
+    // do until a transcript has been audited
+    let reaudit = move || {
+        if audit_result.get_untracked().is_some() && !parsed_courses.get_untracked().is_empty() {
+            set_audit_result.set(Some(audit_transcript(
+                &parsed_courses.get_untracked(),
+                &get_gen_ed_curriculum(),
+                &get_major_curriculum(),
+                &audit_options.get_untracked(),
+            )));
+        }
+    };
+
+    // Switch the elective-cluster policy and re-audit the current courses
+    let on_elective_policy_change = move |strict: bool| {
+        set_audit_options.update(|options| {
+            options.elective_policy = if strict {
+                ElectivePolicy::Strict
+            } else {
+                ElectivePolicy::Lenient
+            };
         });
+        reaudit();
+    };
 
-        if let Some(file) = file_opt {
-            spawn_local(async move {
-                use wasm_bindgen_futures::JsFuture;
-                use web_sys::FileReader;
-
-                        let reader = FileReader::new().unwrap();
-
-                        let promise = js_sys::Promise::new(&mut |resolve, reject| {
-                            let reader_clone = reader.clone();
-                            let reject_clone = reject.clone();
-                            let onload = Closure::once(move |_event: web_sys::Event| {
-                                if let Ok(result) = reader_clone.result() {
-                                    resolve.call1(&JsValue::NULL, &result).unwrap();
-                                } else {
-                                    reject_clone
-                                        .call1(
-                                            &JsValue::NULL,
-                                            &JsValue::from_str("Failed to read file"),
-                                        )
-                                        .unwrap();
-                                }
-                            });
-                            reader.set_onload(Some(onload.as_ref().unchecked_ref()));
-                            // SAFETY: Closure::forget leaks memory but is the standard
-                            // wasm-bindgen pattern for one-shot callbacks. Each analysis
-                            // leaks a small, bounded amount — acceptable for this use case.
-                            onload.forget();
-
-                            let onerror = Closure::once(move |_event: web_sys::Event| {
-                                reject
-                                    .call1(&JsValue::NULL, &JsValue::from_str("Error reading file"))
-                                    .unwrap();
-                            });
-                            reader.set_onerror(Some(onerror.as_ref().unchecked_ref()));
-                            onerror.forget(); // See onload.forget() comment above
-                        });
-
-                        if reader.read_as_array_buffer(&file).is_err() {
-                            set_is_loading.set(false);
-                            set_error_msg.set(Some(if is_thai.get_untracked() {
-                                "ไม่สามารถอ่านไฟล์ PDF ได้ กรุณาลองใหม่อีกครั้ง".to_string()
-                            } else {
-                                "Failed to read the PDF file. Please try again.".to_string()
-                            }));
-                            return;
-                        }
+    // Toggle treating in-progress (IP) courses as passed and re-audit
+    let on_assume_in_progress_change = move |assume: bool| {
+        set_audit_options.update(|options| options.assume_in_progress_pass = assume);
+        reaudit();
+    };
 
-                        // Wait for the file to be loaded
-                        match JsFuture::from(promise).await {
-                            Ok(result) => {
-                                let array_buffer = js_sys::ArrayBuffer::from(result);
-                                let uint8_array = js_sys::Uint8Array::new(&array_buffer);
-
-                                let promise = extract_text_from_pdf(uint8_array);
-                                match JsFuture::from(promise).await {
-                                    Ok(text_value) => {
-                                        if let Some(text) = text_value.as_string() {
-                                            let courses = parse_transcript(&text);
-
-                                            let gen_ed = get_gen_ed_curriculum();
-                                            let major = get_major_curriculum();
-
-                                            let (gen_ed_credits, gen_ed_missing, gen_ed_used) =
-                                                audit_gen_ed(&courses, &gen_ed);
-                                            let (
-                                                major_credits,
-                                                elective_credits,
-                                                major_missing,
-                                                major_used,
-                                            ) = audit_major(&courses, &major);
-
-                                            let mut all_used_courses = gen_ed_used.clone();
-                                            all_used_courses.extend(major_used.clone());
-
-                                            let (free_elective_credits, _free_elective_list) =
-                                                calculate_free_electives(
-                                                    &courses,
-                                                    &all_used_courses,
-                                                );
-
-                                            let mut all_missing: Vec<MissingCourse> =
-                                                gen_ed_missing;
-                                            all_missing.extend(major_missing);
-
-                                            // Drop missing entries for GenEd if total GenEd credits are already met.
-                                            // DO NOT drop Major Core/Basic Science misses, as they are strictly required regardless of total accumulated elective credits.
-                                            all_missing.retain(|m| match m.category.as_str() {
-                                                "General Education" => {
-                                                    gen_ed_credits < gen_ed.total_required_credits
-                                                }
-                                                _ => true,
-                                            });
-
-                                            let total_credits = gen_ed_credits
-                                                + major_credits
-                                                + elective_credits
-                                                + free_elective_credits;
-
-                                            let mut gen_ed_courses = Vec::new();
-                                            let mut major_courses = Vec::new();
-                                            let mut free_elective_courses = Vec::new();
-                                            let mut seen_free_electives: HashSet<String> =
-                                                HashSet::new();
-
-                                            for (idx, parsed) in courses.iter().enumerate() {
-                                                let course = Course {
-                                                    code: parsed.code.clone(),
-                                                    name: parsed.name.clone(),
-                                                    credit: parsed.parsed_credit,
-                                                    grade: parsed.grade.clone(),
-                                                };
-
-                                                if gen_ed_used.contains(&idx) {
-                                                    gen_ed_courses.push(course);
-                                                } else if major_used.contains(&idx) {
-                                                    major_courses.push(course);
-                                                } else if is_passing_grade(&parsed.grade) {
-                                                    let dedupe_key = free_elective_dedupe_key(
-                                                        &parsed.code,
-                                                        &parsed.name,
-                                                    );
-                                                    if seen_free_electives.insert(dedupe_key) {
-                                                        free_elective_courses.push(course);
-                                                    }
-                                                }
-                                            }
+    // Toggle whether P (pass) grades earn credits and re-audit
+    let on_p_counts_credits_change = move |counts: bool| {
+        set_audit_options.update(|options| options.passing_policy.p_counts_credits = counts);
+        reaudit();
+    };
 
-                                            let audit_result = AuditResult {
-                                                total_credits,
-                                                categories: vec![
-                                                    Category {
-                                                        name: "General Education".to_string(),
-                                                        required_credits: gen_ed
-                                                            .total_required_credits,
-                                                        collected_credits: gen_ed_credits,
-                                                        courses: gen_ed_courses,
-                                                    },
-                                                    Category {
-                                                        name: "Major Courses".to_string(),
-                                                        required_credits: major
-                                                            .total_required_credits,
-                                                        collected_credits: major_credits
-                                                            + elective_credits,
-                                                        courses: major_courses,
-                                                    },
-                                                    Category {
-                                                        name: "Free Electives".to_string(),
-                                                        required_credits: 6.0,
-                                                        collected_credits: free_elective_credits,
-                                                        courses: free_elective_courses,
-                                                    },
-                                                ],
-                                                missing_subjects: all_missing,
-                                            };
+    // Set the transcript code that marks a passed English exit test and
+    // re-audit; a blank or malformed code turns the check off
+    let on_english_exit_change = move |code: String| {
+        set_audit_options.update(|options| {
+            options.english_exit_marker = CourseCode::parse(&code).map(|code| code.to_string());
+        });
+        reaudit();
+    };
 
-                                            set_is_loading.set(false);
-                                            set_audit_result.set(Some(audit_result));
-                                        } else {
-                                            set_is_loading.set(false);
-                                            set_error_msg.set(Some(if is_thai.get_untracked() {
-                                                "ไม่สามารถดึงข้อความจาก PDF กรุณาตรวจสอบว่าเป็นใบแสดงผลการเรียนที่ถูกต้อง".to_string()
-                                            } else {
-                                                "Could not extract text from the PDF. Make sure it's a valid transcript.".to_string()
-                                            }));
-                                        }
-                                    }
-                                    Err(_e) => {
-                                        set_is_loading.set(false);
-                                        set_error_msg.set(Some(if is_thai.get_untracked() {
-                                            "การดึงข้อมูล PDF ล้มเหลว ไฟล์อาจเสียหายหรือถูกเข้ารหัส".to_string()
-                                        } else {
-                                            "PDF extraction failed. The file may be corrupted or encrypted.".to_string()
-                                        }));
-                                    }
-                                }
-                            }
-                            Err(_e) => {
-                                set_is_loading.set(false);
-                                set_error_msg
-                                    .set(Some(if is_thai.get_untracked() {
-                                        "ไม่สามารถอ่านไฟล์ที่อัปโหลดได้".to_string()
-                                    } else {
-                                        "Failed to read the uploaded file.".to_string()
-                                    }));
-                            }
-                        }
-                    });
+    // Toggle choosing duplicate rows by curriculum credit and re-audit
+    let on_prefer_curriculum_credit_change = move |prefer: bool| {
+        set_audit_options.update(|options| options.prefer_curriculum_credit = prefer);
+        reaudit();
+    };
+
+    // Choose where matched-course credits come from and re-audit
+    let on_credit_source_change = move |source: CreditSource| {
+        set_audit_options.update(|options| options.credit_source = source);
+        reaudit();
+    };
+
+    // Save the approved substitutions and re-audit the current courses
+    let on_substitutions_change = move |substitutions: HashMap<String, String>| {
+        save_substitutions(&substitutions);
+        set_audit_options.update(|options| options.substitutions = substitutions);
+        reaudit();
+    };
+
+    // Move a category card one place and remember the new order
+    let on_move_category = move |name: String, direction: MoveDirection| {
+        let Some(result) = audit_result.get_untracked() else {
+            return;
+        };
+        let mut names: Vec<String> =
+            ordered_categories(result.categories, &category_order.get_untracked())
+                .into_iter()
+                .map(|category| category.name)
+                .collect();
+        let Some(index) = names.iter().position(|n| n == &name) else {
+            return;
+        };
+        if move_item(&mut names, index, direction) {
+            save_category_order(&names);
+            set_category_order.set(names);
         }
     };
 
+    // Pin or unpin an elective cluster, save the pins and re-audit so the
+    // missing list targets the pinned clusters
+    let on_toggle_cluster_pin = move |cluster_id: String| {
+        set_audit_options.update(|options| {
+            if !options.pinned_clusters.remove(&cluster_id) {
+                options.pinned_clusters.insert(cluster_id);
+            }
+            save_pinned_clusters(&options.pinned_clusters);
+        });
+        reaudit();
+    };
+
     view! {
         <Stylesheet id="leptos" href="/pkg/course-audit-system.css"/>
         <Title text="Course Audit — PSU CS"/>
 
         <div class="min-h-screen font-sans text-zinc-900 flex flex-col selection:bg-brand-100">
+            <ToastHost toasts=toasts/>
+
+            // Announces finished audits and errors to screen readers
+            <div class="sr-only" role="status" aria-live="polite">
+                {move || match (error_msg.get(), audit_result.get()) {
+                    (Some(err), _) => err,
+                    (None, Some(result)) => audit_announcement(&result, is_thai.get()),
+                    (None, None) => String::new(),
+                }}
+            </div>
 
             // ── Navbar ──────────────────────────────────────────────────
-            <header class="sticky top-0 z-50 border-b border-zinc-200/60 bg-white/80 backdrop-blur-xl backdrop-saturate-150">
+            <header class="print:hidden sticky top-0 z-50 border-b border-zinc-200/60 bg-white/80 backdrop-blur-xl backdrop-saturate-150">
                 <div class="max-w-[1440px] mx-auto px-4 sm:px-6 lg:px-8 h-14 flex items-center justify-between">
                     <div class="flex items-center gap-3">
                         <div class="w-7 h-7 rounded-lg bg-brand-600 flex items-center justify-center">
@@ -373,7 +923,7 @@ fn App() -> impl IntoView {
             <div class="flex-1 flex flex-col lg:flex-row max-w-[1440px] w-full mx-auto p-4 sm:p-6 gap-5 animate-fade-in">
 
                 // ── Left Sidebar ────────────────────────────────────────
-                <aside class="w-full lg:w-[360px] shrink-0 flex flex-col gap-4">
+                <aside class="print:hidden w-full lg:w-[360px] shrink-0 flex flex-col gap-4">
 
                     // Upload Card
                     <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft p-5 flex flex-col gap-4">
@@ -384,15 +934,23 @@ fn App() -> impl IntoView {
 
                         // Drop zone
                         <div
-                            class="group relative border border-dashed border-zinc-300 rounded-xl p-5 text-center bg-zinc-50/50 hover:border-brand-400 hover:bg-brand-50/30 transition-all duration-200 cursor-pointer"
+                            class="group relative border border-dashed border-zinc-300 rounded-xl p-5 text-center bg-zinc-50/50 hover:border-brand-400 hover:bg-brand-50/30 transition-all duration-200 cursor-pointer outline-none focus-visible:ring-2 focus-visible:ring-brand-500 focus-visible:ring-offset-2"
+                            tabindex="0"
+                            role="button"
+                            aria-label={move || if is_thai.get() { "เลือกไฟล์ PDF (กด U)" } else { "Choose PDF files (press U)" }}
+                            node_ref=drop_zone_ref
+                            on:keydown=on_drop_zone_keydown
                             on:dragover=on_drag_over
                             on:drop=on_drop
                         >
                             <input
                                 type="file"
                                 accept="application/pdf"
+                                multiple
+                                tabindex="-1"
                                 class="absolute inset-0 w-full h-full opacity-0 cursor-pointer z-10"
                                 id="file-input"
+                                node_ref=file_input_ref
                                 on:change=on_file_change
                             />
                             <div class="flex flex-col items-center gap-2.5 pointer-events-none">
@@ -402,26 +960,165 @@ fn App() -> impl IntoView {
                                     </svg>
                                 </div>
                                 <div>
-                                    <p class="text-sm font-medium text-zinc-700 group-hover:text-brand-600 transition-colors">{move || if is_thai.get() { "วางไฟล์ PDF ที่นี่ หรือคลิกเพื่อเลือกไฟล์" } else { "Drop PDF here or click to browse" }}</p>
-                                    <p class="text-2xs text-zinc-400 mt-0.5">{move || if is_thai.get() { "รับเฉพาะไฟล์ .pdf เท่านั้น" } else { "Accepts .pdf files only" }}</p>
+                                    <p class="text-sm font-medium text-zinc-700 group-hover:text-brand-600 transition-colors">{move || if is_thai.get() { "วางไฟล์ PDF ที่นี่ หรือคลิกเพื่อเลือกไฟล์ (เลือกได้หลายไฟล์)" } else { "Drop PDFs here or click to browse" }}</p>
+                                    <p class="text-2xs text-zinc-400 mt-0.5">{move || if is_thai.get() { format!("รับเฉพาะไฟล์ .pdf ไม่เกิน {} MB · กด U เพื่อไปที่ช่องอัปโหลด", MAX_UPLOAD_MB) } else { format!("Accepts .pdf files up to {} MB · Press U to focus", MAX_UPLOAD_MB) }}</p>
                                 </div>
                             </div>
                         </div>
 
-                        // Selected file
-                        {move || (!file_name.get().is_empty()).then(|| view! {
-                            <div class="flex items-center gap-2.5 px-3 py-2.5 rounded-lg bg-emerald-50 border border-emerald-200/60 animate-scale-in">
-                                <svg class="w-4 h-4 text-emerald-500 shrink-0" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24">
-                                    <path stroke-linecap="round" stroke-linejoin="round" d="M9 12.75L11.25 15 15 9.75M21 12a9 9 0 11-18 0 9 9 0 0118 0z"/>
+                        // Selected files (several transcripts are merged into one audit)
+                        {move || selected_files.get().into_iter().map(|file| {
+                            let name = file.name();
+                            let remove_name = name.clone();
+                            view! {
+                                <div class="flex items-center gap-2.5 px-3 py-2.5 rounded-lg bg-emerald-50 border border-emerald-200/60 animate-scale-in">
+                                    <svg class="w-4 h-4 text-emerald-500 shrink-0" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24">
+                                        <path stroke-linecap="round" stroke-linejoin="round" d="M9 12.75L11.25 15 15 9.75M21 12a9 9 0 11-18 0 9 9 0 0118 0z"/>
+                                    </svg>
+                                    <p class="text-[13px] text-emerald-800 font-medium truncate flex-1">{name}</p>
+                                    <button
+                                        class="text-emerald-600/60 hover:text-emerald-800 text-sm leading-none"
+                                        title={move || if is_thai.get() { "นำไฟล์ออก" } else { "Remove file" }}
+                                        on:click=move |_| {
+                                            set_selected_files.update(|files| files.retain(|f| f.name() != remove_name));
+                                            set_course_count.set(None);
+                                        }
+                                    >
+                                        "×"
+                                    </button>
+                                </div>
+                            }
+                        }).collect::<Vec<_>>()}
+                        {move || file_name.get().map(|name| view! {
+                            <div class="flex items-center gap-2.5 px-3 py-2.5 rounded-lg bg-brand-50 border border-brand-200/60 animate-scale-in">
+                                <svg class="w-4 h-4 text-brand-500 shrink-0" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24">
+                                    <path stroke-linecap="round" stroke-linejoin="round" d="M19.5 14.25v-2.625a3.375 3.375 0 00-3.375-3.375h-1.5A1.125 1.125 0 0113.5 7.125v-1.5a3.375 3.375 0 00-3.375-3.375H8.25m2.25 0H5.625c-.621 0-1.125.504-1.125 1.125v17.25c0 .621.504 1.125 1.125 1.125h12.75c.621 0 1.125-.504 1.125-1.125V11.25a9 9 0 00-9-9z"/>
                                 </svg>
-                                <p class="text-[13px] text-emerald-800 font-medium truncate">{file_name.get()}</p>
+                                <p class="text-[13px] text-brand-700 font-medium truncate flex-1">{name}</p>
+                                <span class="text-2xs font-semibold text-brand-600">{move || if is_thai.get() { "ตัวอย่าง" } else { "Demo" }}</span>
                             </div>
                         })}
+                        {move || course_count.get().map(|count| {
+                            let file_count = if file_name.get().is_some() { 1 } else { selected_files.get().len() };
+                            view! {
+                                <p class="text-2xs text-zinc-500 -mt-2">
+                                    {move || if is_thai.get() {
+                                        format!("รวม {} วิชาจาก {} ไฟล์", count, file_count)
+                                    } else {
+                                        format!("{} courses combined from {} file(s)", count, file_count)
+                                    }}
+                                </p>
+                            }
+                        })}
+
+                        // OCR correction toggle (scanned PDFs)
+                        <label class="flex items-center gap-2 text-[13px] text-zinc-600 cursor-pointer select-none">
+                            <input
+                                type="checkbox"
+                                class="rounded border-zinc-300 text-brand-600"
+                                prop:checked=ocr_correction
+                                on:change=move |ev| set_ocr_correction.set(event_target_checked(&ev))
+                            />
+                            <span>{move || if is_thai.get() { "แก้รหัสวิชาที่สแกนผิด (PDF จากการสแกน)" } else { "Fix OCR code errors (scanned PDF)" }}</span>
+                        </label>
+
+                        // Elective-cluster policy toggle
+                        <label class="flex items-center gap-2 text-[13px] text-zinc-600 cursor-pointer select-none -mt-2">
+                            <input
+                                type="checkbox"
+                                class="rounded border-zinc-300 text-brand-600"
+                                prop:checked=move || audit_options.get().elective_policy == ElectivePolicy::Strict
+                                on:change=move |ev| on_elective_policy_change(event_target_checked(&ev))
+                            />
+                            <span>{move || if is_thai.get() { "นับกลุ่มวิชาเลือกแบบเข้มงวด" } else { "Strict elective clusters" }}</span>
+                        </label>
+
+                        // In-progress (IP) what-if toggle
+                        <label class="flex items-center gap-2 text-[13px] text-zinc-600 cursor-pointer select-none -mt-2">
+                            <input
+                                type="checkbox"
+                                class="rounded border-zinc-300 text-brand-600"
+                                prop:checked=move || audit_options.get().assume_in_progress_pass
+                                on:change=move |ev| on_assume_in_progress_change(event_target_checked(&ev))
+                            />
+                            <span>{move || if is_thai.get() { "สมมติว่าวิชาที่กำลังเรียนผ่านทั้งหมด" } else { "Assume current courses pass" }}</span>
+                        </label>
+
+                        // Pass (P) grade credit toggle
+                        <label class="flex items-center gap-2 text-[13px] text-zinc-600 cursor-pointer select-none -mt-2">
+                            <input
+                                type="checkbox"
+                                class="rounded border-zinc-300 text-brand-600"
+                                prop:checked=move || audit_options.get().passing_policy.p_counts_credits
+                                on:change=move |ev| on_p_counts_credits_change(event_target_checked(&ev))
+                            />
+                            <span>{move || if is_thai.get() { "นับหน่วยกิตวิชาที่ได้ P" } else { "Count credits for P (pass) grades" }}</span>
+                        </label>
+
+                        // English exit requirement: checked only once the marker code is entered
+                        <label class="flex flex-col gap-1 text-[13px] text-zinc-600 -mt-2">
+                            <span>{move || if is_thai.get() { "รหัสผลสอบภาษาอังกฤษก่อนจบในใบเกรด" } else { "English exit test code on your transcript" }}</span>
+                            <input
+                                type="text"
+                                class="w-full text-[13px] font-mono text-zinc-700 bg-white border border-zinc-200 rounded-lg px-2.5 py-1.5"
+                                placeholder=move || if is_thai.get() { "เว้นว่างเพื่อไม่ตรวจ" } else { "Leave blank to skip the check" }
+                                prop:value=move || audit_options.get().english_exit_marker.unwrap_or_default()
+                                on:change=move |ev| on_english_exit_change(event_target_value(&ev))
+                            />
+                        </label>
+
+                        // Withdrawn courses panel toggle
+                        <label class="flex items-center gap-2 text-[13px] text-zinc-600 cursor-pointer select-none -mt-2">
+                            <input
+                                type="checkbox"
+                                class="rounded border-zinc-300 text-brand-600"
+                                prop:checked=show_withdrawn
+                                on:change=move |ev| set_show_withdrawn.set(event_target_checked(&ev))
+                            />
+                            <span>{move || if is_thai.get() { "แสดงวิชาที่ถอน (W)" } else { "Show withdrawn courses" }}</span>
+                        </label>
+
+                        // Credit source when curriculum and transcript disagree
+                        <label class="flex items-center justify-between gap-2 text-[13px] text-zinc-600 -mt-2">
+                            <span>{move || if is_thai.get() { "หน่วยกิตที่นับ" } else { "Credits counted" }}</span>
+                            <select
+                                class="text-2xs font-medium text-zinc-600 bg-white border border-zinc-200 rounded-md px-2 py-1"
+                                on:change=move |ev| on_credit_source_change(match event_target_value(&ev).as_str() {
+                                    "curriculum" => CreditSource::CurriculumOnly,
+                                    "transcript" => CreditSource::TranscriptOnly,
+                                    "max" => CreditSource::MaxOfBoth,
+                                    _ => CreditSource::MinOfBoth,
+                                })
+                            >
+                                {move || {
+                                    let current = audit_options.get_untracked().credit_source;
+                                    [
+                                        ("min", CreditSource::MinOfBoth, "ค่าที่น้อยกว่า (ค่าเริ่มต้น)", "Lower of both (default)"),
+                                        ("curriculum", CreditSource::CurriculumOnly, "ตามหลักสูตร", "Curriculum"),
+                                        ("transcript", CreditSource::TranscriptOnly, "ตามใบแสดงผล", "Transcript"),
+                                        ("max", CreditSource::MaxOfBoth, "ค่าที่มากกว่า", "Higher of both"),
+                                    ].into_iter().map(|(value, source, th, en)| view! {
+                                        <option value=value selected={source == current}>{if is_thai.get() { th } else { en }}</option>
+                                    }).collect::<Vec<_>>()
+                                }}
+                            </select>
+                        </label>
+
+                        // Duplicate rows: prefer the curriculum credit
+                        <label class="flex items-center gap-2 text-[13px] text-zinc-600 cursor-pointer select-none -mt-2">
+                            <input
+                                type="checkbox"
+                                class="rounded border-zinc-300 text-brand-600"
+                                prop:checked=move || audit_options.get().prefer_curriculum_credit
+                                on:change=move |ev| on_prefer_curriculum_credit_change(event_target_checked(&ev))
+                            />
+                            <span>{move || if is_thai.get() { "วิชาซ้ำ: ใช้แถวที่หน่วยกิตตรงกับหลักสูตร" } else { "Duplicates: use the row matching curriculum credits" }}</span>
+                        </label>
 
                         // Analyze button
                         <button
                             class="w-full flex items-center justify-center gap-2 bg-zinc-900 hover:bg-zinc-800 text-white text-sm font-medium py-2.5 px-4 rounded-xl transition-all duration-200 disabled:opacity-40 disabled:cursor-not-allowed active:scale-[0.98] shadow-soft hover:shadow-medium"
-                            disabled={move || file_name.get().is_empty() || is_loading.get()}
+                            disabled={move || selected_files.get().is_empty() || is_loading.get()}
                             on:click=on_start_analysis
                         >
                             {move || if is_loading.get() {
@@ -436,10 +1133,42 @@ fn App() -> impl IntoView {
                                 }.into_view()
                             }}
                         </button>
+
+                        // Reopen a result downloaded with "Save results"
+                        <label class="w-full text-center text-[13px] font-medium text-zinc-500 hover:text-brand-600 transition-colors cursor-pointer -mt-2">
+                            <input type="file" accept=".json,application/json" class="hidden" on:change=on_open_saved/>
+                            {move || if is_thai.get() { "เปิดผลลัพธ์ที่บันทึกไว้ (.json)" } else { "Open saved results (.json)" }}
+                        </label>
+
+                        // Demo mode: audit the bundled sample transcript
+                        <button
+                            class="w-full text-[13px] font-medium text-zinc-500 hover:text-brand-600 transition-colors disabled:opacity-40 -mt-2"
+                            disabled=move || is_loading.get()
+                            on:click=on_try_demo
+                        >
+                            {move || if is_thai.get() { "ยังไม่มีไฟล์? ลองใช้ข้อมูลตัวอย่าง" } else { "No PDF handy? Try demo" }}
+                        </button>
+
+                        // Paste mode: grades copied from the registration portal
+                        <div class="flex flex-col gap-2">
+                            <textarea
+                                class="w-full h-20 text-2xs font-mono text-zinc-700 bg-white border border-zinc-200 rounded-lg px-2.5 py-2 resize-y"
+                                placeholder=move || if is_thai.get() { "หรือวางผลการเรียนที่คัดลอกจากระบบลงทะเบียน" } else { "Or paste grades copied from the registration portal" }
+                                prop:value=pasted_text
+                                on:input=move |ev| set_pasted_text.set(event_target_value(&ev))
+                            ></textarea>
+                            <button
+                                class="self-end text-xs font-medium text-zinc-600 bg-white border border-zinc-200 hover:bg-zinc-50 rounded-lg px-3 py-1.5 transition-colors disabled:opacity-40"
+                                disabled=move || is_loading.get()
+                                on:click=on_parse_pasted
+                            >
+                                {move || if is_thai.get() { "อ่านข้อความที่วาง" } else { "Parse pasted text" }}
+                            </button>
+                        </div>
                     </div>
 
-                    // How it works card (only when no file selected)
-                    {move || preview_url.get().is_none().then(|| view! {
+                    // How it works card (only when no file selected; hidden below results on phones)
+                    {move || (preview_url.get().is_none() && !(compact.get() && audit_result.get().is_some())).then(|| view! {
                         <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft p-5 animate-fade-in-up">
                             <h3 class="text-xs font-semibold text-zinc-500 uppercase tracking-widest mb-3">{move || if is_thai.get() { "วิธีการใช้งาน" } else { "How it works" }}</h3>
                             <div class="space-y-3">
@@ -474,21 +1203,30 @@ fn App() -> impl IntoView {
                         if is_loading.get() {
                             // Loading state
                             view! {
-                                <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft h-full min-h-[500px] flex flex-col items-center justify-center gap-4">
+                                <div class={format!("bg-white rounded-2xl border border-zinc-200/80 shadow-soft flex flex-col items-center justify-center gap-4 {}", placeholder_height())}>
                                     <div class="relative">
                                         <div class="w-12 h-12 rounded-full border-2 border-zinc-200"></div>
                                         <div class="absolute inset-0 w-12 h-12 rounded-full border-2 border-brand-500 border-t-transparent animate-spin"></div>
                                     </div>
                                     <div class="text-center">
                                         <p class="text-sm font-medium text-zinc-700">{move || if is_thai.get() { "กำลังวิเคราะห์ใบแสดงผลการเรียน..." } else { "Analyzing transcript..." }}</p>
-                                        <p class="text-xs text-zinc-400 mt-1">{move || if is_thai.get() { "กำลังดึงข้อมูลวิชาและตรวจสอบข้อกำหนด" } else { "Parsing courses and validating requirements" }}</p>
+                                        <p class="text-xs text-zinc-400 mt-1">{move || match analysis_stage.get() {
+                                            Some(stage) => format!(
+                                                "{} ({}/{})",
+                                                stage.label(is_thai.get()),
+                                                stage.step(),
+                                                AnalysisStage::ALL.len()
+                                            ),
+                                            None if is_thai.get() => "กำลังดึงข้อมูลวิชาและตรวจสอบข้อกำหนด".to_string(),
+                                            None => "Parsing courses and validating requirements".to_string(),
+                                        }}</p>
                                     </div>
                                 </div>
                             }.into_view()
                         } else if let Some(err) = error_msg.get() {
                             // Error state
                             view! {
-                                <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft h-full min-h-[500px] flex flex-col items-center justify-center gap-4 px-8 text-center">
+                                <div class={format!("bg-white rounded-2xl border border-zinc-200/80 shadow-soft flex flex-col items-center justify-center gap-4 px-8 text-center {}", placeholder_height())}>
                                     <div class="w-12 h-12 rounded-full bg-red-50 flex items-center justify-center">
                                         <svg class="w-6 h-6 text-red-500" fill="none" stroke="currentColor" stroke-width="1.5" viewBox="0 0 24 24">
                                             <path stroke-linecap="round" stroke-linejoin="round" d="M12 9v3.75m9-.75a9 9 0 11-18 0 9 9 0 0118 0zm-9 3.75h.008v.008H12v-.008z"/>
@@ -498,10 +1236,47 @@ fn App() -> impl IntoView {
                                         <p class="text-sm font-semibold text-zinc-800">{move || if is_thai.get() { "การวิเคราะห์ล้มเหลว" } else { "Analysis Failed" }}</p>
                                         <p class="text-[13px] text-zinc-500 mt-1 max-w-sm leading-relaxed">{err}</p>
                                     </div>
+                                    {(!selected_files.get().is_empty()).then(|| view! {
+                                        <button
+                                            class="flex items-center gap-2 bg-zinc-900 hover:bg-zinc-800 text-white text-sm font-medium py-2 px-4 rounded-xl transition-all duration-200 active:scale-[0.98] shadow-soft"
+                                            on:click=on_start_analysis
+                                        >
+                                            <svg class="w-4 h-4 opacity-70" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" d="M16.023 9.348h4.992v-.001M2.985 19.644v-4.992m0 0h4.992m-4.993 0l3.181 3.183a8.25 8.25 0 0013.803-3.7M4.031 9.865a8.25 8.25 0 0113.803-3.7l3.181 3.182m0-4.991v4.99"/></svg>
+                                            <span>{move || if is_thai.get() { "ลองอีกครั้ง" } else { "Try again" }}</span>
+                                        </button>
+                                    })}
+                                </div>
+                            }.into_view()
+                        } else if let Some(result) = audit_result.get().filter(|_| show_report.get()) {
+                            // Advising report view
+                            let gpa = result.gpa;
+                            let status = graduation_status(&result, MIN_GRADUATION_GPA);
+                            view! {
+                                <div class="space-y-4 animate-fade-in">
+                                    <div class="print:hidden flex items-center justify-between gap-2">
+                                        <button
+                                            class="text-xs font-medium text-zinc-600 bg-white border border-zinc-200 hover:bg-zinc-50 rounded-lg px-3 py-1.5 transition-colors"
+                                            on:click=move |_| set_show_report.set(false)
+                                        >
+                                            {move || if is_thai.get() { "กลับไปหน้าผลลัพธ์" } else { "Back to results" }}
+                                        </button>
+                                        <button
+                                            class="text-xs font-medium text-white bg-zinc-900 hover:bg-zinc-800 rounded-lg px-3 py-1.5 transition-colors"
+                                            on:click=move |_| {
+                                                if let Some(window) = web_sys::window() {
+                                                    let _ = window.print();
+                                                }
+                                            }
+                                        >
+                                            {move || if is_thai.get() { "พิมพ์" } else { "Print" }}
+                                        </button>
+                                    </div>
+                                    <GraduationReport result=result gpa=gpa status=status/>
                                 </div>
                             }.into_view()
-                        } else if let Some(result) = audit_result.get() {
-                            // Results view
+                        } else if let Some(mut result) = audit_result.get() {
+                            // Results view, categories in the student's chosen order
+                            result.categories = ordered_categories(result.categories, &category_order.get());
                             view! {
                                 <div class="space-y-5 animate-fade-in">
 
@@ -510,104 +1285,386 @@ fn App() -> impl IntoView {
                                         <div class="absolute -right-16 -top-16 w-48 h-48 bg-brand-100/40 rounded-full blur-3xl pointer-events-none"></div>
                                         <div class="relative flex flex-col sm:flex-row sm:items-end sm:justify-between gap-4">
                                             <div>
-                                                <p class="text-xs font-semibold text-brand-600 uppercase tracking-widest mb-1">{move || if is_thai.get() { "ความคืบหน้าทั้งหมด" } else { "Total Progress" }}</p>
+                                                <div class="flex items-center gap-2 mb-1">
+                                                    <p class="text-xs font-semibold text-brand-600 uppercase tracking-widest">{move || if is_thai.get() { "ความคืบหน้าทั้งหมด" } else { "Total Progress" }}</p>
+                                                    {
+                                                        let status = program_status(&result, &ProgramStatusThresholds::default());
+                                                        let pill_class = match status {
+                                                            ProgramStatus::ReadyToGraduate => "bg-emerald-50 text-emerald-700 border-emerald-200/60",
+                                                            ProgramStatus::OnTrack => "bg-brand-50 text-brand-700 border-brand-200/60",
+                                                            ProgramStatus::AtRisk => "bg-amber-50 text-amber-700 border-amber-200/60",
+                                                        };
+                                                        view! {
+                                                            <span class={format!("text-2xs font-semibold px-2 py-0.5 rounded-full border {}", pill_class)}>
+                                                                {move || status.label(is_thai.get())}
+                                                            </span>
+                                                        }
+                                                    }
+                                                </div>
                                                 <div class="flex items-baseline gap-2">
                                                     <span class="text-5xl sm:text-6xl font-extrabold tracking-tighter text-zinc-900 tabular-nums">
-                                                        {result.total_credits as u32}
+                                                        {format_credits(result.total_credits)}
                                                     </span>
                                                     <span class="text-base font-medium text-zinc-400">{move || if is_thai.get() { "หน่วยกิตที่ได้รับ" } else { "credits earned" }}</span>
                                                 </div>
+                                                {result.gpa.map(|gpa| view! {
+                                                    <p class="text-[13px] text-zinc-500 mt-1">
+                                                        {move || if is_thai.get() { "เกรดเฉลี่ยสะสม " } else { "Cumulative GPA " }}
+                                                        <span class="font-semibold text-zinc-800 tabular-nums">{format!("{:.2}", gpa)}</span>
+                                                    </p>
+                                                })}
+                                                {(result.coverage.total > 0).then(|| {
+                                                    let coverage = result.coverage;
+                                                    // Low coverage usually means rows were misread or codes are missing from the data
+                                                    let low = coverage.percent() < LOW_COVERAGE_PERCENT;
+                                                    let class = if low { "text-xs text-amber-600 mt-1" } else { "text-xs text-zinc-400 mt-1" };
+                                                    view! {
+                                                        <p
+                                                            class=class
+                                                            title=move || if is_thai.get() {
+                                                                format!("วิชาที่ผ่านแล้วซึ่งถูกจัดเข้าหมวดใดหมวดหนึ่ง (ไม่ถูกนับ {} วิชา)", coverage.uncounted)
+                                                            } else {
+                                                                format!("Passed courses placed in a category ({} not counted anywhere)", coverage.uncounted)
+                                                            }
+                                                        >
+                                                            {move || if is_thai.get() {
+                                                                format!("จัดหมวดได้ {} จาก {} วิชา", coverage.categorized(), coverage.total)
+                                                            } else {
+                                                                format!("{} of {} courses categorized", coverage.categorized(), coverage.total)
+                                                            }}
+                                                        </p>
+                                                    }
+                                                })}
                                             </div>
-                                            <div class="flex items-center gap-1.5 text-xs text-zinc-500 bg-zinc-50 rounded-lg px-3 py-1.5 self-start sm:self-auto">
-                                                <svg class="w-3.5 h-3.5" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" d="M4.26 10.147a60.436 60.436 0 00-.491 6.347A48.627 48.627 0 0112 20.904a48.627 48.627 0 018.232-4.41 60.46 60.46 0 00-.491-6.347"/></svg>
-                                                <span class="font-medium">{move || if is_thai.get() { "วท.บ. (วิทยาการคอมพิวเตอร์)" } else { "B.Sc. (Computer Science)" }}</span>
+                                            <div class="flex items-center gap-2 self-start sm:self-auto">
+                                                <button
+                                                    class="text-xs font-medium text-zinc-600 bg-white border border-zinc-200 hover:bg-zinc-50 rounded-lg px-3 py-1.5 transition-colors"
+                                                    on:click=move |_| set_show_report.set(true)
+                                                >
+                                                    {move || if is_thai.get() { "รายงานสำหรับอาจารย์ที่ปรึกษา" } else { "Advising report" }}
+                                                </button>
+                                                <button
+                                                    class="text-xs font-medium text-zinc-600 bg-white border border-zinc-200 hover:bg-zinc-50 rounded-lg px-3 py-1.5 transition-colors"
+                                                    on:click=on_share
+                                                >
+                                                    {move || match (is_thai.get(), share_copied.get()) {
+                                                        (true, true) => "คัดลอกลิงก์แล้ว",
+                                                        (true, false) => "แชร์ความคืบหน้า",
+                                                        (false, true) => "Link copied",
+                                                        (false, false) => "Share progress",
+                                                    }}
+                                                </button>
+                                                <button
+                                                    class="text-xs font-medium text-zinc-600 bg-white border border-zinc-200 hover:bg-zinc-50 rounded-lg px-3 py-1.5 transition-colors"
+                                                    on:click=on_copy_summary
+                                                >
+                                                    {move || match (is_thai.get(), summary_copied.get()) {
+                                                        (true, true) => "คัดลอกสรุปแล้ว",
+                                                        (true, false) => "คัดลอกสรุป",
+                                                        (false, true) => "Summary copied",
+                                                        (false, false) => "Copy summary",
+                                                    }}
+                                                </button>
+                                                <button
+                                                    class="text-xs font-medium text-zinc-600 bg-white border border-zinc-200 hover:bg-zinc-50 rounded-lg px-3 py-1.5 transition-colors"
+                                                    on:click=on_save_result
+                                                >
+                                                    {move || if is_thai.get() { "บันทึกผลลัพธ์" } else { "Save results" }}
+                                                </button>
+                                                <button
+                                                    class="text-xs font-medium text-zinc-600 bg-white border border-zinc-200 hover:bg-zinc-50 rounded-lg px-3 py-1.5 transition-colors"
+                                                    title={move || if is_thai.get() { "ซ่อนชื่อวิชาเลือก เหลือเฉพาะวิชาบังคับ" } else { "Elective names are replaced; required courses are kept" }}
+                                                    on:click=on_save_anonymized
+                                                >
+                                                    {move || if is_thai.get() { "บันทึกสำหรับอาจารย์ (ไม่ระบุวิชาเลือก)" } else { "Save for advisor (anonymized)" }}
+                                                </button>
+                                                <div class="flex items-center gap-1.5 text-xs text-zinc-500 bg-zinc-50 rounded-lg px-3 py-1.5">
+                                                    <svg class="w-3.5 h-3.5" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" d="M4.26 10.147a60.436 60.436 0 00-.491 6.347A48.627 48.627 0 0112 20.904a48.627 48.627 0 018.232-4.41 60.46 60.46 0 00-.491-6.347"/></svg>
+                                                    <span class="font-medium">{move || if is_thai.get() { "วท.บ. (วิทยาการคอมพิวเตอร์)" } else { "B.Sc. (Computer Science)" }}</span>
+                                                </div>
                                             </div>
                                         </div>
                                     </div>
 
+                                    // ── Remaining Semesters ──────────────────
+                                    {(result.remaining_credits() > 0.0).then(|| view! {
+                                        <TermPlanner
+                                            remaining=result.remaining_credits()
+                                            grade_totals={
+                                                let courses = parsed_courses.get_untracked();
+                                                (!courses.is_empty()).then(|| grade_point_totals(&courses))
+                                            }
+                                        />
+                                    })}
+
                                     // ── Category Progress Cards ─────────────
-                                    <div class="grid grid-cols-1 md:grid-cols-3 gap-4 stagger-in">
-                                        {result.categories.iter().map(|cat| {
-                                            let pct = ((cat.collected_credits / cat.required_credits) * 100.0).min(100.0);
-                                            let complete = pct >= 100.0;
-                                            let cat_name_str = cat.name.clone();
-                                            let cat_display = {
-                                                let cat_name_str = cat_name_str.clone();
-                                                move || {
-                                                    let is_thai = is_thai.get();
-                                                    match cat_name_str.as_str() {
-                                                        "General Education" if is_thai => "หมวดวิชาศึกษาทั่วไป".to_string(),
-                                                        "Major Courses" if is_thai => "หมวดวิชาเฉพาะ".to_string(),
-                                                        "Free Electives" if is_thai => "หมวดวิชาเลือกเสรี".to_string(),
-                                                        _ => cat_name_str.clone(),
+                                    {if compact.get() {
+                                        // Condensed rows on phones: name, bar and credits only
+                                        view! {
+                                            <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft divide-y divide-zinc-100/80">
+                                                {result.categories.iter().map(|cat| {
+                                                    let pct = progress_percent(cat.collected_credits, cat.required_credits);
+                                                    let bar_class = progress_color_class(pct);
+                                                    let cat_name_str = cat.name.clone();
+                                                    let cat_display = move || category_label(&cat_name_str, is_thai.get());
+                                                    view! {
+                                                        <div class="px-4 py-3">
+                                                            <div class="flex items-baseline justify-between gap-3">
+                                                                <p class="text-[13px] font-semibold text-zinc-800 truncate">{cat_display}</p>
+                                                                <span class="text-xs text-zinc-500 tabular-nums shrink-0">
+                                                                    {format!("{} / {}", format_credits(cat.collected_credits), format_credits(cat.required_credits))}
+                                                                </span>
+                                                            </div>
+                                                            <div class="mt-1.5 h-1.5 rounded-full bg-zinc-100 overflow-hidden">
+                                                                <div class={format!("h-full rounded-full {}", bar_class)} style={format!("width: {}%", pct)}></div>
+                                                            </div>
+                                                        </div>
                                                     }
-                                                }
-                                            };
-                                            let collected = cat.collected_credits;
-                                            let required = cat.required_credits;
-                                            let pct_display = pct as u32;
-
-                                            // SVG donut params
-                                            let circumference = 100.0;
-                                            let dash = (pct / 100.0) * circumference;
-
-                                            let color_class = if complete { "text-emerald-500" } else { "text-brand-500" };
-                                            let bg_class = if complete { "bg-emerald-50 border-emerald-100" } else { "bg-white border-zinc-200/80" };
-                                            let badge_class = if complete { "bg-emerald-100 text-emerald-700" } else { "bg-brand-50 text-brand-600" };
-                                            let badge_text = move || if is_thai.get() {
-                                                if complete { "เสร็จสมบูรณ์" } else { "กำลังดำเนินการ" }
-                                            } else {
-                                                if complete { "Complete" } else { "In Progress" }
-                                            };
+                                                }).collect::<Vec<_>>()}
+                                            </div>
+                                        }.into_view()
+                                    } else {
+                                        view! {
+                                            <div class="grid grid-cols-1 md:grid-cols-3 gap-4 stagger-in">
+                                                {result.categories.iter().enumerate().map(|(index, cat)| {
+                                                    let is_first = index == 0;
+                                                    let is_last = index + 1 == result.categories.len();
+                                                    let pct = progress_percent(cat.collected_credits, cat.required_credits);
+                                                    let complete = pct >= 100.0;
+                                                    let cat_name_str = cat.name.clone();
+                                                    let cat_display = {
+                                                        let cat_name_str = cat_name_str.clone();
+                                                        move || category_label(&cat_name_str, is_thai.get())
+                                                    };
+                                                    let collected = cat.collected_credits;
+                                                    let required = cat.required_credits;
 
-                                            view! {
-                                                <div class={format!("rounded-2xl border shadow-soft p-5 flex flex-col gap-4 transition-shadow hover:shadow-medium {}", bg_class)}>
-                                                    <div class="flex items-start justify-between">
-                                                        <p class="text-sm font-semibold text-zinc-800">{cat_display}</p>
-                                                        <span class={format!("text-2xs font-semibold px-2 py-0.5 rounded-full {}", badge_class)}>{badge_text}</span>
-                                                    </div>
-                                                    <div class="flex items-center gap-4">
-                                                        // Donut chart
-                                                        <div class="relative w-14 h-14 shrink-0">
-                                                            <svg class="w-14 h-14 -rotate-90" viewBox="0 0 36 36">
-                                                                <circle cx="18" cy="18" r="15.9155" fill="none" stroke="#e4e4e7" stroke-width="3"/>
-                                                                <circle cx="18" cy="18" r="15.9155" fill="none"
-                                                                    class={format!("{} donut-animated", color_class)}
-                                                                    stroke="currentColor" stroke-width="3" stroke-linecap="round"
-                                                                    stroke-dasharray={format!("{} {}", dash, circumference - dash)}/>
-                                                            </svg>
-                                                            <div class="absolute inset-0 flex items-center justify-center">
-                                                                <span class="text-xs font-bold text-zinc-700">{format!("{}%", pct_display)}</span>
+                                                    // SVG donut params
+                                                    let circumference = 100.0;
+                                                    let dash = (pct / 100.0) * circumference;
+
+                                                    let color_class = if complete { "text-emerald-500" } else { "text-brand-500" };
+                                                    let bg_class = if complete { "bg-emerald-50 border-emerald-100" } else { "bg-white border-zinc-200/80" };
+                                                    let badge_class = if complete { "bg-emerald-100 text-emerald-700" } else { "bg-brand-50 text-brand-600" };
+                                                    let badge_text = move || if is_thai.get() {
+                                                        if complete { "เสร็จสมบูรณ์" } else { "กำลังดำเนินการ" }
+                                                    } else {
+                                                        if complete { "Complete" } else { "In Progress" }
+                                                    };
+
+                                                    view! {
+                                                        <div class={format!("rounded-2xl border shadow-soft p-5 flex flex-col gap-4 transition-shadow hover:shadow-medium {}", bg_class)}>
+                                                            <div class="flex items-start justify-between">
+                                                                <p class="text-sm font-semibold text-zinc-800">{cat_display}</p>
+                                                                <div class="flex items-center gap-1.5">
+                                                                    <span class={format!("text-2xs font-semibold px-2 py-0.5 rounded-full {}", badge_class)}>{badge_text}</span>
+                                                                    <button
+                                                                        class="text-xs text-zinc-400 hover:text-zinc-700 disabled:opacity-30 disabled:cursor-default transition-colors"
+                                                                        disabled=is_first
+                                                                        aria-label={move || if is_thai.get() { "เลื่อนขึ้น" } else { "Move up" }}
+                                                                        on:click={
+                                                                            let name = cat_name_str.clone();
+                                                                            move |_| on_move_category(name.clone(), MoveDirection::Up)
+                                                                        }
+                                                                    >
+                                                                        "←"
+                                                                    </button>
+                                                                    <button
+                                                                        class="text-xs text-zinc-400 hover:text-zinc-700 disabled:opacity-30 disabled:cursor-default transition-colors"
+                                                                        disabled=is_last
+                                                                        aria-label={move || if is_thai.get() { "เลื่อนลง" } else { "Move down" }}
+                                                                        on:click={
+                                                                            let name = cat_name_str.clone();
+                                                                            move |_| on_move_category(name.clone(), MoveDirection::Down)
+                                                                        }
+                                                                    >
+                                                                        "→"
+                                                                    </button>
+                                                                </div>
                                                             </div>
-                                                        </div>
-                                                        // Credits
-                                                        <div>
-                                                            <div class="flex items-baseline gap-1">
-                                                                <span class="text-2xl font-bold text-zinc-900 tabular-nums">{collected as u32}</span>
-                                                                <span class="text-sm text-zinc-400 font-medium">{format!("/ {}", required as u32)}</span>
+                                                            <div class="flex items-center gap-4">
+                                                                // Donut chart
+                                                                <div class="relative w-14 h-14 shrink-0">
+                                                                    <svg class="w-14 h-14 -rotate-90" viewBox="0 0 36 36">
+                                                                        <circle cx="18" cy="18" r="15.9155" fill="none" stroke="#e4e4e7" stroke-width="3"/>
+                                                                        <circle cx="18" cy="18" r="15.9155" fill="none"
+                                                                            class={format!("{} donut-animated", color_class)}
+                                                                            stroke="currentColor" stroke-width="3" stroke-linecap="round"
+                                                                            stroke-dasharray={format!("{} {}", dash, circumference - dash)}/>
+                                                                    </svg>
+                                                                    <div class="absolute inset-0 flex items-center justify-center">
+                                                                        <span class="text-xs font-bold text-zinc-700">{format_percent(pct)}</span>
+                                                                    </div>
+                                                                </div>
+                                                                // Credits
+                                                                <div>
+                                                                    <div class="flex items-baseline gap-1">
+                                                                        <span class="text-2xl font-bold text-zinc-900 tabular-nums">{format_credits(collected)}</span>
+                                                                        <span class="text-sm text-zinc-400 font-medium">{format!("/ {}", format_credits(required))}</span>
+                                                                    </div>
+                                                                    <p class="text-2xs text-zinc-400 mt-0.5 font-medium">{move || if is_thai.get() { "หน่วยกิต" } else { "credits" }}</p>
+                                                                </div>
                                                             </div>
-                                                            <p class="text-2xs text-zinc-400 mt-0.5 font-medium">{move || if is_thai.get() { "หน่วยกิต" } else { "credits" }}</p>
                                                         </div>
-                                                    </div>
+                                                    }
+                                                }).collect::<Vec<_>>()}
+                                            </div>
+                                        }.into_view()
+                                    }}
+
+                                    // ── Credit Breakdown ─────────────────────
+                                    <CreditBreakdown categories=result.categories.clone()/>
+
+                                    // ── Extra Credits ────────────────────────
+                                    {
+                                        let surpluses = credit_surpluses(&result.categories);
+                                        (!surpluses.is_empty()).then(|| view! {
+                                            <div class="flex items-start gap-2.5 px-4 py-3 rounded-xl bg-brand-50/60 border border-brand-100">
+                                                <svg class="w-4 h-4 text-brand-500 shrink-0 mt-0.5" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" d="M11.25 11.25l.041-.02a.75.75 0 011.063.852l-.708 2.836a.75.75 0 001.063.853l.041-.021M21 12a9 9 0 11-18 0 9 9 0 0118 0zm-9-3.75h.008v.008H12V8.25z"/></svg>
+                                                <div class="text-[13px] text-brand-800 leading-relaxed">
+                                                    <p class="font-medium">{move || if is_thai.get() { "คุณมีหน่วยกิตเกินข้อกำหนด" } else { "You have extra credits" }}</p>
+                                                    {surpluses.iter().map(|s| {
+                                                        let category = s.category.clone();
+                                                        let surplus = s.surplus;
+                                                        view! {
+                                                            <p class="text-brand-700/80">{move || if is_thai.get() {
+                                                                format!("{}: เกิน +{} หน่วยกิต", category_label(&category, true), format_credits(surplus))
+                                                            } else {
+                                                                format!("{}: +{} credits beyond the requirement", category, format_credits(surplus))
+                                                            }}</p>
+                                                        }
+                                                    }).collect::<Vec<_>>()}
                                                 </div>
-                                            }
-                                        }).collect::<Vec<_>>()}
-                                    </div>
+                                            </div>
+                                        })
+                                    }
 
                                     // ── Course Details Accordion ─────────────
                                     <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft overflow-hidden">
                                         <div class="px-5 py-4 border-b border-zinc-100 flex items-center gap-2.5">
                                             <svg class="w-4 h-4 text-zinc-400" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" d="M3.75 12h16.5m-16.5 3.75h16.5M3.75 19.5h16.5M5.625 4.5h12.75a1.875 1.875 0 010 3.75H5.625a1.875 1.875 0 010-3.75z"/></svg>
                                             <h3 class="text-sm font-semibold text-zinc-800">{move || if is_thai.get() { "รายละเอียดวิชา" } else { "Course Details" }}</h3>
+                                            <div class="ml-auto flex items-center gap-2 text-2xs font-medium text-zinc-500">
+                                                <button
+                                                    class="hover:text-brand-600 transition-colors"
+                                                    on:click={
+                                                        let names: Vec<String> = result.categories.iter().map(|c| c.name.clone()).collect();
+                                                        move |_| category_expansion.update(|e| e.expand_all(names.iter().map(String::as_str)))
+                                                    }
+                                                >
+                                                    {move || if is_thai.get() { "ขยายทั้งหมด" } else { "Expand all" }}
+                                                </button>
+                                                <span class="text-zinc-300">"·"</span>
+                                                <button
+                                                    class="hover:text-brand-600 transition-colors"
+                                                    on:click=move |_| category_expansion.update(|e| e.collapse_all())
+                                                >
+                                                    {move || if is_thai.get() { "ย่อทั้งหมด" } else { "Collapse all" }}
+                                                </button>
+                                            </div>
+                                            <button
+                                                class="text-2xs font-medium text-zinc-500 hover:text-brand-600 transition-colors"
+                                                on:click=move |_| set_show_requirements_table.update(|t| *t = !*t)
+                                            >
+                                                {move || match (is_thai.get(), show_requirements_table.get()) {
+                                                    (true, true) => "มุมมองการ์ด",
+                                                    (true, false) => "มุมมองตาราง",
+                                                    (false, true) => "Card view",
+                                                    (false, false) => "Table view",
+                                                }}
+                                            </button>
+                                            <div class="flex items-center rounded-lg bg-zinc-100 p-0.5 text-2xs font-semibold">
+                                                <button
+                                                    class={move || format!("px-2 py-0.5 rounded-md transition-colors {}", if display_lang.get() == DisplayLang::Thai { "bg-white text-zinc-800 shadow-soft" } else { "text-zinc-500" })}
+                                                    on:click=move |_| set_display_lang.set(DisplayLang::Thai)
+                                                >
+                                                    "ไทย"
+                                                </button>
+                                                <button
+                                                    class={move || format!("px-2 py-0.5 rounded-md transition-colors {}", if display_lang.get() == DisplayLang::English { "bg-white text-zinc-800 shadow-soft" } else { "text-zinc-500" })}
+                                                    on:click=move |_| set_display_lang.set(DisplayLang::English)
+                                                >
+                                                    "EN"
+                                                </button>
+                                            </div>
                                         </div>
-                                        <div class="divide-y divide-zinc-100">
-                                            {result.categories.iter().map(|category| {
-                                                let category = category.clone();
-                                                view! { <CategoryCard category={category} /> }
-                                            }).collect::<Vec<_>>()}
-                                        </div>
+                                        {
+                                            let table_result = result.clone();
+                                            move || if show_requirements_table.get() {
+                                                view! {
+                                                    <RequirementsTable
+                                                        categories=table_result.categories.clone()
+                                                        strands=table_result.gen_ed_strands.clone()
+                                                        clusters=table_result.cluster_progress.clone()
+                                                    />
+                                                }.into_view()
+                                            } else {
+                                                view! {
+                                                    <div class="divide-y divide-zinc-100">
+                                                        {table_result.categories.iter().map(|category| {
+                                                            let strands = if category.name == "General Education" {
+                                                                table_result.gen_ed_strands.clone()
+                                                            } else {
+                                                                Vec::new()
+                                                            };
+                                                            let category = category.clone();
+                                                            view! { <CategoryCard category={category} strands=strands /> }
+                                                        }).collect::<Vec<_>>()}
+                                                    </div>
+                                                }.into_view()
+                                            }
+                                        }
                                     </div>
 
+                                    // ── Elective Clusters ────────────────────
+                                    {(!result.cluster_progress.is_empty()).then(|| view! {
+                                        <ClusterBoard
+                                            clusters=result.cluster_progress.clone()
+                                            pinned=Signal::derive(move || audit_options.get().pinned_clusters)
+                                            on_toggle_pin=on_toggle_cluster_pin
+                                        />
+                                    })}
+
+                                    // ── Manual Course Entry ──────────────────
+                                    {move || (!parsed_courses.get().is_empty()).then(|| view! {
+                                        <CourseEditor on_add=on_add_course/>
+                                        {move || course_history.with(|history| history.can_undo()).then(|| view! {
+                                            <button
+                                                class="self-start -mt-2 text-xs font-medium text-zinc-600 bg-white border border-zinc-200 hover:bg-zinc-50 rounded-lg px-3 py-1.5 transition-colors"
+                                                on:click=on_undo_edit
+                                            >
+                                                {move || if is_thai.get() { "เลิกทำการเพิ่มวิชาล่าสุด" } else { "Undo last course edit" }}
+                                            </button>
+                                        })}
+                                        <div class="flex items-center gap-2 text-xs text-zinc-500">
+                                            <span>{move || if is_thai.get() { "ดาวน์โหลดรายวิชาที่อ่านได้" } else { "Download parsed courses" }}</span>
+                                            <button
+                                                class="font-medium text-zinc-600 bg-white border border-zinc-200 hover:bg-zinc-50 rounded-lg px-2.5 py-1 transition-colors"
+                                                on:click=on_export_parsed_json
+                                            >
+                                                "JSON"
+                                            </button>
+                                            <button
+                                                class="font-medium text-zinc-600 bg-white border border-zinc-200 hover:bg-zinc-50 rounded-lg px-2.5 py-1 transition-colors"
+                                                on:click=on_export_parsed_csv
+                                            >
+                                                "CSV"
+                                            </button>
+                                            {debug_logging_enabled().then(|| view! {
+                                                <button
+                                                    class="font-medium text-zinc-600 bg-white border border-zinc-200 hover:bg-zinc-50 rounded-lg px-2.5 py-1 transition-colors"
+                                                    on:click=on_export_schema
+                                                >
+                                                    {move || if is_thai.get() { "โครงสร้างหลักสูตร" } else { "Curriculum schema" }}
+                                                </button>
+                                            })}
+                                        </div>
+                                        <SubstitutionEditor
+                                            substitutions=Signal::derive(move || audit_options.get().substitutions)
+                                            on_change=on_substitutions_change
+                                        />
+                                    })}
+
                                     // ── Missing Requirements ─────────────────
                                     {(!result.missing_subjects.is_empty()).then(|| {
                                         let mut seen_cats: Vec<String> = Vec::new();
@@ -623,14 +1680,23 @@ fn App() -> impl IntoView {
                                                     <svg class="w-4 h-4 text-red-500" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" d="M12 9v3.75m-9.303 3.376c-.866 1.5.217 3.374 1.948 3.374h14.71c1.73 0 2.813-1.874 1.948-3.374L13.949 3.378c-.866-1.5-3.032-1.5-3.898 0L2.697 16.126zM12 15.75h.007v.008H12v-.008z"/></svg>
                                                     <h3 class="text-sm font-semibold text-red-800">{move || if is_thai.get() { "ข้อกำหนดที่ขาด" } else { "Missing Requirements" }}</h3>
                                                     <span class="ml-auto text-2xs font-semibold text-red-600 bg-red-100 px-2 py-0.5 rounded-full">{move || if is_thai.get() { format!("{} รายการ", missing_count) } else { format!("{} items", missing_count) }}</span>
+                                                    <button
+                                                        class="print:hidden text-2xs font-medium text-red-700 hover:text-red-900 bg-white border border-red-200 rounded-md px-2 py-0.5 transition-colors"
+                                                        title=move || if is_thai.get() { "ดาวน์โหลดเป็นรายการสิ่งที่ต้องทำ (Markdown)" } else { "Download as a markdown to-do list" }
+                                                        on:click=on_download_checklist
+                                                    >
+                                                        {CHECKLIST_FILE_NAME}
+                                                    </button>
                                                 </div>
                                                 <div class="divide-y divide-red-100/60">
                                                     {seen_cats.iter().map(|cat| {
                                                         let cat_courses: Vec<_> = result.missing_subjects.iter()
                                                             .filter(|m| &m.category == cat)
                                                             .collect();
-                                                        let display_items: Vec<String> = if cat == "General Education" {
-                                                            let mut ge_groups: Vec<String> = Vec::new();
+                                                        // (description, reason) per line; a grouped GenEd line keeps
+                                                        // the reason of its first entry
+                                                        let display_items: Vec<(String, Option<String>)> = if cat == "General Education" {
+                                                            let mut ge_groups: Vec<(String, Option<String>)> = Vec::new();
                                                             for m in &cat_courses {
                                                                 let description = m.description.trim();
                                                                 let group = if description.contains("missing") {
@@ -638,37 +1704,44 @@ fn App() -> impl IntoView {
                                                                 } else {
                                                                     description.split(':').next().unwrap_or(description).trim().to_string()
                                                                 };
-                                                                if !ge_groups.contains(&group) {
-                                                                    ge_groups.push(group);
+                                                                if !ge_groups.iter().any(|(g, _)| g == &group) {
+                                                                    ge_groups.push((group, m.reason.clone()));
                                                                 }
                                                             }
                                                             ge_groups
                                                         } else {
-                                                            cat_courses.iter().map(|m| m.description.clone()).collect()
+                                                            cat_courses.iter().map(|m| (m.description.clone(), m.reason.clone())).collect()
                                                         };
                                                         let cat_display_name = {
                                                             let name = cat.clone();
-                                                            move || {
-                                                                let is_thai = is_thai.get();
-                                                                match name.as_str() {
-                                                                    "General Education" if is_thai => "หมวดวิชาศึกษาทั่วไป".to_string(),
-                                                                    "Major Courses" if is_thai => "หมวดวิชาเฉพาะ".to_string(),
-                                                                    "Free Electives" if is_thai => "หมวดวิชาเลือกเสรี".to_string(),
-                                                                    _ => name.clone(),
-                                                                }
-                                                            }
+                                                            move || category_label(&name, is_thai.get())
                                                         };
                                                         view! {
                                                             <div class="p-5">
                                                                 <p class="text-xs font-semibold text-zinc-700 uppercase tracking-wider mb-2.5">{cat_display_name}</p>
                                                                 <div class="space-y-1.5">
-                                                                    {display_items.iter().map(|item| {
+                                                                    {display_items.iter().map(|(item, reason)| {
                                                                         let desc = item.clone();
+                                                                        let reason = reason.clone();
+                                                                        let target = target_category(cat);
                                                                         view! {
-                                                                            <div class="flex items-start gap-2.5 py-1.5">
+                                                                            <button
+                                                                                class="w-full flex items-start gap-2.5 py-1.5 text-left rounded-md hover:bg-red-50/60 transition-colors disabled:cursor-default disabled:hover:bg-transparent"
+                                                                                disabled=target.is_none()
+                                                                                on:click=move |_| {
+                                                                                    if let Some(target) = target {
+                                                                                        jump_to_category(target);
+                                                                                    }
+                                                                                }
+                                                                            >
                                                                                 <div class="w-1.5 h-1.5 rounded-full bg-red-400 mt-1.5 shrink-0"></div>
-                                                                                <p class="text-[13px] text-zinc-600 leading-relaxed">{desc}</p>
-                                                                            </div>
+                                                                                <div>
+                                                                                    <p class="text-[13px] text-zinc-600 leading-relaxed">{desc}</p>
+                                                                                    {reason.map(|reason| view! {
+                                                                                        <p class="text-2xs text-zinc-400">{reason}</p>
+                                                                                    })}
+                                                                                </div>
+                                                                            </button>
                                                                         }
                                                                     }).collect::<Vec<_>>()}
                                                                 </div>
@@ -679,12 +1752,170 @@ fn App() -> impl IntoView {
                                             </div>
                                         }
                                     })}
+
+                                    // ── To Retake ────────────────────────────
+                                    {(!result.retake_courses.is_empty()).then(|| {
+                                        // Required courses first: they block graduation
+                                        let mut retakes = result.retake_courses.clone();
+                                        retakes.sort_by_key(|retake| !retake.required);
+                                        let items = retakes.into_iter().map(|retake| {
+                                            let (detail_en, detail_th) = if retake.required {
+                                                (format!("Urgent: grade {} in a required course — retake to graduate", retake.course.grade),
+                                                 format!("ด่วน: ได้เกรด {} ในวิชาบังคับ ต้องลงเรียนใหม่จึงจะสำเร็จการศึกษา", retake.course.grade))
+                                            } else {
+                                                (format!("Grade {} — not yet passed; retake or replace with another course", retake.course.grade),
+                                                 format!("เกรด {} — ยังไม่ผ่าน ลงเรียนใหม่หรือเลือกวิชาอื่นแทน", retake.course.grade))
+                                            };
+                                            NoticeItem {
+                                                code: retake.course.code,
+                                                name: retake.course.name,
+                                                detail_en,
+                                                detail_th,
+                                            }
+                                        }).collect::<Vec<_>>();
+                                        view! {
+                                            <NoticePanel
+                                                title_en="To Retake"
+                                                title_th="วิชาที่ต้องลงเรียนใหม่"
+                                                tone=NoticeTone::Warning
+                                                items=items
+                                            />
+                                        }
+                                    })}
+
+                                    // ── Weak Grades ──────────────────────────
+                                    {(!result.weak_grade_courses.is_empty()).then(|| {
+                                        let items = result.weak_grade_courses.iter().map(|course| NoticeItem {
+                                            code: course.code.clone(),
+                                            name: course.name.clone(),
+                                            detail_en: format!("Grade {} — below {:.2}; may affect standing or progression", course.grade, WEAK_GRADE_THRESHOLD),
+                                            detail_th: format!("เกรด {} — ต่ำกว่า {:.2} อาจส่งผลต่อสถานภาพหรือการลงทะเบียนวิชาต่อเนื่อง", course.grade, WEAK_GRADE_THRESHOLD),
+                                        }).collect::<Vec<_>>();
+                                        view! {
+                                            <NoticePanel
+                                                title_en="Low Grades"
+                                                title_th="วิชาที่ได้เกรดต่ำ"
+                                                tone=NoticeTone::Warning
+                                                items=items
+                                            />
+                                        }
+                                    })}
+
+                                    // ── Major-Faculty GenEd Courses ──────────
+                                    {
+                                        let conflicts = gen_ed_faculty_conflicts(&result.categories, MAJOR_FACULTY_PREFIXES);
+                                        (!conflicts.is_empty()).then(|| {
+                                            let items = conflicts.iter().map(|course| NoticeItem {
+                                                code: course.code.clone(),
+                                                name: course.name.clone(),
+                                                detail_en: "Counted toward General Education but offered by the major's faculty — check with your advisor".to_string(),
+                                                detail_th: "นับเป็นวิชาศึกษาทั่วไป แต่เปิดสอนโดยคณะของสาขา — ควรตรวจสอบกับอาจารย์ที่ปรึกษา".to_string(),
+                                            }).collect::<Vec<_>>();
+                                            view! {
+                                                <NoticePanel
+                                                    title_en="GenEd Courses From the Major's Faculty"
+                                                    title_th="วิชาศึกษาทั่วไปจากคณะของสาขา"
+                                                    tone=NoticeTone::Info
+                                                    items=items
+                                                />
+                                            }
+                                        })
+                                    }
+
+                                    // ── GenEd Electives by Faculty ───────────
+                                    <GenEdElectivesByFaculty electives=get_gen_ed_curriculum().electives/>
+
+                                    // ── Withdrawn Courses ────────────────────
+                                    {
+                                        let withdrawn = result.withdrawn_courses.clone();
+                                        move || (show_withdrawn.get() && !withdrawn.is_empty()).then(|| {
+                                            let items = withdrawn.iter().map(|course| NoticeItem {
+                                                code: course.code.clone(),
+                                                name: course.name.clone(),
+                                                detail_en: format!("Withdrawn ({} cr) — no credits, not in the GPA", format_credits(course.credit)),
+                                                detail_th: format!("ถอนรายวิชา ({} หน่วยกิต) — ไม่นับหน่วยกิตและไม่คิดเกรดเฉลี่ย", format_credits(course.credit)),
+                                            }).collect::<Vec<_>>();
+                                            view! {
+                                                <NoticePanel
+                                                    title_en="Withdrawn Courses"
+                                                    title_th="วิชาที่ถอน"
+                                                    tone=NoticeTone::Info
+                                                    items=items
+                                                />
+                                            }
+                                        })
+                                    }
+
+                                    // ── Co-requisites ────────────────────────
+                                    {(!result.corequisite_warnings.is_empty()).then(|| {
+                                        let items = result.corequisite_warnings.iter().map(|warning| NoticeItem {
+                                            code: warning.course.code.clone(),
+                                            name: warning.course.name.clone(),
+                                            detail_en: format!("Passed without co-requisite {} {}", warning.missing_code, warning.missing_name),
+                                            detail_th: format!("ผ่านแล้วแต่ยังไม่ผ่านวิชาที่ต้องเรียนควบคู่ {} {}", warning.missing_code, warning.missing_name),
+                                        }).collect::<Vec<_>>();
+                                        view! {
+                                            <NoticePanel
+                                                title_en="Co-requisite Warnings"
+                                                title_th="วิชาที่ต้องเรียนควบคู่"
+                                                tone=NoticeTone::Warning
+                                                items=items
+                                            />
+                                        }
+                                    })}
+
+                                    // ── Not Counted ──────────────────────────
+                                    {(!result.excluded_courses.is_empty()).then(|| {
+                                        let items = result.excluded_courses.iter().map(|excluded| {
+                                            let (detail_en, detail_th) = match excluded.reason {
+                                                ExclusionReason::ZeroCredit => ("Not counted: 0-credit course", "ไม่นับ: วิชา 0 หน่วยกิต"),
+                                                ExclusionReason::Remedial => ("Not counted: remedial / pre-degree course", "ไม่นับ: วิชาปรับพื้นฐาน"),
+                                                ExclusionReason::DuplicateCapstone => ("Not counted: a capstone is already counted", "ไม่นับ: นับวิชาโครงงาน/สหกิจศึกษาไปแล้ว 1 วิชา"),
+                                                ExclusionReason::BelowMinimumGrade => ("Not counted: grade below the free-elective minimum", "ไม่นับ: เกรดต่ำกว่าเกณฑ์ขั้นต่ำของวิชาเลือกเสรี"),
+                                            };
+                                            NoticeItem {
+                                                code: excluded.course.code.clone(),
+                                                name: excluded.course.name.clone(),
+                                                detail_en: detail_en.to_string(),
+                                                detail_th: detail_th.to_string(),
+                                            }
+                                        }).collect::<Vec<_>>();
+                                        view! {
+                                            <NoticePanel
+                                                title_en="Not Counted"
+                                                title_th="วิชาที่ไม่นับหน่วยกิต"
+                                                tone=NoticeTone::Info
+                                                items=items
+                                            />
+                                        }
+                                    })}
+
+                                    // ── Other Courses ────────────────────────
+                                    {(!result.unrecognized_courses.is_empty()).then(|| {
+                                        let items = result.unrecognized_courses.iter().map(|course| NoticeItem {
+                                            code: course.code.clone(),
+                                            name: course.name.clone(),
+                                            detail_en: "Not in the curriculum — counted as a free elective. Check the code if this looks wrong.".to_string(),
+                                            detail_th: "ไม่อยู่ในหลักสูตร — นับเป็นวิชาเลือกเสรี กรุณาตรวจสอบรหัสวิชาหากไม่ถูกต้อง".to_string(),
+                                        }).collect::<Vec<_>>();
+                                        view! {
+                                            <NoticePanel
+                                                title_en="Other Courses"
+                                                title_th="วิชาอื่น ๆ"
+                                                tone=NoticeTone::Info
+                                                items=items
+                                            />
+                                        }
+                                    })}
                                 </div>
                             }.into_view()
+                        } else if let Some(summary) = shared_summary.clone() {
+                            // Shared summary link (read-only)
+                            view! { <SharedSummaryView summary=summary /> }.into_view()
                         } else {
                             // Empty state
                             view! {
-                                <div class="bg-white rounded-2xl border border-zinc-200/80 shadow-soft h-full min-h-[500px] flex flex-col items-center justify-center gap-5 px-8 text-center">
+                                <div class={format!("bg-white rounded-2xl border border-zinc-200/80 shadow-soft flex flex-col items-center justify-center gap-5 px-8 text-center {}", placeholder_height())}>
                                     <div class="w-16 h-16 rounded-2xl bg-zinc-50 border border-zinc-200 flex items-center justify-center">
                                         <svg class="w-7 h-7 text-zinc-300" fill="none" stroke="currentColor" stroke-width="1.5" viewBox="0 0 24 24">
                                             <path stroke-linecap="round" stroke-linejoin="round" d="M19.5 14.25v-2.625a3.375 3.375 0 00-3.375-3.375h-1.5A1.125 1.125 0 0113.5 7.125v-1.5a3.375 3.375 0 00-3.375-3.375H8.25m0 12.75h7.5m-7.5 3H12M10.5 2.25H5.625c-.621 0-1.125.504-1.125 1.125v17.25c0 .621.504 1.125 1.125 1.125h12.75c.621 0 1.125-.504 1.125-1.125V11.25a9 9 0 00-9-9z"/>
@@ -703,3 +1934,73 @@ fn App() -> impl IntoView {
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::parser::{parse_transcript, ParseOptions};
+
+    fn result(text: &str) -> AuditResult {
+        let courses = parse_transcript(text, &ParseOptions::default()).unwrap();
+        audit_transcript(
+            &courses,
+            &get_gen_ed_curriculum(),
+            &get_major_curriculum(),
+            &AuditOptions::default(),
+        )
+    }
+
+    #[test]
+    fn announcement_reports_credits_and_remaining_requirements() {
+        let result = result("322-101 CALCULUS I 01 3 B+\n999-101 POTTERY 01 3 A");
+        let remaining = result.missing_subjects.len();
+
+        assert_eq!(
+            audit_announcement(&result, false),
+            format!(
+                "Audit complete: 6 of 132 credits, {} requirements remaining",
+                remaining
+            )
+        );
+        assert_eq!(
+            audit_announcement(&result, true),
+            format!(
+                "ตรวจสอบเสร็จแล้ว: 6 จาก 132 หน่วยกิต เหลือ {} ข้อกำหนด",
+                remaining
+            )
+        );
+    }
+
+    #[test]
+    fn pasted_text_is_parsed_and_audited() {
+        let (result, courses) = analyze_pasted_text(
+            "322-101\tCALCULUS I\t01\t3\tB+\n890-102   EVERYDAY ENGLISH   01   2   A",
+            AuditOptions::default(),
+        )
+        .unwrap();
+
+        let rows: Vec<_> = courses
+            .iter()
+            .map(|c| {
+                (
+                    c.code.as_str(),
+                    c.name.as_str(),
+                    c.parsed_credit,
+                    c.grade.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("322-101", "CALCULUS I", 3.0, "B+"),
+                ("890-102", "EVERYDAY ENGLISH", 2.0, "A"),
+            ]
+        );
+        assert_eq!(result.total_credits, 5.0);
+        assert!(matches!(
+            analyze_pasted_text("  \n", AuditOptions::default()),
+            Err(AuditError::PastedTextEmpty)
+        ));
+    }
+}
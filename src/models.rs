@@ -2,11 +2,17 @@
 //!
 //! This module defines all data types used throughout the application:
 //! - `Course`: Individual course with grade and credit info
+//! - `CourseCode`: Validated course code compared by its canonical form
 //! - `Category`: Top-level audit category (GenEd, Major, Electives)
 //! - `AuditResult`: Final audit result with all categories and missing courses
+//! - `CreditSummary`, `GenEdSummary`, `MajorSummary`: Per-auditor credit progress
 //! - Curriculum types: `GenEdCurriculum`, `MajorCurriculum` for static curriculum data
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
 /// Represents a single course instance in the transcript
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,6 +21,91 @@ pub struct Course {
     pub name: String,  // Course name
     pub credit: f32,   // Credits earned
     pub grade: String, // Letter grade (A, B, C, etc.)
+    #[serde(default)]
+    pub curriculum_name: Option<String>, // English name from the curriculum, when matched
+    #[serde(default)]
+    pub substitutes_for: Option<String>, // Requirement code this course fills by approved substitution
+    #[serde(default)]
+    pub section: Option<String>, // Section number from the transcript, when printed
+    #[serde(default)]
+    pub matched: Option<CourseMatch>, // Requirement this course counted toward, if any
+}
+
+/// A validated course code: `NNN-NNN` with an optional alphanumeric suffix of
+/// up to three characters (e.g. `344-111`, `344-111A`). Two codes are equal
+/// when their canonical `NNN-NNN` forms match; `Display` keeps the suffix.
+#[derive(Debug, Clone, Eq)]
+pub struct CourseCode(String);
+
+impl CourseCode {
+    /// Validates and uppercases `raw` (surrounding whitespace is ignored).
+    /// Returns `None` for anything that is not a course code.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let code = raw.trim().to_uppercase();
+        let bytes = code.as_bytes();
+        let well_formed = bytes.len() >= 7
+            && bytes.len() <= 10
+            && bytes[..3].iter().all(u8::is_ascii_digit)
+            && bytes[3] == b'-'
+            && bytes[4..7].iter().all(u8::is_ascii_digit)
+            && bytes[7..].iter().all(u8::is_ascii_alphanumeric);
+        well_formed.then_some(Self(code))
+    }
+
+    /// The `NNN-NNN` form used to match against the curriculum.
+    pub fn canonical(&self) -> &str {
+        &self.0[..7]
+    }
+}
+
+impl PartialEq for CourseCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical() == other.canonical()
+    }
+}
+
+impl Hash for CourseCode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical().hash(state);
+    }
+}
+
+impl fmt::Display for CourseCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Canonical form of a raw code for comparisons; codes that fail validation
+/// are compared as written.
+pub fn canonical_code(raw: &str) -> String {
+    CourseCode::parse(raw).map_or_else(|| raw.to_string(), |code| code.canonical().to_string())
+}
+
+/// Language used for course names in course lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DisplayLang {
+    Thai,    // Name as printed on the transcript
+    English, // Curriculum (English) name when the course was matched
+}
+
+impl Course {
+    /// Picks the name to display: the curriculum English name when English is
+    /// selected and available, otherwise the transcript name, and finally the
+    /// course code when neither name is available.
+    pub fn display_name(&self, lang: DisplayLang) -> &str {
+        let transcript_name = Some(self.name.as_str()).filter(|n| !n.trim().is_empty());
+        let curriculum_name = self
+            .curriculum_name
+            .as_deref()
+            .filter(|n| !n.trim().is_empty());
+
+        let preferred = match lang {
+            DisplayLang::English => curriculum_name.or(transcript_name),
+            DisplayLang::Thai => transcript_name.or(curriculum_name),
+        };
+        preferred.unwrap_or(&self.code)
+    }
 }
 
 /// Aggregates courses within a displayable category (e.g., General Education, Major)
@@ -31,18 +122,345 @@ pub struct Category {
 pub struct MissingCourse {
     pub category: String,    // e.g. "General Education", "Major Courses"
     pub description: String, // e.g. "344-101 - Calculus I"
+    #[serde(default)]
+    pub reason: Option<String>, // Why it is missing, e.g. "None of the 3 options was passed"
 }
 
+/// Schema version written into every `AuditResult`. Bump it whenever the
+/// serialized shape changes so old saved files are rejected, not misread.
+pub const AUDIT_SCHEMA_VERSION: u32 = 1;
+
 /// Final audit result containing all categories and missing requirements
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditResult {
-    pub total_credits: f32,                   // Total credits earned
-    pub categories: Vec<Category>,            // All audit categories (GenEd, Major, Electives)
+    pub schema_version: u32, // Serialized shape version (`AUDIT_SCHEMA_VERSION`)
+    pub total_credits: f32,  // Total credits earned
+    pub categories: Vec<Category>, // All audit categories (GenEd, Major, Electives)
     pub missing_subjects: Vec<MissingCourse>, // Missing courses with their category
+    #[serde(default)]
+    pub excluded_courses: Vec<ExcludedCourse>, // Passing courses not counted anywhere
+    #[serde(default)]
+    pub unrecognized_courses: Vec<Course>, // Passing courses matching no curriculum code
+    #[serde(default)]
+    pub weak_grade_courses: Vec<Course>, // Passing courses below the weak-grade threshold
+    #[serde(default)]
+    pub withdrawn_courses: Vec<Course>, // W-graded enrollments; never credited or in the GPA
+    #[serde(default)]
+    pub gpa: Option<f32>, // Cumulative GPA; None when no graded credits
+    #[serde(default)]
+    pub gen_ed_strands: Vec<StrandProgress>, // Per-strand GenEd progress
+    #[serde(default)]
+    pub retake_courses: Vec<RetakeCourse>, // Failed and not yet passed on a retake
+    #[serde(default)]
+    pub corequisite_warnings: Vec<CorequisiteWarning>, // Lectures passed without their lab (or vice versa)
+    #[serde(default)]
+    pub cluster_progress: Vec<ClusterProgress>, // Every elective cluster, for choosing which to finish
+    #[serde(default)]
+    pub coverage: Coverage, // How many passed courses were placed in a category
+    #[serde(skip)]
+    pub claimed_indices: HashSet<usize>, // Transcript indices claimed by GenEd or Major
 }
 
-/// A single General Education course.
+impl AuditResult {
+    /// Returns `true` when every category is complete and nothing is missing.
+    pub fn is_complete(&self) -> bool {
+        self.missing_subjects.is_empty()
+            && self
+                .categories
+                .iter()
+                .all(|c| c.collected_credits >= c.required_credits)
+    }
+
+    /// Credits still needed across all categories. Surplus in one category
+    /// does not offset a shortfall in another.
+    pub fn remaining_credits(&self) -> f32 {
+        self.categories
+            .iter()
+            .map(|c| (c.required_credits - c.collected_credits).max(0.0))
+            .sum()
+    }
+}
+
+/// Coverage below this percentage hints at a parsing or curriculum data problem.
+pub const LOW_COVERAGE_PERCENT: f32 = 90.0;
+
+/// How many passed transcript courses the audit placed. `uncounted` are
+/// passed courses counted nowhere (neither matched nor free electives, e.g.
+/// excluded ones); unknown codes counted as free electives are in `free`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Coverage {
+    pub total: usize,   // Passed (or claimed) transcript courses
+    pub matched: usize, // Claimed by a GenEd or Major requirement
+    pub free: usize,    // Counted as free electives
+    #[serde(alias = "unrecognized")]
+    pub uncounted: usize, // Left out of every category
+}
+
+impl Coverage {
+    /// Courses placed in a category (matched or free elective).
+    pub fn categorized(&self) -> usize {
+        self.matched + self.free
+    }
+
+    /// Share of courses categorized, 100 when there are none.
+    pub fn percent(&self) -> f32 {
+        if self.total == 0 {
+            100.0
+        } else {
+            self.categorized() as f32 / self.total as f32 * 100.0
+        }
+    }
+}
+
+/// A failed course that has not been passed since. `required` marks courses
+/// the curriculum demands by code, which must be retaken to graduate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetakeCourse {
+    pub course: Course, // The latest failed attempt
+    pub required: bool, // Basic science, core or a choose-all GenEd course
+}
+
+/// A passed course whose co-requisite (e.g. its lab) has not been passed.
+/// Advisory only: credits are unaffected.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorequisiteWarning {
+    pub course: Course,       // The passed course
+    pub missing_code: String, // Co-requisite that is not passed
+    pub missing_name: String,
+}
+
+/// How many of an elective cluster's courses have been passed, whichever
+/// requirement they ended up counting toward.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClusterProgress {
+    pub domain: String,
+    pub cluster_id: String,
+    pub name: String,
+    pub completed_courses: u32,
+    pub required_courses: u32, // Courses needed to complete the cluster
+    pub total_courses: u32,
+}
+
+/// Credit progress of one GenEd strand, with its sub-groups (if any) nested
+/// one level down in the same shape.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StrandProgress {
+    pub name: String,
+    pub completed: f32, // Credits counted toward the strand (or sub-group)
+    pub required: f32,
+    #[serde(default)]
+    pub sub_groups: Vec<StrandProgress>,
+}
+
+/// GenEd audit result: overall credit progress plus the per-strand breakdown.
+#[derive(Debug, Clone, Default)]
+pub struct GenEdSummary {
+    pub credits: CreditSummary,
+    pub strands: Vec<StrandProgress>,
+}
+
+/// Requirement a transcript course was matched to and the credits it earned
+/// there, which can be less than the transcript credit (curriculum cap).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CourseMatch {
+    pub requirement: String, // e.g. "Core Courses", "Cluster 1.1 Big Data"
+    pub credited: f32,
+}
+
+/// Credit progress produced by an auditor for one requirement group.
+#[derive(Debug, Clone, Default)]
+pub struct CreditSummary {
+    pub completed: f32,                       // Credits matched to this group
+    pub required: f32,                        // Credits the group requires
+    pub missing: Vec<MissingCourse>,          // Unmet requirements, in audit order
+    pub used: HashSet<usize>,                 // Transcript indices claimed by this group
+    pub matches: HashMap<usize, CourseMatch>, // Requirement and credit per claimed index
+}
+
+/// Major audit result, split into the required portion (basic science, core,
+/// capstone) and the elective portion (clusters and other electives).
+#[derive(Debug, Clone, Default)]
+pub struct MajorSummary {
+    pub courses: CreditSummary,
+    pub electives: CreditSummary,
+    pub duplicate_capstones: Vec<usize>, // Second capstone enrollments, not counted
+}
+
+impl MajorSummary {
+    /// Total major credits across required courses and electives.
+    pub fn completed(&self) -> f32 {
+        self.courses.completed + self.electives.completed
+    }
+
+    /// Missing requirements, required portion first.
+    pub fn missing(&self) -> Vec<MissingCourse> {
+        let mut missing = self.courses.missing.clone();
+        missing.extend(self.electives.missing.iter().cloned());
+        missing
+    }
+
+    /// Every transcript index claimed by the major audit.
+    pub fn used(&self) -> HashSet<usize> {
+        self.courses
+            .used
+            .union(&self.electives.used)
+            .copied()
+            .collect()
+    }
+}
+
+/// Why a passing course was not counted toward any requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ExclusionReason {
+    ZeroCredit,        // Carries no credit (e.g., prep/placement courses)
+    Remedial,          // On the policy's remedial deny-list
+    DuplicateCapstone, // A second capstone option; only one capstone counts
+    BelowMinimumGrade, // Grade point under the free-elective minimum
+}
+
+/// A passing course that was deliberately left out of free electives.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExcludedCourse {
+    pub course: Course,
+    pub reason: ExclusionReason,
+}
+
+/// Rules deciding which unclaimed passing courses count as free electives.
+#[derive(Debug, Clone)]
+pub struct FreeElectivePolicy {
+    pub exclude_zero_credit: bool,    // Skip courses with 0 parsed credits
+    pub remedial_codes: Vec<String>,  // Pre-degree codes that never count
+    pub min_grade_point: Option<f32>, // Graded courses below this never count; None = any pass
+}
+
+impl Default for FreeElectivePolicy {
+    fn default() -> Self {
+        Self {
+            exclude_zero_credit: true,
+            remedial_codes: vec!["890-101".to_string()],
+            min_grade_point: None,
+        }
+    }
+}
+
+/// Where a matched course's credits come from when the curriculum value and
+/// the transcript value disagree. The default takes the lower of the two: a
+/// PDF-parsing slip can then only under-credit, never inflate progress. That
+/// under-credits a course whose real credit outgrew stale curriculum data
+/// (e.g. a 6-credit MODULE course listed at 3), so the other sources can be
+/// chosen per audit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CreditSource {
+    CurriculumOnly, // Curriculum value, whatever the transcript says
+    TranscriptOnly, // Transcript value, trusting the parse
+    #[default]
+    MinOfBoth,
+    MaxOfBoth,
+}
+
+impl CreditSource {
+    /// Credits to count for a course the curriculum lists at
+    /// `curriculum_credits` and the transcript shows at `transcript_credits`.
+    pub fn credited(self, curriculum_credits: f32, transcript_credits: f32) -> f32 {
+        match self {
+            CreditSource::CurriculumOnly => curriculum_credits,
+            CreditSource::TranscriptOnly => transcript_credits,
+            CreditSource::MinOfBoth => curriculum_credits.min(transcript_credits),
+            CreditSource::MaxOfBoth => curriculum_credits.max(transcript_credits),
+        }
+    }
+}
+
+/// How elective clusters are judged complete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ElectivePolicy {
+    /// Only courses claimed by the cluster itself count toward completion.
+    Strict,
+    /// A passed cluster course counts even if GenEd or another cluster
+    /// already claimed it. Required core codes never count toward a cluster.
+    #[default]
+    Lenient,
+}
+
+/// How pass/no-credit (`P`) grades are counted. `P` never enters the GPA;
+/// some programs still credit it and others do not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PassingPolicy {
+    pub p_counts_credits: bool, // A `P` course earns credits and completes requirements
+}
+
+impl Default for PassingPolicy {
+    /// Credits `P` courses, matching `is_passing_grade`.
+    fn default() -> Self {
+        Self {
+            p_counts_credits: true,
+        }
+    }
+}
+
+impl PassingPolicy {
+    /// Whether a passing `grade` earns credits under this policy.
+    pub fn earns_credits(self, grade: &str) -> bool {
+        self.p_counts_credits || !grade.trim().eq_ignore_ascii_case("P")
+    }
+}
+
+/// Cap on repeated special-topic enrollments counted as major electives;
+/// enrollments beyond it are left for free electives.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpecialTopicLimit {
+    pub max_courses: usize, // Special-topic enrollments that may count
+    pub max_credits: f32,   // Special-topic credits that may count
+}
+
+impl Default for SpecialTopicLimit {
+    fn default() -> Self {
+        Self {
+            max_courses: 2,
+            max_credits: 6.0,
+        }
+    }
+}
+
+/// Policy knobs for a full audit run.
+#[derive(Debug, Clone, Default)]
+pub struct AuditOptions {
+    pub elective_policy: ElectivePolicy,
+    pub credit_source: CreditSource, // Curriculum vs transcript credits for matched courses
+    pub prefer_curriculum_credit: bool, // Among duplicate rows, claim the one whose credit fits the curriculum
+    pub free_elective_policy: FreeElectivePolicy,
+    pub special_topic_limit: SpecialTopicLimit,
+    pub assume_in_progress_pass: bool, // Count IP courses as passed ("what if I pass?")
+    pub passing_policy: PassingPolicy, // Whether P (pass) grades earn credits
+    pub substitutions: HashMap<String, String>, // Approved petitions: taken code -> code it satisfies
+    pub english_exit_marker: Option<String>, // Marker code for the English exit test; None skips the check
+    pub degree_min_credits: Option<f32>, // Overall credit minimum for the degree; None skips the check
+    pub pinned_clusters: HashSet<String>, // Cluster ids the student plans to complete
+    pub minor: Option<MinorCurriculum>, // Secondary curriculum audited after the major; None skips it
+}
+
+impl AuditOptions {
+    /// Canonical code that `code` satisfies by approved substitution, if any.
+    pub fn substitution_for(&self, code: &str) -> Option<String> {
+        let code = canonical_code(code);
+        self.substitutions
+            .iter()
+            .find(|(taken, _)| canonical_code(taken) == code)
+            .map(|(_, satisfies)| canonical_code(satisfies))
+    }
+}
+
+/// Free-elective outcome computed from courses not claimed by any requirement.
+#[derive(Debug, Clone, Default)]
+pub struct FreeElectiveSummary {
+    pub credits: f32,                  // Credits counted as free electives
+    pub list: Vec<String>,             // Display lines, e.g. "344-496 (Grade: A, 3 cr)"
+    pub indices: Vec<usize>,           // Transcript indices counted as free electives
+    pub excluded: Vec<ExcludedCourse>, // Passing courses not counted, with reason
+}
+
+/// A single General Education course.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GenEdCourse {
     pub code: String,
     pub name: String,
@@ -50,7 +468,7 @@ pub struct GenEdCourse {
 }
 
 /// A nested sub-group under a GenEd strand.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GenEdSubGroup {
     pub name: String,
     pub required_credits: f32,
@@ -58,7 +476,7 @@ pub struct GenEdSubGroup {
 }
 
 /// A GenEd strand which may contain direct courses or sub-groups.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GenEdStrand {
     pub id: u32,
     pub name: String,
@@ -74,17 +492,17 @@ pub struct GenEdStrand {
 }
 
 /// Elective sub-category within GenEd (e.g., language electives).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GenEdElectiveSubCategory {
     pub name: String,
     pub required_credits: f32,
-    pub min_courses: u32,
-    pub max_courses: u32,
+    pub min_courses: u32, // Fewer passed courses than this raises a warning
+    pub max_courses: u32, // At most this many courses count toward GenEd electives
     pub courses: Vec<GenEdCourse>,
 }
 
 /// Collects GenEd electives.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GenEdElectives {
     pub name: String,
     pub total_required_credits: f32,
@@ -92,7 +510,7 @@ pub struct GenEdElectives {
 }
 
 /// Top-level General Education curriculum definition.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GenEdCurriculum {
     pub name: String,
     pub total_required_credits: f32,
@@ -101,15 +519,17 @@ pub struct GenEdCurriculum {
 }
 
 /// A course that belongs to the major curriculum.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MajorCourse {
     pub code: String,
     pub name: String,
     pub credits: f32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub corequisites: Vec<String>, // Codes that must be passed alongside (lecture ↔ lab)
 }
 
 /// Cluster of courses inside a domain.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MajorCluster {
     pub id: String,
     pub name: String,
@@ -119,7 +539,7 @@ pub struct MajorCluster {
 }
 
 /// Domain grouping clusters of major electives.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MajorDomain {
     pub id: u32,
     pub name: String,
@@ -128,7 +548,7 @@ pub struct MajorDomain {
 }
 
 /// Basic science portion of the major.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MajorBasicScience {
     pub name: String,
     pub required_credits: f32,
@@ -136,7 +556,7 @@ pub struct MajorBasicScience {
 }
 
 /// Core courses required for the major.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MajorCoreCourses {
     pub name: String,
     pub required_credits: f32,
@@ -144,7 +564,7 @@ pub struct MajorCoreCourses {
 }
 
 /// Capstone options for the major (project or co-op).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MajorCapstone {
     pub name: String,
     pub credits_per_option: f32,
@@ -152,7 +572,7 @@ pub struct MajorCapstone {
 }
 
 /// Elective requirements, including domains and other choices.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MajorElectives {
     pub name: String,
     pub total_required_credits: f32,
@@ -162,7 +582,7 @@ pub struct MajorElectives {
 }
 
 /// Top-level Major curriculum definition.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MajorCurriculum {
     pub name: String,
     pub total_required_credits: f32,
@@ -172,41 +592,375 @@ pub struct MajorCurriculum {
     pub electives: MajorElectives,
 }
 
+/// A MODULE course that some transcripts print as separate component rows
+/// (e.g. `344-201A` and `344-201B`) instead of one module row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleComponents {
+    pub code: String, // Module code the components are merged into
+    pub name: String,
+    pub credits: f32,
+    pub components: Vec<String>, // Component codes exactly as printed (with suffix)
+}
+
+/// Secondary curriculum (a minor) audited over the courses GenEd and the
+/// major left unclaimed. Required courses are matched first; any listed
+/// elective then fills the remaining credits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinorCurriculum {
+    pub name: String,
+    pub total_required_credits: f32,
+    pub required_courses: Vec<MajorCourse>,
+    pub electives: Vec<MajorCourse>,
+}
+
+impl MinorCurriculum {
+    /// Whether `code` is one of the minor's required or elective courses.
+    pub fn lists(&self, code: &str) -> bool {
+        let code = canonical_code(code);
+        self.required_courses
+            .iter()
+            .chain(&self.electives)
+            .any(|course| canonical_code(&course.code) == code)
+    }
+}
+
+/// Order in which requirement groups claim transcript courses, highest
+/// priority first. Required groups all precede elective ones, so a course
+/// eligible for both always fills the required slot; free electives only see
+/// what every group left unclaimed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchPriority {
+    GenEdStrand,
+    BasicScience,
+    Core,
+    Capstone,
+    GenEdElective,
+    Cluster,
+    OtherElective,
+}
+
+/// Where a course is defined in the curricula.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CurriculumLocation {
+    GenEdStrand(String), // Strand name
+    GenEdSubGroup { strand: String, sub_group: String },
+    GenEdElective(String), // Elective sub-category name
+    BasicScience,
+    Core,
+    Capstone,
+    OtherElective, // Major "others" electives
+    Cluster { id: String, name: String },
+}
+
+impl CurriculumLocation {
+    /// Matching priority of the requirement group at this location.
+    pub fn priority(&self) -> MatchPriority {
+        match self {
+            CurriculumLocation::GenEdStrand(_) | CurriculumLocation::GenEdSubGroup { .. } => {
+                MatchPriority::GenEdStrand
+            }
+            CurriculumLocation::GenEdElective(_) => MatchPriority::GenEdElective,
+            CurriculumLocation::BasicScience => MatchPriority::BasicScience,
+            CurriculumLocation::Core => MatchPriority::Core,
+            CurriculumLocation::Capstone => MatchPriority::Capstone,
+            CurriculumLocation::OtherElective => MatchPriority::OtherElective,
+            CurriculumLocation::Cluster { .. } => MatchPriority::Cluster,
+        }
+    }
+}
+
+/// A course as defined in the curriculum, with where it lives.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurriculumCourseRef {
+    pub code: String,
+    pub name: String, // Curriculum (English) name
+    pub credits: f32,
+    pub location: CurriculumLocation,
+}
+
 /// Parsed course details extracted from the transcript text.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParsedCourse {
     pub code: String,
     pub name: String,
     pub grade: String,
     pub parsed_credit: f32,
+    pub section: Option<String>, // Section number, when the row prints one
+    #[serde(default)]
+    pub low_confidence: bool, // Credit could not be read as printed (defaulted or clamped)
 }
 
 // ── Shared utility functions ────────────────────────────────────────────────
 
 /// Returns `true` when the grade represents a passing result.
-/// Failing markers: F (fail), W (withdraw), U (unsatisfactory).
+/// Not passing: F (fail), W (withdraw), U (unsatisfactory), I/IP (incomplete /
+/// in progress). `G` (graded transfer-equivalent) passes: it earns credits but
+/// no grade point.
 pub fn is_passing_grade(grade: &str) -> bool {
     grade
         .trim()
         .chars()
         .next()
-        .map(|c| !matches!(c.to_ascii_uppercase(), 'F' | 'W' | 'U'))
+        .map(|c| !matches!(c.to_ascii_uppercase(), 'F' | 'W' | 'U' | 'I'))
         .unwrap_or(false)
 }
 
-/// Builds a deduplication key for a course so that repeatable special-topic
-/// courses (344-496 … 344-499) are keyed by code **and** name, while all other
-/// courses are keyed by code alone.
-pub fn free_elective_dedupe_key(code: &str, name: &str) -> String {
-    let is_repeatable_special_topic = code.starts_with("344-49")
+/// Returns `true` for a failed attempt: `F`, or `U` on S/U courses. Unlike
+/// `W`, `I` and `IP`, these mean the course has to be retaken.
+pub fn is_failing_grade(grade: &str) -> bool {
+    matches!(grade.trim().to_ascii_uppercase().as_str(), "F" | "U")
+}
+
+/// Returns `true` for a withdrawal (`W`): the enrollment stays on the
+/// transcript but earns no credits and no grade point.
+pub fn is_withdrawn_grade(grade: &str) -> bool {
+    grade.trim().eq_ignore_ascii_case("W")
+}
+
+/// Returns `true` for the in-progress grade `IP` (course still being taken).
+pub fn is_in_progress_grade(grade: &str) -> bool {
+    grade.trim().eq_ignore_ascii_case("IP")
+}
+
+/// Whether a grade counts as passed in an audit: a passing grade, or `IP`
+/// when the audit assumes current courses will pass.
+pub fn counts_as_passed(grade: &str, assume_in_progress_pass: bool) -> bool {
+    is_passing_grade(grade) || (assume_in_progress_pass && is_in_progress_grade(grade))
+}
+
+/// Letter-grade scale the transcript is read with. PSU grades plus-only;
+/// `PlusMinus` also accepts A-, B-, C- and D- in case minus grades are
+/// introduced. Under `PlusOnly` a row with a minus grade is skipped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GradingScheme {
+    #[default]
+    PlusOnly,
+    #[allow(dead_code)] // PSU grades plus-only today; reserved for minus grades.
+    PlusMinus,
+}
+
+impl GradingScheme {
+    /// Whether a normalized grade (e.g. `"B-"`) belongs to this scheme.
+    pub fn accepts(self, grade: &str) -> bool {
+        match self {
+            GradingScheme::PlusOnly => !grade.ends_with('-'),
+            GradingScheme::PlusMinus => true,
+        }
+    }
+}
+
+/// Grade-point table for every letter grade that enters the GPA. This is the
+/// single source of truth: GPA, weak-grade detection and sorting all go
+/// through `grade_point`. Minus grades only reach it when the transcript is
+/// parsed with `GradingScheme::PlusMinus`.
+const GRADE_POINTS: [(&str, f32); 12] = [
+    ("A", 4.0),
+    ("A-", 3.75),
+    ("B+", 3.5),
+    ("B", 3.0),
+    ("B-", 2.75),
+    ("C+", 2.5),
+    ("C", 2.0),
+    ("C-", 1.75),
+    ("D+", 1.5),
+    ("D", 1.0),
+    ("D-", 0.75),
+    ("F", 0.0),
+];
+
+/// Returns the grade point for a letter grade, or `None` for grades that carry
+/// no grade point (S/U/W/P/I/IP/T/G/E and anything unrecognized). `G` courses
+/// count toward credits via `is_passing_grade` but are left out of the GPA.
+pub fn grade_point(grade: &str) -> Option<f32> {
+    let grade = grade.trim();
+    GRADE_POINTS
+        .iter()
+        .find(|(letter, _)| letter.eq_ignore_ascii_case(grade))
+        .map(|&(_, points)| points)
+}
+
+/// Completion percentage of `collected` over `required`, clamped to [0, 100].
+/// A zero requirement reads as 100% once anything is collected and 0% otherwise,
+/// so the result is never NaN or infinite.
+pub fn progress_percent(collected: f32, required: f32) -> f32 {
+    if required <= 0.0 {
+        return if collected > 0.0 { 100.0 } else { 0.0 };
+    }
+    (collected / required * 100.0).clamp(0.0, 100.0)
+}
+
+/// Formats a percentage rounded to the nearest integer (74.5 → "75%", 74.9 → "75%").
+pub fn format_percent(percent: f32) -> String {
+    format!("{}%", percent.round() as i32)
+}
+
+/// Formats a credit count to one decimal place, dropping a trailing ".0"
+/// (3.0 → "3", 1.5 → "1.5", 2.96 → "3").
+pub fn format_credits(credits: f32) -> String {
+    let rounded = (credits * 10.0).round() / 10.0;
+    if rounded.fract() == 0.0 {
+        format!("{}", rounded as i32)
+    } else {
+        format!("{:.1}", rounded)
+    }
+}
+
+/// Total grade points and graded credits over every attempt that carries a
+/// grade point (retakes included, F counted as 0.0).
+pub fn grade_point_totals(courses: &[ParsedCourse]) -> (f32, f32) {
+    courses
+        .iter()
+        .filter_map(|c| grade_point(&c.grade).map(|gp| (gp * c.parsed_credit, c.parsed_credit)))
+        .fold((0.0, 0.0), |(p, cr), (gp, c)| (p + gp, cr + c))
+}
+
+/// Cumulative GPA over every attempt that carries a grade point (retakes
+/// included, F counted as 0.0). Returns `None` when no graded credits exist.
+pub fn calculate_gpa(courses: &[ParsedCourse]) -> Option<f32> {
+    let (points, credits) = grade_point_totals(courses);
+    (credits > 0.0).then(|| points / credits)
+}
+
+/// Returns `true` for repeatable special-topic codes (344-496 … 344-499): any
+/// 344-49x code except the seminar, capstone and selected-topic courses.
+pub fn is_special_topic(code: &str) -> bool {
+    code.starts_with("344-49")
         && !matches!(
             code,
             "344-491" | "344-492" | "344-493" | "344-494" | "344-495"
-        );
+        )
+}
 
-    if is_repeatable_special_topic {
+/// Builds a deduplication key for a course so that repeatable special-topic
+/// courses (344-496 … 344-499) are keyed by code **and** name, while all other
+/// courses are keyed by code alone.
+pub fn free_elective_dedupe_key(code: &str, name: &str) -> String {
+    if is_special_topic(code) {
         format!("{}::{}", code, name)
     } else {
         code.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn course(name: &str, curriculum_name: Option<&str>) -> Course {
+        Course {
+            code: "344-101".to_string(),
+            name: name.to_string(),
+            credit: 3.0,
+            grade: "A".to_string(),
+            curriculum_name: curriculum_name.map(str::to_string),
+            section: None,
+            substitutes_for: None,
+            matched: None,
+        }
+    }
+
+    #[test]
+    fn display_name_prefers_the_selected_language() {
+        let both = course("การเขียนโปรแกรม", Some("Programming"));
+        assert_eq!(both.display_name(DisplayLang::Thai), "การเขียนโปรแกรม");
+        assert_eq!(both.display_name(DisplayLang::English), "Programming");
+
+        let transcript_only = course("การเขียนโปรแกรม", None);
+        assert_eq!(
+            transcript_only.display_name(DisplayLang::English),
+            "การเขียนโปรแกรม"
+        );
+
+        let curriculum_only = course(" ", Some("Programming"));
+        assert_eq!(
+            curriculum_only.display_name(DisplayLang::Thai),
+            "Programming"
+        );
+
+        let neither = course("", None);
+        assert_eq!(neither.display_name(DisplayLang::Thai), "344-101");
+    }
+
+    fn parsed(grade: &str, credit: f32) -> ParsedCourse {
+        ParsedCourse {
+            code: "344-101".to_string(),
+            name: "Course".to_string(),
+            grade: grade.to_string(),
+            parsed_credit: credit,
+            section: None,
+            low_confidence: false,
+        }
+    }
+
+    #[test]
+    fn g_grade_passes_but_is_left_out_of_gpa() {
+        assert!(is_passing_grade("G"));
+        assert_eq!(grade_point("G"), None);
+        assert_eq!(
+            calculate_gpa(&[parsed("A", 3.0), parsed("G", 3.0)]),
+            Some(4.0)
+        );
+        assert_eq!(calculate_gpa(&[parsed("G", 3.0)]), None);
+    }
+
+    #[test]
+    fn progress_percent_handles_zero_and_overflow() {
+        assert_eq!(progress_percent(3.0, 0.0), 100.0);
+        assert_eq!(progress_percent(0.0, 0.0), 0.0);
+        assert_eq!(progress_percent(12.0, 6.0), 100.0);
+        assert_eq!(progress_percent(3.0, 6.0), 50.0);
+    }
+
+    #[test]
+    fn percent_and_credit_formatting_round_at_half_and_nine_tenths() {
+        assert_eq!(format_percent(74.4), "74%");
+        assert_eq!(format_percent(74.5), "75%");
+        assert_eq!(format_percent(74.9), "75%");
+        assert_eq!(format_credits(3.0), "3");
+        assert_eq!(format_credits(1.5), "1.5");
+        assert_eq!(format_credits(2.96), "3");
+        assert_eq!(format_credits(2.94), "2.9");
+    }
+
+    #[test]
+    fn suffixed_and_plain_course_codes_are_equal() {
+        assert_eq!(
+            CourseCode::parse("344-111A"),
+            CourseCode::parse(" 344-111 ")
+        );
+        assert_eq!(canonical_code("344-111a"), "344-111");
+        assert_eq!(
+            CourseCode::parse("344-111a").unwrap().to_string(),
+            "344-111A"
+        );
+    }
+
+    #[test]
+    fn malformed_course_codes_are_rejected() {
+        for raw in ["344111", "34-1111", "344-11X", "344-111-A", ""] {
+            assert!(CourseCode::parse(raw).is_none(), "{:?}", raw);
+        }
+    }
+
+    #[test]
+    fn every_recognized_grade_maps_to_its_grade_point() {
+        let graded = [
+            ("A", 4.0),
+            ("B+", 3.5),
+            ("B", 3.0),
+            ("C+", 2.5),
+            ("C", 2.0),
+            ("D+", 1.5),
+            ("D", 1.0),
+            ("F", 0.0),
+        ];
+        for (grade, points) in graded {
+            assert_eq!(grade_point(grade), Some(points), "{}", grade);
+        }
+        assert_eq!(grade_point(" b+ "), Some(3.5));
+
+        for grade in ["S", "U", "W", "P", "I", "IP", "T", "G", "E", "", "A+", "Z"] {
+            assert_eq!(grade_point(grade), None, "{:?}", grade);
+        }
+    }
+}